@@ -0,0 +1,83 @@
+//! Boss components
+
+use bevy::prelude::*;
+
+pub use crate::game::map::BossKind;
+
+/// Progress of a boss zone's encounter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EncounterState {
+    /// Waiting for the network to grow into the zone
+    #[default]
+    Dormant,
+    /// The boss is awake and fighting
+    Active,
+    /// The boss has been killed
+    Defeated,
+}
+
+/// A fixed map zone guarded by a boss
+#[derive(Component, Debug, Clone)]
+pub struct BossZone {
+    pub kind: BossKind,
+    /// Radius of the trigger region
+    pub radius: f32,
+    pub state: EncounterState,
+    /// The boss while the encounter is active
+    pub boss: Option<Entity>,
+}
+
+impl BossZone {
+    #[must_use]
+    pub fn new(kind: BossKind, radius: f32) -> Self {
+        Self {
+            kind,
+            radius,
+            state: EncounterState::Dormant,
+            boss: None,
+        }
+    }
+
+    /// Whether a point lies inside the trigger region
+    #[must_use]
+    pub fn contains(&self, center: Vec2, point: Vec2) -> bool {
+        center.distance_squared(point) <= self.radius * self.radius
+    }
+}
+
+/// A boss driven by its definition's phase sequence
+#[derive(Component, Debug, Clone)]
+pub struct Boss {
+    pub kind: BossKind,
+    /// Zone that spawned this boss
+    pub zone: Entity,
+    /// Index of the current phase
+    pub phase: usize,
+    /// Seconds until the current phase's attack fires
+    pub attack_timer: f32,
+    /// Seconds until the current phase's summons arrive
+    pub summon_timer: f32,
+    /// Seconds the network has been absent from the zone
+    pub disengage_timer: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_starts_dormant() {
+        let zone = BossZone::new(BossKind::Blight, 100.0);
+        assert_eq!(zone.state, EncounterState::Dormant);
+        assert!(zone.boss.is_none());
+        assert!(zone.contains(Vec2::ZERO, Vec2::new(0.0, 100.0)));
+        assert!(!zone.contains(Vec2::ZERO, Vec2::new(0.0, 101.0)));
+    }
+
+    #[test]
+    fn test_boss_components_are_components() {
+        fn assert_component<T: Component>() {}
+        assert_component::<BossZone>();
+        assert_component::<Boss>();
+    }
+}
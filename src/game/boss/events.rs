@@ -0,0 +1,44 @@
+//! Boss events
+
+use bevy::prelude::*;
+
+use super::components::BossKind;
+
+/// Event fired when the network wakes a boss
+#[derive(Event, Debug, Clone)]
+pub struct EncounterStarted {
+    pub zone: Entity,
+    pub boss: Entity,
+    pub kind: BossKind,
+}
+
+/// Event fired when a boss encounter is over
+#[derive(Event, Debug, Clone)]
+pub struct EncounterEnded {
+    pub zone: Entity,
+    pub kind: BossKind,
+    /// Whether the boss was killed
+    pub defeated: bool,
+}
+
+/// Event fired when a boss enters a new phase
+#[derive(Event, Debug, Clone)]
+pub struct BossPhaseChanged {
+    pub boss: Entity,
+    pub kind: BossKind,
+    /// Index of the new phase
+    pub phase: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boss_events_are_events() {
+        fn assert_event<T: Event>() {}
+        assert_event::<EncounterStarted>();
+        assert_event::<EncounterEnded>();
+        assert_event::<BossPhaseChanged>();
+    }
+}
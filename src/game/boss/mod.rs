@@ -0,0 +1,408 @@
+//! Boss encounters
+//!
+//! Handles the fixed boss zones on the map:
+//! - Zones placed from the map layout at the start of a run
+//! - Encounters that start when the network grows into a zone
+//! - Data-driven phase scripts with attacks and summons
+//! - Bosses retreating when the network abandons their zone
+//! - A health bar hook for the active boss
+//! - Winning the run once every major threat is destroyed
+
+use bevy::prelude::*;
+
+use crate::game::combat::CombatSet;
+use crate::game::map::MapGenSet;
+use crate::GameState;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+pub use components::*;
+pub use events::*;
+pub use resources::*;
+pub use systems::spawn_boss;
+
+/// Plugin for boss encounters
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<BossDefinitions>()
+            .init_resource::<EncounterConfig>()
+            .init_resource::<BossHealthBar>()
+            // Events
+            .add_event::<EncounterStarted>()
+            .add_event::<EncounterEnded>()
+            .add_event::<BossPhaseChanged>()
+            // Fresh zones for every new run
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                systems::spawn_boss_zones.after(MapGenSet),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::GameOver,
+                    entered: GameState::Playing,
+                },
+                systems::spawn_boss_zones.after(MapGenSet),
+            )
+            // Cleanup when the run ends
+            .add_systems(OnEnter(GameState::Menu), systems::despawn_boss_encounters)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                systems::despawn_boss_encounters,
+            )
+            // Encounters react to this frame's damage and deaths
+            .add_systems(
+                Update,
+                (
+                    systems::trigger_encounters,
+                    systems::advance_boss_phases,
+                    systems::run_boss_attacks,
+                    systems::run_boss_summons,
+                    systems::disengage_bosses,
+                    systems::handle_boss_deaths,
+                    systems::update_boss_health_bar,
+                )
+                    .chain()
+                    .after(CombatSet)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // A kill that also offers an upgrade still wins the run
+            .add_systems(
+                Update,
+                systems::check_victory
+                    .after(systems::update_boss_health_bar)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::Upgrading))),
+            )
+            // Rendering
+            .add_systems(
+                Update,
+                systems::render_bosses.run_if(
+                    resource_exists::<bevy::gizmos::config::GizmoConfigStore>
+                        .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{create_test_app, enter_state, site};
+    use super::*;
+    use crate::game::combat::{DamageEvent, DamageType};
+    use crate::game::enemies::{Enemy, MajorThreat};
+    use crate::game::map::ColonySite;
+    use crate::game::network::{Health, TendrilPosition, TendrilSegment};
+    use crate::game::progression::Nutrients;
+    use crate::game::state::RunOutcome;
+
+    /// Collects encounter events across frames
+    #[derive(Resource, Default)]
+    struct Encounters {
+        started: Vec<EncounterStarted>,
+        ended: Vec<EncounterEnded>,
+        phases: Vec<BossPhaseChanged>,
+    }
+
+    fn collect_encounters(
+        mut started: EventReader<EncounterStarted>,
+        mut ended: EventReader<EncounterEnded>,
+        mut phases: EventReader<BossPhaseChanged>,
+        mut out: ResMut<Encounters>,
+    ) {
+        out.started.extend(started.read().cloned());
+        out.ended.extend(ended.read().cloned());
+        out.phases.extend(phases.read().cloned());
+    }
+
+    fn create_boss_app(app: App) -> App {
+        let mut app = app;
+        app.init_resource::<Encounters>()
+            .add_systems(Update, collect_encounters);
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn zones(app: &mut App) -> Vec<(Entity, BossZone)> {
+        let mut query = app.world_mut().query::<(Entity, &BossZone)>();
+        query
+            .iter(app.world())
+            .map(|(e, zone)| (e, zone.clone()))
+            .collect()
+    }
+
+    fn bosses(app: &mut App) -> Vec<(Entity, Boss)> {
+        let mut query = app.world_mut().query::<(Entity, &Boss)>();
+        query
+            .iter(app.world())
+            .map(|(e, boss)| (e, boss.clone()))
+            .collect()
+    }
+
+    fn grow_segment_at(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id()
+    }
+
+    fn damage(app: &mut App, target: Entity, amount: f32) {
+        app.world_mut()
+            .send_event(DamageEvent::new(None, target, amount, DamageType::Physical));
+        app.update();
+    }
+
+    #[test]
+    fn test_zones_spawn_dormant_from_map_layout() {
+        let mut app = create_boss_app(create_test_app(vec![
+            site(BossKind::BroodMother, Vec2::new(1000.0, 0.0)),
+            site(BossKind::Blight, Vec2::new(-1000.0, 0.0)),
+        ]));
+
+        let spawned = zones(&mut app);
+        assert_eq!(spawned.len(), 2);
+        for (entity, zone) in spawned {
+            assert_eq!(zone.state, EncounterState::Dormant);
+            let major = app.world().get::<MajorThreat>(entity).is_some();
+            assert_eq!(major, zone.kind == BossKind::BroodMother);
+        }
+        assert!(bosses(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_growing_into_zone_starts_encounter() {
+        let mut app = create_boss_app(create_test_app(vec![site(
+            BossKind::Blight,
+            Vec2::new(1000.0, 0.0),
+        )]));
+        grow_segment_at(&mut app, Vec2::new(300.0, 0.0));
+        app.update();
+        assert!(bosses(&mut app).is_empty());
+
+        grow_segment_at(&mut app, Vec2::new(900.0, 0.0));
+        app.update();
+
+        let spawned = bosses(&mut app);
+        assert_eq!(spawned.len(), 1);
+        let (boss, state) = &spawned[0];
+        let (zone_entity, zone) = zones(&mut app)[0].clone();
+        assert_eq!(zone.state, EncounterState::Active);
+        assert_eq!(zone.boss, Some(*boss));
+        assert_eq!(state.zone, zone_entity);
+
+        let definition = BossDefinitions::default()
+            .get(BossKind::Blight)
+            .unwrap()
+            .clone();
+        assert_eq!(
+            app.world().get::<Health>(*boss).unwrap().max,
+            definition.health
+        );
+        app.update();
+        let started = &app.world().resource::<Encounters>().started;
+        assert_eq!(started.len(), 1);
+        assert_eq!(started[0].boss, *boss);
+    }
+
+    #[test]
+    fn test_boss_advances_phases_as_health_drops() {
+        let mut app = create_boss_app(create_test_app(vec![site(
+            BossKind::BroodMother,
+            Vec2::new(1000.0, 0.0),
+        )]));
+        grow_segment_at(&mut app, Vec2::new(1000.0, 0.0));
+        app.update();
+        let (boss, _) = bosses(&mut app)[0];
+        let max = app.world().get::<Health>(boss).unwrap().max;
+
+        damage(&mut app, boss, max * 0.6);
+        app.update();
+        assert_eq!(app.world().get::<Boss>(boss).unwrap().phase, 1);
+
+        damage(&mut app, boss, max * 0.25);
+        app.update();
+        assert_eq!(app.world().get::<Boss>(boss).unwrap().phase, 2);
+
+        let phases: Vec<usize> = app
+            .world()
+            .resource::<Encounters>()
+            .phases
+            .iter()
+            .map(|e| e.phase)
+            .collect();
+        assert_eq!(phases, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_boss_attacks_nearby_segments() {
+        let mut app = create_test_app(vec![site(BossKind::Blight, Vec2::new(1000.0, 0.0))]);
+        if let Some(definition) = app
+            .world_mut()
+            .resource_mut::<BossDefinitions>()
+            .get_mut(BossKind::Blight)
+        {
+            definition.phases[0].attack = AttackPattern::Pulse {
+                radius: 150.0,
+                damage: 5.0,
+                damage_type: DamageType::Physical,
+            };
+            definition.phases[0].attack_interval = 0.25;
+        }
+        let mut app = create_boss_app(app);
+        let near = grow_segment_at(&mut app, Vec2::new(1000.0, 100.0));
+        let far = grow_segment_at(&mut app, Vec2::new(1000.0, 200.0));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let world = app.world();
+        let near = world.get::<TendrilSegment>(near).unwrap();
+        let far = world.get::<TendrilSegment>(far).unwrap();
+        assert!(near.health < near.max_health);
+        assert_eq!(far.health, far.max_health);
+    }
+
+    #[test]
+    fn test_boss_summons_enemies() {
+        let mut app = create_test_app(vec![site(BossKind::BroodMother, Vec2::new(1000.0, 0.0))]);
+        if let Some(definition) = app
+            .world_mut()
+            .resource_mut::<BossDefinitions>()
+            .get_mut(BossKind::BroodMother)
+        {
+            definition.phases[0].attack = AttackPattern::None;
+            definition.phases[0].summon_interval = 0.25;
+        }
+        let mut app = create_boss_app(app);
+        grow_segment_at(&mut app, Vec2::new(1000.0, 0.0));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let mut query = app.world_mut().query::<&Enemy>();
+        assert!(query.iter(app.world()).count() > 0);
+    }
+
+    #[test]
+    fn test_boss_disengages_when_network_leaves() {
+        let mut app = create_test_app(vec![site(BossKind::Blight, Vec2::new(1000.0, 0.0))]);
+        app.world_mut()
+            .resource_mut::<EncounterConfig>()
+            .disengage_delay = 0.5;
+        let mut app = create_boss_app(app);
+        let segment = grow_segment_at(&mut app, Vec2::new(1000.0, 0.0));
+        app.update();
+        assert_eq!(bosses(&mut app).len(), 1);
+
+        app.world_mut().despawn(segment);
+        for _ in 0..4 {
+            app.update();
+        }
+
+        assert!(bosses(&mut app).is_empty());
+        assert_eq!(zones(&mut app)[0].1.state, EncounterState::Dormant);
+        let ended = &app.world().resource::<Encounters>().ended;
+        assert_eq!(ended.len(), 1);
+        assert!(!ended[0].defeated);
+    }
+
+    #[test]
+    fn test_killing_boss_awards_nutrients_and_updates_health_bar() {
+        let mut app = create_boss_app(create_test_app(vec![site(
+            BossKind::Blight,
+            Vec2::new(1000.0, 0.0),
+        )]));
+        grow_segment_at(&mut app, Vec2::new(1000.0, 0.0));
+        app.update();
+        let (boss, _) = bosses(&mut app)[0];
+        let entry = app.world().resource::<BossHealthBar>().0.clone().unwrap();
+        assert_eq!(entry.boss, boss);
+        assert_eq!(entry.name, "Blight");
+        assert_eq!(entry.fraction(), 1.0);
+
+        let before = app.world().resource::<Nutrients>().current;
+        damage(&mut app, boss, 100_000.0);
+        app.update();
+
+        assert!(app.world().get_entity(boss).is_err());
+        assert!(app.world().resource::<BossHealthBar>().0.is_none());
+        assert_eq!(zones(&mut app)[0].1.state, EncounterState::Defeated);
+        assert!(app.world().resource::<Nutrients>().current > before);
+        let ended = &app.world().resource::<Encounters>().ended;
+        assert_eq!(ended.len(), 1);
+        assert!(ended[0].defeated);
+    }
+
+    #[test]
+    fn test_destroying_every_major_threat_wins_the_run() {
+        let mut app = create_test_app(vec![
+            site(BossKind::BroodMother, Vec2::new(1000.0, 0.0)),
+            site(BossKind::Blight, Vec2::new(-1000.0, 0.0)),
+        ]);
+        app.world_mut()
+            .resource_mut::<crate::game::map::MapLayout>()
+            .colonies = vec![ColonySite {
+            position: Vec2::new(0.0, 1000.0),
+            radius: 100.0,
+            major_threat: true,
+        }];
+        let mut app = create_boss_app(app);
+        grow_segment_at(&mut app, Vec2::new(1000.0, 0.0));
+        app.update();
+        let (boss, _) = bosses(&mut app)[0];
+
+        damage(&mut app, boss, 100_000.0);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::Playing
+        );
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<Entity, (With<MajorThreat>, Without<BossZone>)>();
+        let colony = query.single(app.world());
+        damage(&mut app, colony, 100_000.0);
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::GameOver
+        );
+        assert_eq!(*app.world().resource::<RunOutcome>(), RunOutcome::Victory);
+    }
+
+    #[test]
+    fn test_encounters_reset_between_runs() {
+        let mut app = create_boss_app(create_test_app(vec![site(
+            BossKind::Blight,
+            Vec2::new(1000.0, 0.0),
+        )]));
+        let segment = grow_segment_at(&mut app, Vec2::new(1000.0, 0.0));
+        app.update();
+        assert_eq!(bosses(&mut app).len(), 1);
+
+        enter_state(&mut app, GameState::GameOver);
+        app.world_mut().despawn(segment);
+        assert!(bosses(&mut app).is_empty());
+        assert!(zones(&mut app).is_empty());
+
+        enter_state(&mut app, GameState::Playing);
+        assert_eq!(zones(&mut app)[0].1.state, EncounterState::Dormant);
+    }
+}
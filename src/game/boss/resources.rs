@@ -0,0 +1,316 @@
+//! Boss resources
+//!
+//! Bosses are data: a definition lists the phases in order, each starting
+//! when health falls to its threshold.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::components::BossKind;
+use crate::game::combat::DamageType;
+use crate::game::enemies::EnemyKind;
+
+/// Attack a boss repeats while in a phase
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttackPattern {
+    /// No attack
+    None,
+    /// Damage every player segment and the core within `radius`
+    Pulse {
+        radius: f32,
+        damage: f32,
+        damage_type: DamageType,
+    },
+    /// Damage the `targets` nearest player segments within `range`
+    Volley {
+        range: f32,
+        targets: usize,
+        damage: f32,
+        damage_type: DamageType,
+    },
+}
+
+/// Enemies a boss calls in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summon {
+    pub kind: EnemyKind,
+    pub count: u32,
+    /// Distance from the boss the summons appear at
+    pub distance: f32,
+}
+
+/// One step of a boss's script
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossPhase {
+    pub name: String,
+    /// Health fraction at or below which this phase begins
+    pub health_threshold: f32,
+    pub attack: AttackPattern,
+    /// Seconds between attacks
+    pub attack_interval: f32,
+    pub summons: Vec<Summon>,
+    /// Seconds between summon waves
+    pub summon_interval: f32,
+}
+
+/// Data definition of a boss
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossDefinition {
+    pub name: String,
+    pub health: f32,
+    pub radius: f32,
+    /// Nutrients awarded when killed
+    pub nutrient_drop: f32,
+    /// Whether killing it counts toward winning the run
+    pub major_threat: bool,
+    /// Phases ordered by descending health threshold, the first at 1.0
+    pub phases: Vec<BossPhase>,
+}
+
+impl BossDefinition {
+    /// Index of the phase for a health fraction
+    ///
+    /// The latest phase whose threshold has been reached wins.
+    #[must_use]
+    pub fn phase_for(&self, health_fraction: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| health_fraction <= phase.health_threshold)
+            .unwrap_or(0)
+    }
+}
+
+/// Boss definitions by kind
+#[derive(Resource, Debug, Clone)]
+pub struct BossDefinitions {
+    definitions: HashMap<BossKind, BossDefinition>,
+}
+
+impl BossDefinitions {
+    #[must_use]
+    pub fn get(&self, kind: BossKind) -> Option<&BossDefinition> {
+        self.definitions.get(&kind)
+    }
+
+    pub fn get_mut(&mut self, kind: BossKind) -> Option<&mut BossDefinition> {
+        self.definitions.get_mut(&kind)
+    }
+
+    pub fn set(&mut self, kind: BossKind, definition: BossDefinition) {
+        self.definitions.insert(kind, definition);
+    }
+}
+
+impl Default for BossDefinitions {
+    fn default() -> Self {
+        let mut definitions = HashMap::new();
+        definitions.insert(
+            BossKind::BroodMother,
+            BossDefinition {
+                name: "Brood Mother".to_string(),
+                health: 1500.0,
+                radius: 28.0,
+                nutrient_drop: 150.0,
+                major_threat: true,
+                phases: vec![
+                    BossPhase {
+                        name: "Brooding".to_string(),
+                        health_threshold: 1.0,
+                        attack: AttackPattern::Pulse {
+                            radius: 120.0,
+                            damage: 8.0,
+                            damage_type: DamageType::Physical,
+                        },
+                        attack_interval: 3.0,
+                        summons: vec![Summon {
+                            kind: EnemyKind::Insect,
+                            count: 4,
+                            distance: 60.0,
+                        }],
+                        summon_interval: 6.0,
+                    },
+                    BossPhase {
+                        name: "Frenzy".to_string(),
+                        health_threshold: 0.5,
+                        attack: AttackPattern::Volley {
+                            range: 300.0,
+                            targets: 3,
+                            damage: 12.0,
+                            damage_type: DamageType::Physical,
+                        },
+                        attack_interval: 1.5,
+                        summons: vec![Summon {
+                            kind: EnemyKind::Insect,
+                            count: 6,
+                            distance: 60.0,
+                        }],
+                        summon_interval: 4.0,
+                    },
+                    BossPhase {
+                        name: "Death Throes".to_string(),
+                        health_threshold: 0.2,
+                        attack: AttackPattern::Pulse {
+                            radius: 180.0,
+                            damage: 15.0,
+                            damage_type: DamageType::Physical,
+                        },
+                        attack_interval: 2.0,
+                        summons: vec![Summon {
+                            kind: EnemyKind::Bacteria,
+                            count: 4,
+                            distance: 40.0,
+                        }],
+                        summon_interval: 3.0,
+                    },
+                ],
+            },
+        );
+        definitions.insert(
+            BossKind::Blight,
+            BossDefinition {
+                name: "Blight".to_string(),
+                health: 800.0,
+                radius: 24.0,
+                nutrient_drop: 90.0,
+                major_threat: false,
+                phases: vec![
+                    BossPhase {
+                        name: "Seeping".to_string(),
+                        health_threshold: 1.0,
+                        attack: AttackPattern::Pulse {
+                            radius: 150.0,
+                            damage: 5.0,
+                            damage_type: DamageType::Corrupting,
+                        },
+                        attack_interval: 2.5,
+                        summons: Vec::new(),
+                        summon_interval: 0.0,
+                    },
+                    BossPhase {
+                        name: "Bloom".to_string(),
+                        health_threshold: 0.4,
+                        attack: AttackPattern::Volley {
+                            range: 260.0,
+                            targets: 4,
+                            damage: 8.0,
+                            damage_type: DamageType::Corrupting,
+                        },
+                        attack_interval: 2.0,
+                        summons: vec![Summon {
+                            kind: EnemyKind::Bacteria,
+                            count: 3,
+                            distance: 40.0,
+                        }],
+                        summon_interval: 5.0,
+                    },
+                ],
+            },
+        );
+        Self { definitions }
+    }
+}
+
+/// Tuning shared by every encounter
+#[derive(Resource, Debug, Clone)]
+pub struct EncounterConfig {
+    /// Seconds without network presence before a boss retreats
+    pub disengage_delay: f32,
+    /// Leash radius as a multiple of the zone radius
+    pub leash_multiplier: f32,
+}
+
+impl Default for EncounterConfig {
+    fn default() -> Self {
+        Self {
+            disengage_delay: 10.0,
+            leash_multiplier: 1.5,
+        }
+    }
+}
+
+/// Current boss shown by the boss health bar, if any
+///
+/// Updated every frame from the active boss; the UI only reads it.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct BossHealthBar(pub Option<BossHealthBarEntry>);
+
+/// What the boss health bar displays
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossHealthBarEntry {
+    pub boss: Entity,
+    pub name: String,
+    pub phase_name: String,
+    pub current: f32,
+    pub max: f32,
+}
+
+impl BossHealthBarEntry {
+    /// Health fraction for drawing the bar
+    #[must_use]
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 {
+            (self.current / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_definitions_are_well_formed() {
+        let definitions = BossDefinitions::default();
+        for kind in [BossKind::BroodMother, BossKind::Blight] {
+            let definition = definitions.get(kind).unwrap();
+            assert!(definition.health > 0.0);
+            assert_eq!(definition.phases[0].health_threshold, 1.0);
+            for pair in definition.phases.windows(2) {
+                assert!(pair[0].health_threshold > pair[1].health_threshold);
+            }
+        }
+        assert!(definitions.get(BossKind::BroodMother).unwrap().major_threat);
+    }
+
+    #[test]
+    fn test_phase_for_health_fraction() {
+        let definition = BossDefinitions::default()
+            .get(BossKind::BroodMother)
+            .unwrap()
+            .clone();
+        assert_eq!(definition.phase_for(1.0), 0);
+        assert_eq!(definition.phase_for(0.51), 0);
+        assert_eq!(definition.phase_for(0.5), 1);
+        assert_eq!(definition.phase_for(0.2), 2);
+        assert_eq!(definition.phase_for(0.0), 2);
+    }
+
+    #[test]
+    fn test_definitions_can_be_overridden() {
+        let mut definitions = BossDefinitions::default();
+        definitions.get_mut(BossKind::Blight).unwrap().health = 1.0;
+        assert_eq!(definitions.get(BossKind::Blight).unwrap().health, 1.0);
+    }
+
+    #[test]
+    fn test_health_bar_fraction() {
+        let entry = BossHealthBarEntry {
+            boss: Entity::PLACEHOLDER,
+            name: "Boss".to_string(),
+            phase_name: "One".to_string(),
+            current: 25.0,
+            max: 100.0,
+        };
+        assert_eq!(entry.fraction(), 0.25);
+    }
+
+    #[test]
+    fn test_boss_resources_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<BossDefinitions>();
+        assert_resource::<EncounterConfig>();
+        assert_resource::<BossHealthBar>();
+    }
+}
@@ -0,0 +1,424 @@
+//! Boss systems
+//!
+//! Zone placement, encounter triggers, phase scripts, health bar updates and
+//! the victory check.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::components::{Boss, BossKind, BossZone, EncounterState};
+use super::events::{BossPhaseChanged, EncounterEnded, EncounterStarted};
+use super::resources::{
+    AttackPattern, BossDefinition, BossDefinitions, BossHealthBar, BossHealthBarEntry,
+    EncounterConfig,
+};
+use crate::game::collision::{Collider, CollisionLayers, Layer, LayerMask};
+use crate::game::combat::{DamageEvent, Died, Hostile};
+use crate::game::enemies::{MajorThreat, SpawnEnemy};
+use crate::game::map::MapLayout;
+use crate::game::network::{CoreNode, Faction, Health, TendrilPosition, TendrilSegment};
+use crate::game::progression::{NutrientSource, Nutrients, NutrientsGained};
+use crate::game::state::RunOutcome;
+use crate::GameState;
+
+/// Positions of the player's live segments
+fn player_segment_positions(
+    segments: &Query<(Entity, &TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+) -> Vec<(Entity, Vec2)> {
+    segments
+        .iter()
+        .filter(|(_, _, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .map(|(entity, pos, _, _)| (entity, pos.position))
+        .collect()
+}
+
+/// Spawn a boss for a zone and return its entity
+pub fn spawn_boss(
+    commands: &mut Commands,
+    kind: BossKind,
+    definition: &BossDefinition,
+    zone: Entity,
+    position: Vec2,
+) -> Entity {
+    let first = definition.phases.first();
+    let mut entity = commands.spawn((
+        Boss {
+            kind,
+            zone,
+            phase: 0,
+            attack_timer: first.map_or(0.0, |p| p.attack_interval),
+            summon_timer: first.map_or(0.0, |p| p.summon_interval),
+            disengage_timer: 0.0,
+        },
+        Hostile,
+        Health::new(definition.health),
+        Collider::Circle {
+            radius: definition.radius,
+        },
+        CollisionLayers::new(Layer::Enemy, LayerMask::TENDRIL | LayerMask::CORE),
+        Transform::from_translation(position.extend(0.0)),
+        Visibility::default(),
+    ));
+    if definition.major_threat {
+        entity.insert(MajorThreat);
+    }
+    entity.id()
+}
+
+/// Place boss zones from the map layout
+pub fn spawn_boss_zones(
+    mut commands: Commands,
+    layout: Res<MapLayout>,
+    definitions: Res<BossDefinitions>,
+) {
+    for site in layout.bosses.iter() {
+        let Some(definition) = definitions.get(site.kind) else {
+            warn!("No boss definition for {:?}", site.kind);
+            continue;
+        };
+        let mut zone = commands.spawn((
+            BossZone::new(site.kind, site.radius),
+            Transform::from_translation(site.position.extend(0.0)),
+            Visibility::default(),
+        ));
+        // An unbeaten zone counts as a threat before its boss wakes
+        if definition.major_threat {
+            zone.insert(MajorThreat);
+        }
+    }
+}
+
+/// Wake a zone's boss when a player segment grows into it
+pub fn trigger_encounters(
+    mut commands: Commands,
+    definitions: Res<BossDefinitions>,
+    mut zones: Query<(Entity, &mut BossZone, &Transform)>,
+    segments: Query<(Entity, &TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    mut started: EventWriter<EncounterStarted>,
+) {
+    let positions = player_segment_positions(&segments);
+    for (zone_entity, mut zone, transform) in zones.iter_mut() {
+        if zone.state != EncounterState::Dormant {
+            continue;
+        }
+        let center = transform.translation.truncate();
+        if !positions.iter().any(|(_, p)| zone.contains(center, *p)) {
+            continue;
+        }
+        let Some(definition) = definitions.get(zone.kind) else {
+            continue;
+        };
+        let boss = spawn_boss(&mut commands, zone.kind, definition, zone_entity, center);
+        zone.state = EncounterState::Active;
+        zone.boss = Some(boss);
+        started.send(EncounterStarted {
+            zone: zone_entity,
+            boss,
+            kind: zone.kind,
+        });
+    }
+}
+
+/// Move bosses to later phases as their health drops
+pub fn advance_boss_phases(
+    definitions: Res<BossDefinitions>,
+    mut bosses: Query<(Entity, &mut Boss, &Health)>,
+    mut changed: EventWriter<BossPhaseChanged>,
+) {
+    for (entity, mut boss, health) in bosses.iter_mut() {
+        let Some(definition) = definitions.get(boss.kind) else {
+            continue;
+        };
+        let fraction = if health.max > 0.0 {
+            health.current / health.max
+        } else {
+            0.0
+        };
+        let phase = definition.phase_for(fraction);
+        // Phases only move forward, healing does not rewind the script
+        if phase <= boss.phase {
+            continue;
+        }
+        let script = &definition.phases[phase];
+        boss.phase = phase;
+        boss.attack_timer = script.attack_interval;
+        boss.summon_timer = script.summon_interval;
+        changed.send(BossPhaseChanged {
+            boss: entity,
+            kind: boss.kind,
+            phase,
+        });
+    }
+}
+
+/// Fire each boss's current attack pattern on its interval
+pub fn run_boss_attacks(
+    time: Res<Time>,
+    definitions: Res<BossDefinitions>,
+    mut bosses: Query<(Entity, &mut Boss, &Transform)>,
+    segments: Query<(Entity, &TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    cores: Query<(Entity, &Transform), With<CoreNode>>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    let delta = time.delta_secs();
+    let mut targets = player_segment_positions(&segments);
+    targets.extend(
+        cores
+            .iter()
+            .map(|(entity, t)| (entity, t.translation.truncate())),
+    );
+
+    for (entity, mut boss, transform) in bosses.iter_mut() {
+        let Some(phase) = definitions
+            .get(boss.kind)
+            .and_then(|d| d.phases.get(boss.phase))
+        else {
+            continue;
+        };
+        if phase.attack_interval <= 0.0 {
+            continue;
+        }
+        boss.attack_timer -= delta;
+        if boss.attack_timer > 0.0 {
+            continue;
+        }
+        boss.attack_timer += phase.attack_interval;
+
+        let origin = transform.translation.truncate();
+        let mut hit = |target: Entity, amount: f32, damage_type| {
+            damage.send(DamageEvent::new(Some(entity), target, amount, damage_type));
+        };
+        match phase.attack {
+            AttackPattern::None => {}
+            AttackPattern::Pulse {
+                radius,
+                damage,
+                damage_type,
+            } => {
+                for (target, position) in targets.iter() {
+                    if position.distance_squared(origin) <= radius * radius {
+                        hit(*target, damage, damage_type);
+                    }
+                }
+            }
+            AttackPattern::Volley {
+                range,
+                targets: count,
+                damage,
+                damage_type,
+            } => {
+                let mut in_range: Vec<(Entity, f32)> = targets
+                    .iter()
+                    .map(|(target, position)| (*target, position.distance_squared(origin)))
+                    .filter(|(_, distance)| *distance <= range * range)
+                    .collect();
+                in_range.sort_by(|a, b| a.1.total_cmp(&b.1));
+                for (target, _) in in_range.into_iter().take(count) {
+                    hit(target, damage, damage_type);
+                }
+            }
+        }
+    }
+}
+
+/// Call in each boss's current summons on their interval
+pub fn run_boss_summons(
+    time: Res<Time>,
+    definitions: Res<BossDefinitions>,
+    mut bosses: Query<(&mut Boss, &Transform)>,
+    mut spawn: EventWriter<SpawnEnemy>,
+) {
+    let delta = time.delta_secs();
+    for (mut boss, transform) in bosses.iter_mut() {
+        let Some(phase) = definitions
+            .get(boss.kind)
+            .and_then(|d| d.phases.get(boss.phase))
+        else {
+            continue;
+        };
+        if phase.summons.is_empty() || phase.summon_interval <= 0.0 {
+            continue;
+        }
+        boss.summon_timer -= delta;
+        if boss.summon_timer > 0.0 {
+            continue;
+        }
+        boss.summon_timer += phase.summon_interval;
+
+        let origin = transform.translation.truncate();
+        for summon in phase.summons.iter() {
+            for i in 0..summon.count {
+                let angle = TAU * i as f32 / summon.count as f32;
+                let position = origin + Vec2::from_angle(angle) * summon.distance;
+                spawn.send(SpawnEnemy::new(summon.kind, position));
+            }
+        }
+    }
+}
+
+/// Send bosses back to sleep when the network abandons their zone
+pub fn disengage_bosses(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<EncounterConfig>,
+    mut bosses: Query<(Entity, &mut Boss)>,
+    mut zones: Query<(&mut BossZone, &Transform)>,
+    segments: Query<(Entity, &TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    mut ended: EventWriter<EncounterEnded>,
+) {
+    let positions = player_segment_positions(&segments);
+    for (entity, mut boss) in bosses.iter_mut() {
+        let Ok((mut zone, transform)) = zones.get_mut(boss.zone) else {
+            continue;
+        };
+        let center = transform.translation.truncate();
+        let leash = zone.radius * config.leash_multiplier;
+        let present = positions
+            .iter()
+            .any(|(_, p)| p.distance_squared(center) <= leash * leash);
+        if present {
+            boss.disengage_timer = 0.0;
+            continue;
+        }
+        boss.disengage_timer += time.delta_secs();
+        if boss.disengage_timer < config.disengage_delay {
+            continue;
+        }
+        zone.state = EncounterState::Dormant;
+        zone.boss = None;
+        ended.send(EncounterEnded {
+            zone: boss.zone,
+            kind: boss.kind,
+            defeated: false,
+        });
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Close encounters whose boss died and award its nutrients
+pub fn handle_boss_deaths(
+    mut commands: Commands,
+    mut died: EventReader<Died>,
+    definitions: Res<BossDefinitions>,
+    bosses: Query<&Boss>,
+    mut zones: Query<&mut BossZone>,
+    mut nutrients: ResMut<Nutrients>,
+    mut gained: EventWriter<NutrientsGained>,
+    mut ended: EventWriter<EncounterEnded>,
+) {
+    for event in died.read() {
+        let Ok(boss) = bosses.get(event.entity) else {
+            continue;
+        };
+        if let Ok(mut zone) = zones.get_mut(boss.zone) {
+            zone.state = EncounterState::Defeated;
+            zone.boss = None;
+            commands.entity(boss.zone).remove::<MajorThreat>();
+        }
+        if let Some(definition) = definitions.get(boss.kind) {
+            if definition.nutrient_drop > 0.0 {
+                nutrients.add(definition.nutrient_drop);
+                gained.send(NutrientsGained::new(
+                    definition.nutrient_drop,
+                    NutrientSource::EnemyDrop,
+                ));
+            }
+        }
+        ended.send(EncounterEnded {
+            zone: boss.zone,
+            kind: boss.kind,
+            defeated: true,
+        });
+        commands.entity(event.entity).despawn_recursive();
+    }
+}
+
+/// Mirror the active boss into the health bar hook
+pub fn update_boss_health_bar(
+    definitions: Res<BossDefinitions>,
+    bosses: Query<(Entity, &Boss, &Health)>,
+    mut bar: ResMut<BossHealthBar>,
+) {
+    let entry =
+        bosses
+            .iter()
+            .min_by_key(|(entity, _, _)| *entity)
+            .and_then(|(entity, boss, health)| {
+                let definition = definitions.get(boss.kind)?;
+                Some(BossHealthBarEntry {
+                    boss: entity,
+                    name: definition.name.clone(),
+                    phase_name: definition
+                        .phases
+                        .get(boss.phase)
+                        .map(|p| p.name.clone())
+                        .unwrap_or_default(),
+                    current: health.current,
+                    max: health.max,
+                })
+            });
+    if bar.0 != entry {
+        bar.0 = entry;
+    }
+}
+
+/// Win the run once every major threat on the map is dead
+pub fn check_victory(
+    layout: Res<MapLayout>,
+    definitions: Res<BossDefinitions>,
+    threats: Query<(), With<MajorThreat>>,
+    mut outcome: ResMut<RunOutcome>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let map_has_threats = layout.colonies.iter().any(|c| c.major_threat)
+        || layout
+            .bosses
+            .iter()
+            .any(|b| definitions.get(b.kind).is_some_and(|d| d.major_threat));
+    if !map_has_threats || !threats.is_empty() {
+        return;
+    }
+    *outcome = RunOutcome::Victory;
+    next_state.set(GameState::GameOver);
+}
+
+/// Remove zones and bosses when the run ends
+pub fn despawn_boss_encounters(
+    mut commands: Commands,
+    entities: Query<Entity, Or<(With<BossZone>, With<Boss>)>>,
+    mut bar: ResMut<BossHealthBar>,
+) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    bar.0 = None;
+}
+
+/// Draw boss zones and bosses
+pub fn render_bosses(
+    mut gizmos: Gizmos,
+    definitions: Res<BossDefinitions>,
+    zones: Query<(&BossZone, &Transform)>,
+    bosses: Query<(&Boss, &Transform, &Visibility)>,
+) {
+    for (zone, transform) in zones.iter() {
+        let color = match zone.state {
+            EncounterState::Dormant => Color::srgba(0.8, 0.5, 0.2, 0.35),
+            EncounterState::Active => Color::srgba(0.95, 0.2, 0.2, 0.6),
+            EncounterState::Defeated => Color::srgba(0.5, 0.5, 0.5, 0.2),
+        };
+        gizmos.circle_2d(transform.translation.truncate(), zone.radius, color);
+    }
+    for (boss, transform, visibility) in bosses.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        let radius = definitions.get(boss.kind).map_or(24.0, |d| d.radius);
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            radius,
+            Color::srgb(0.95, 0.25, 0.25),
+        );
+    }
+}
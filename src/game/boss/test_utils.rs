@@ -0,0 +1,79 @@
+//! Shared test utilities for boss module tests
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use super::{BossKind, BossPlugin};
+use crate::game::collision::CollisionPlugin;
+use crate::game::combat::CombatPlugin;
+use crate::game::enemies::{EnemiesPlugin, SpawnDirectorConfig};
+use crate::game::map::{
+    BiomeMap, BiomeTable, BossSite, MapLayout, ObstacleConfig, Obstacles, TerrainGrid,
+    WorldDescription,
+};
+use crate::game::network::NetworkStats;
+use crate::game::pickups::PickupsPlugin;
+use crate::game::progression::ProgressionPlugin;
+use crate::game::state::{RunOutcome, RunStats};
+use crate::GameState;
+
+/// Fixed frame time used by boss tests (exact in binary floating point)
+pub const TEST_FRAME_SECS: f32 = 0.25;
+
+/// A boss site with a 200 unit trigger radius
+pub fn site(kind: BossKind, position: Vec2) -> BossSite {
+    BossSite {
+        kind,
+        position,
+        radius: 200.0,
+    }
+}
+
+/// Helper to create a test app with the boss sites given and the enemy,
+/// pickup, combat, collision and progression plugins, with a fixed time step
+/// and the spawn director disabled
+pub fn create_test_app(bosses: Vec<BossSite>) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(StatesPlugin)
+        .init_state::<GameState>()
+        .init_resource::<RunStats>()
+        .init_resource::<RunOutcome>()
+        .init_resource::<NetworkStats>()
+        .init_resource::<TerrainGrid>()
+        .init_resource::<BiomeMap>()
+        .init_resource::<BiomeTable>()
+        .init_resource::<WorldDescription>()
+        .init_resource::<Obstacles>()
+        .init_resource::<ObstacleConfig>()
+        .insert_resource(MapLayout {
+            colonies: Vec::new(),
+            bosses,
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            TEST_FRAME_SECS,
+        )))
+        .add_plugins((
+            CollisionPlugin,
+            CombatPlugin,
+            ProgressionPlugin,
+            EnemiesPlugin,
+            PickupsPlugin,
+            BossPlugin,
+        ));
+    app.world_mut()
+        .resource_mut::<SpawnDirectorConfig>()
+        .max_alive = 0;
+    app
+}
+
+/// Transition the test app into `state` and let the transition settle
+pub fn enter_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
@@ -59,7 +59,7 @@ mod tests {
 
         // Should have exactly one MainCamera
         let mut query = app.world_mut().query_filtered::<Entity, With<MainCamera>>();
-        assert_eq!(query.iter(&app.world()).count(), 1);
+        assert_eq!(query.iter(app.world()).count(), 1);
     }
 
     #[test]
@@ -71,7 +71,7 @@ mod tests {
         let mut query = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>();
-        assert_eq!(query.iter(&app.world()).count(), 1);
+        assert_eq!(query.iter(app.world()).count(), 1);
     }
 
     #[test]
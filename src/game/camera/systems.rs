@@ -114,7 +114,7 @@ mod tests {
 
         // Verify camera was spawned
         let mut query = app.world_mut().query_filtered::<Entity, With<MainCamera>>();
-        assert_eq!(query.iter(&app.world()).count(), 1);
+        assert_eq!(query.iter(app.world()).count(), 1);
     }
 
     #[test]
@@ -125,7 +125,7 @@ mod tests {
         let mut query = app
             .world_mut()
             .query_filtered::<&OrthographicProjection, With<MainCamera>>();
-        assert_eq!(query.iter(&app.world()).count(), 1);
+        assert_eq!(query.iter(app.world()).count(), 1);
     }
 
     // Camera zoom tests
@@ -138,7 +138,7 @@ mod tests {
         let initial_zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .zoom;
 
         // Send scroll up event (zoom in)
@@ -153,7 +153,7 @@ mod tests {
         let new_zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .zoom;
 
         // Zoom in = smaller scale value
@@ -168,7 +168,7 @@ mod tests {
         let initial_zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .zoom;
 
         // Send scroll down event (zoom out)
@@ -183,7 +183,7 @@ mod tests {
         let new_zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .zoom;
 
         // Zoom out = larger scale value
@@ -199,7 +199,7 @@ mod tests {
         let min_zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .min_zoom;
 
         // Send many scroll up events to hit min limit
@@ -216,7 +216,7 @@ mod tests {
         let zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .zoom;
 
         assert!(zoom >= min_zoom);
@@ -231,7 +231,7 @@ mod tests {
         let max_zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .max_zoom;
 
         // Send many scroll down events to hit max limit
@@ -248,7 +248,7 @@ mod tests {
         let zoom = app
             .world_mut()
             .query_filtered::<&CameraController, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .zoom;
 
         assert!(zoom <= max_zoom);
@@ -263,7 +263,7 @@ mod tests {
         let initial_pos = app
             .world_mut()
             .query_filtered::<&Transform, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .translation;
 
         // Press D key to pan right
@@ -275,7 +275,7 @@ mod tests {
         let new_pos = app
             .world_mut()
             .query_filtered::<&Transform, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .translation;
 
         assert!(new_pos.x > initial_pos.x);
@@ -300,7 +300,7 @@ mod tests {
         let initial_pos = app
             .world_mut()
             .query_filtered::<&Transform, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .translation;
 
         // Try to pan
@@ -312,7 +312,7 @@ mod tests {
         let new_pos = app
             .world_mut()
             .query_filtered::<&Transform, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .translation;
 
         // Position should not change when locked
@@ -344,7 +344,7 @@ mod tests {
         let camera_pos = app
             .world_mut()
             .query_filtered::<&Transform, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .translation;
 
         assert_eq!(camera_pos.x, 100.0);
@@ -373,7 +373,7 @@ mod tests {
         let camera_pos = app
             .world_mut()
             .query_filtered::<&Transform, With<MainCamera>>()
-            .single(&app.world())
+            .single(app.world())
             .translation;
 
         assert_eq!(camera_pos.x, 0.0);
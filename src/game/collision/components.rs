@@ -0,0 +1,164 @@
+//! Collision components
+
+use std::ops::BitOr;
+
+use bevy::prelude::*;
+
+/// Collision layers an entity can belong to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layer {
+    Enemy,
+    Tendril,
+    Projectile,
+    Core,
+    Pickup,
+}
+
+/// Set of collision layers as a bit mask
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LayerMask(pub u32);
+
+impl LayerMask {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(u32::MAX);
+    pub const ENEMY: Self = Self::from_layer(Layer::Enemy);
+    pub const TENDRIL: Self = Self::from_layer(Layer::Tendril);
+    pub const PROJECTILE: Self = Self::from_layer(Layer::Projectile);
+    pub const CORE: Self = Self::from_layer(Layer::Core);
+    pub const PICKUP: Self = Self::from_layer(Layer::Pickup);
+
+    /// Mask containing a single layer
+    #[must_use]
+    pub const fn from_layer(layer: Layer) -> Self {
+        Self(1 << layer as u32)
+    }
+
+    /// Whether the mask contains a layer
+    #[must_use]
+    pub fn contains(self, layer: Layer) -> bool {
+        self.intersects(Self::from_layer(layer))
+    }
+
+    /// Whether the masks share any layer
+    #[must_use]
+    pub fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for LayerMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<Layer> for LayerMask {
+    fn from(layer: Layer) -> Self {
+        Self::from_layer(layer)
+    }
+}
+
+/// Which layers an entity is on and which layers it collides with
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionLayers {
+    /// Layers this entity belongs to
+    pub membership: LayerMask,
+    /// Layers this entity collides with
+    pub filter: LayerMask,
+}
+
+impl CollisionLayers {
+    #[must_use]
+    pub fn new(membership: impl Into<LayerMask>, filter: impl Into<LayerMask>) -> Self {
+        Self {
+            membership: membership.into(),
+            filter: filter.into(),
+        }
+    }
+
+    /// Whether two entities with these layers can collide (both must accept the other)
+    #[must_use]
+    pub fn interacts(&self, other: &Self) -> bool {
+        self.filter.intersects(other.membership) && other.filter.intersects(self.membership)
+    }
+}
+
+/// Collision shape of an entity
+///
+/// Positions come from `TendrilPosition` when present, otherwise `Transform`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum Collider {
+    /// Circle around the entity position
+    Circle { radius: f32 },
+    /// Capsule from the `NetworkParent` position to the entity position;
+    /// a circle when the entity has no parent
+    Capsule { radius: f32 },
+}
+
+impl Collider {
+    /// Thickness of the shape
+    #[must_use]
+    pub fn radius(&self) -> f32 {
+        match *self {
+            Self::Circle { radius } | Self::Capsule { radius } => radius,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_masks_are_distinct() {
+        let all = [
+            LayerMask::ENEMY,
+            LayerMask::TENDRIL,
+            LayerMask::PROJECTILE,
+            LayerMask::CORE,
+            LayerMask::PICKUP,
+        ];
+        for (i, a) in all.iter().enumerate() {
+            for b in &all[i + 1..] {
+                assert!(!a.intersects(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_layer_mask_combination() {
+        let mask = LayerMask::ENEMY | LayerMask::PICKUP;
+        assert!(mask.contains(Layer::Enemy));
+        assert!(mask.contains(Layer::Pickup));
+        assert!(!mask.contains(Layer::Core));
+        assert!(!LayerMask::NONE.contains(Layer::Enemy));
+        assert!(LayerMask::ALL.contains(Layer::Tendril));
+    }
+
+    #[test]
+    fn test_collision_layers_require_both_filters() {
+        let enemy = CollisionLayers::new(Layer::Enemy, LayerMask::TENDRIL | LayerMask::CORE);
+        let tendril = CollisionLayers::new(Layer::Tendril, LayerMask::ENEMY);
+        let pickup = CollisionLayers::new(Layer::Pickup, LayerMask::TENDRIL);
+        assert!(enemy.interacts(&tendril));
+        assert!(tendril.interacts(&enemy));
+        // Tendrils do not list pickups in their filter here
+        assert!(!tendril.interacts(&pickup));
+        assert!(!enemy.interacts(&enemy));
+    }
+
+    #[test]
+    fn test_collider_radius() {
+        assert_eq!(Collider::Circle { radius: 3.0 }.radius(), 3.0);
+        assert_eq!(Collider::Capsule { radius: 2.0 }.radius(), 2.0);
+    }
+
+    #[test]
+    fn test_collision_components_are_components() {
+        fn assert_component<T: Component>() {}
+        assert_component::<Collider>();
+        assert_component::<CollisionLayers>();
+    }
+}
@@ -0,0 +1,84 @@
+//! Collision events
+//!
+//! Pairs are ordered so that `a < b`; use `other` to get the partner of an
+//! entity regardless of order.
+
+use bevy::prelude::*;
+
+fn partner(a: Entity, b: Entity, entity: Entity) -> Option<Entity> {
+    if a == entity {
+        Some(b)
+    } else if b == entity {
+        Some(a)
+    } else {
+        None
+    }
+}
+
+/// Fired on the first frame two colliders overlap
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionEnter {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+impl CollisionEnter {
+    /// The other entity of the pair, if `entity` is part of it
+    #[must_use]
+    pub fn other(&self, entity: Entity) -> Option<Entity> {
+        partner(self.a, self.b, entity)
+    }
+}
+
+/// Fired every frame two colliders overlap, including the first
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionStay {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+impl CollisionStay {
+    /// The other entity of the pair, if `entity` is part of it
+    #[must_use]
+    pub fn other(&self, entity: Entity) -> Option<Entity> {
+        partner(self.a, self.b, entity)
+    }
+}
+
+/// Fired when two colliders stop overlapping or one of them is removed
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionExit {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+impl CollisionExit {
+    /// The other entity of the pair, if `entity` is part of it
+    #[must_use]
+    pub fn other(&self, entity: Entity) -> Option<Entity> {
+        partner(self.a, self.b, entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_other_returns_partner() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let event = CollisionStay { a, b };
+        assert_eq!(event.other(a), Some(b));
+        assert_eq!(event.other(b), Some(a));
+        assert_eq!(event.other(Entity::from_raw(3)), None);
+    }
+
+    #[test]
+    fn test_collision_events_are_events() {
+        fn assert_event<T: Event>() {}
+        assert_event::<CollisionEnter>();
+        assert_event::<CollisionStay>();
+        assert_event::<CollisionExit>();
+    }
+}
@@ -0,0 +1,221 @@
+//! Collision system
+//!
+//! Lightweight collision detection without a physics engine:
+//! - Layers and masks (enemy, tendril, projectile, core, pickup)
+//! - Circle and capsule shapes, capsules spanning parent to segment
+//! - Spatial grid broad phase
+//! - Enter, stay and exit events for gameplay systems to consume
+
+use bevy::prelude::*;
+
+use crate::GameState;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+pub mod shapes;
+mod systems;
+
+pub use components::*;
+pub use events::*;
+pub use resources::*;
+pub use shapes::{shapes_overlap, Shape};
+pub use systems::{CORE_COLLIDER_RADIUS, TENDRIL_COLLIDER_RADIUS};
+
+/// System set for collision detection; consumers run after it
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollisionSet;
+
+/// Plugin for the collision system
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Contacts>()
+            .add_event::<CollisionEnter>()
+            .add_event::<CollisionStay>()
+            .add_event::<CollisionExit>()
+            .add_systems(OnEnter(GameState::Menu), systems::clear_contacts)
+            .add_systems(OnEnter(GameState::GameOver), systems::clear_contacts)
+            .add_systems(
+                Update,
+                (
+                    systems::attach_network_colliders,
+                    systems::detect_collisions,
+                )
+                    .chain()
+                    .in_set(CollisionSet)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::network::{CoreNode, NetworkParent, TendrilPosition, TendrilSegment};
+    use bevy::state::app::StatesPlugin;
+
+    /// Collects collision events across frames
+    #[derive(Resource, Default)]
+    struct Log {
+        enter: Vec<(Entity, Entity)>,
+        stay: Vec<(Entity, Entity)>,
+        exit: Vec<(Entity, Entity)>,
+    }
+
+    fn record(
+        mut log: ResMut<Log>,
+        mut enter: EventReader<CollisionEnter>,
+        mut stay: EventReader<CollisionStay>,
+        mut exit: EventReader<CollisionExit>,
+    ) {
+        log.enter.extend(enter.read().map(|e| (e.a, e.b)));
+        log.stay.extend(stay.read().map(|e| (e.a, e.b)));
+        log.exit.extend(exit.read().map(|e| (e.a, e.b)));
+    }
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .add_plugins(CollisionPlugin)
+            .init_resource::<Log>()
+            .add_systems(Update, record.after(CollisionSet));
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec2, radius: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Collider::Circle { radius },
+                CollisionLayers::new(Layer::Enemy, LayerMask::TENDRIL | LayerMask::CORE),
+                Transform::from_translation(position.extend(0.0)),
+            ))
+            .id()
+    }
+
+    fn spawn_segment(app: &mut App, position: Vec2, parent: Option<Entity>) -> Entity {
+        let mut segment = app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(position, Vec2::X),
+        ));
+        if let Some(parent) = parent {
+            segment.insert(NetworkParent(parent));
+        }
+        segment.id()
+    }
+
+    fn log(app: &App) -> &Log {
+        app.world().resource::<Log>()
+    }
+
+    fn pair(a: Entity, b: Entity) -> (Entity, Entity) {
+        Contacts::key(a, b)
+    }
+
+    #[test]
+    fn test_collision_plugin_builds() {
+        let mut app = create_test_app();
+        app.update();
+        assert!(app.world().get_resource::<Contacts>().is_some());
+    }
+
+    #[test]
+    fn test_network_entities_get_colliders() {
+        let mut app = create_test_app();
+        let segment = spawn_segment(&mut app, Vec2::ZERO, None);
+        let core = app.world_mut().spawn((CoreNode, Transform::default())).id();
+        app.update();
+
+        let layers = app.world().get::<CollisionLayers>(segment).unwrap();
+        assert!(layers.membership.contains(Layer::Tendril));
+        assert!(matches!(
+            app.world().get::<Collider>(segment),
+            Some(Collider::Capsule { .. })
+        ));
+        let layers = app.world().get::<CollisionLayers>(core).unwrap();
+        assert!(layers.membership.contains(Layer::Core));
+    }
+
+    #[test]
+    fn test_enter_stay_exit_sequence() {
+        let mut app = create_test_app();
+        let segment = spawn_segment(&mut app, Vec2::ZERO, None);
+        let enemy = spawn_enemy(&mut app, Vec2::new(8.0, 0.0), 5.0);
+
+        app.update();
+        app.update();
+        assert_eq!(log(&app).enter, vec![pair(segment, enemy)]);
+        assert_eq!(log(&app).stay.len(), 2);
+        assert!(log(&app).exit.is_empty());
+
+        app.world_mut()
+            .get_mut::<Transform>(enemy)
+            .unwrap()
+            .translation
+            .x = 100.0;
+        app.update();
+        assert_eq!(log(&app).exit, vec![pair(segment, enemy)]);
+        assert_eq!(log(&app).stay.len(), 2);
+        assert!(app.world().resource::<Contacts>().is_empty());
+    }
+
+    #[test]
+    fn test_despawn_sends_exit() {
+        let mut app = create_test_app();
+        spawn_segment(&mut app, Vec2::ZERO, None);
+        let enemy = spawn_enemy(&mut app, Vec2::ZERO, 5.0);
+        app.update();
+
+        app.world_mut().entity_mut(enemy).despawn();
+        app.update();
+        assert_eq!(log(&app).exit.len(), 1);
+    }
+
+    #[test]
+    fn test_layers_filter_pairs() {
+        let mut app = create_test_app();
+        spawn_enemy(&mut app, Vec2::ZERO, 5.0);
+        spawn_enemy(&mut app, Vec2::new(2.0, 0.0), 5.0);
+        app.update();
+
+        // Enemies do not collide with each other
+        assert!(log(&app).enter.is_empty());
+    }
+
+    #[test]
+    fn test_capsule_spans_from_parent() {
+        let mut app = create_test_app();
+        let parent = spawn_segment(&mut app, Vec2::ZERO, None);
+        let child = spawn_segment(&mut app, Vec2::new(100.0, 0.0), Some(parent));
+        // Touches the middle of the parent-child capsule only
+        let enemy = spawn_enemy(&mut app, Vec2::new(50.0, 6.0), 3.0);
+        app.update();
+
+        let contacts = app.world().resource::<Contacts>();
+        assert!(contacts.contains(child, enemy));
+        assert!(!contacts.contains(parent, enemy));
+    }
+
+    #[test]
+    fn test_contacts_cleared_on_menu() {
+        let mut app = create_test_app();
+        spawn_segment(&mut app, Vec2::ZERO, None);
+        spawn_enemy(&mut app, Vec2::ZERO, 5.0);
+        app.update();
+        assert_eq!(app.world().resource::<Contacts>().len(), 1);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Menu);
+        app.update();
+        assert!(app.world().resource::<Contacts>().is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+//! Collision resources
+
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+/// Pairs of entities overlapping as of the last collision pass
+#[derive(Resource, Debug, Default)]
+pub struct Contacts {
+    pairs: HashSet<(Entity, Entity)>,
+}
+
+impl Contacts {
+    /// Order a pair so that lookups do not depend on argument order
+    #[must_use]
+    pub fn key(a: Entity, b: Entity) -> (Entity, Entity) {
+        if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Whether two entities are touching
+    #[must_use]
+    pub fn contains(&self, a: Entity, b: Entity) -> bool {
+        self.pairs.contains(&Self::key(a, b))
+    }
+
+    /// Every entity currently touching `entity`
+    pub fn partners(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.pairs.iter().filter_map(move |&(a, b)| {
+            if a == entity {
+                Some(b)
+            } else if b == entity {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Number of touching pairs
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Whether nothing is touching
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Replace the contact set, returning the previous one
+    pub(crate) fn replace(
+        &mut self,
+        pairs: HashSet<(Entity, Entity)>,
+    ) -> HashSet<(Entity, Entity)> {
+        std::mem::replace(&mut self.pairs, pairs)
+    }
+
+    /// Forget all contacts
+    pub fn clear(&mut self) {
+        self.pairs.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contacts_are_order_independent() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let mut contacts = Contacts::default();
+        let mut pairs = HashSet::new();
+        pairs.insert(Contacts::key(b, a));
+        contacts.replace(pairs);
+
+        assert!(contacts.contains(a, b));
+        assert!(contacts.contains(b, a));
+        assert_eq!(contacts.partners(a).collect::<Vec<_>>(), vec![b]);
+        assert_eq!(contacts.len(), 1);
+
+        contacts.clear();
+        assert!(contacts.is_empty());
+    }
+
+    #[test]
+    fn test_contacts_is_resource() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<Contacts>();
+    }
+}
@@ -0,0 +1,182 @@
+//! Collision shapes
+//!
+//! Pure geometry for circle and capsule overlap tests.
+
+use bevy::prelude::*;
+
+/// A collision shape resolved to world space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    Circle {
+        center: Vec2,
+        radius: f32,
+    },
+    /// Line segment from `a` to `b` thickened by `radius`
+    Capsule {
+        a: Vec2,
+        b: Vec2,
+        radius: f32,
+    },
+}
+
+impl Shape {
+    /// Center of the smallest circle enclosing the shape
+    #[must_use]
+    pub fn bounding_center(&self) -> Vec2 {
+        match *self {
+            Self::Circle { center, .. } => center,
+            Self::Capsule { a, b, .. } => (a + b) * 0.5,
+        }
+    }
+
+    /// Radius of the smallest circle enclosing the shape
+    #[must_use]
+    pub fn bounding_radius(&self) -> f32 {
+        match *self {
+            Self::Circle { radius, .. } => radius,
+            Self::Capsule { a, b, radius } => a.distance(b) * 0.5 + radius,
+        }
+    }
+
+    /// Core segment and thickness; a circle is a zero-length segment
+    fn segment(&self) -> (Vec2, Vec2, f32) {
+        match *self {
+            Self::Circle { center, radius } => (center, center, radius),
+            Self::Capsule { a, b, radius } => (a, b, radius),
+        }
+    }
+}
+
+/// Closest point to `p` on the segment `a`-`b`
+#[must_use]
+pub fn closest_point_on_segment(p: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq <= f32::EPSILON {
+        return a;
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+/// Squared distance between segments `p1`-`q1` and `p2`-`q2`
+#[must_use]
+pub fn segment_distance_squared(p1: Vec2, q1: Vec2, p2: Vec2, q2: Vec2) -> f32 {
+    if segments_intersect(p1, q1, p2, q2) {
+        return 0.0;
+    }
+    [
+        p1.distance_squared(closest_point_on_segment(p1, p2, q2)),
+        q1.distance_squared(closest_point_on_segment(q1, p2, q2)),
+        p2.distance_squared(closest_point_on_segment(p2, p1, q1)),
+        q2.distance_squared(closest_point_on_segment(q2, p1, q1)),
+    ]
+    .into_iter()
+    .fold(f32::MAX, f32::min)
+}
+
+/// Whether two segments properly cross each other
+fn segments_intersect(p1: Vec2, q1: Vec2, p2: Vec2, q2: Vec2) -> bool {
+    let d1 = (q1 - p1).perp_dot(p2 - p1);
+    let d2 = (q1 - p1).perp_dot(q2 - p1);
+    let d3 = (q2 - p2).perp_dot(p1 - p2);
+    let d4 = (q2 - p2).perp_dot(q1 - p2);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+/// Whether two shapes overlap (touching counts)
+#[must_use]
+pub fn shapes_overlap(a: &Shape, b: &Shape) -> bool {
+    let (a1, a2, ra) = a.segment();
+    let (b1, b2, rb) = b.segment();
+    let reach = ra + rb;
+    segment_distance_squared(a1, a2, b1, b2) <= reach * reach
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circle(x: f32, y: f32, radius: f32) -> Shape {
+        Shape::Circle {
+            center: Vec2::new(x, y),
+            radius,
+        }
+    }
+
+    fn capsule(a: Vec2, b: Vec2, radius: f32) -> Shape {
+        Shape::Capsule { a, b, radius }
+    }
+
+    #[test]
+    fn test_closest_point_on_segment_clamps_to_ends() {
+        let a = Vec2::ZERO;
+        let b = Vec2::new(10.0, 0.0);
+        assert_eq!(
+            closest_point_on_segment(Vec2::new(5.0, 3.0), a, b),
+            Vec2::new(5.0, 0.0)
+        );
+        assert_eq!(closest_point_on_segment(Vec2::new(-5.0, 3.0), a, b), a);
+        assert_eq!(closest_point_on_segment(Vec2::new(15.0, 3.0), a, b), b);
+        assert_eq!(closest_point_on_segment(Vec2::ONE, a, a), a);
+    }
+
+    #[test]
+    fn test_segment_distance_for_crossing_and_parallel_segments() {
+        let crossing = segment_distance_squared(
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, -1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert_eq!(crossing, 0.0);
+
+        let parallel = segment_distance_squared(
+            Vec2::ZERO,
+            Vec2::new(10.0, 0.0),
+            Vec2::new(0.0, 3.0),
+            Vec2::new(10.0, 3.0),
+        );
+        assert_eq!(parallel, 9.0);
+    }
+
+    #[test]
+    fn test_circle_circle_overlap() {
+        assert!(shapes_overlap(
+            &circle(0.0, 0.0, 5.0),
+            &circle(9.0, 0.0, 5.0)
+        ));
+        assert!(shapes_overlap(
+            &circle(0.0, 0.0, 5.0),
+            &circle(10.0, 0.0, 5.0)
+        ));
+        assert!(!shapes_overlap(
+            &circle(0.0, 0.0, 5.0),
+            &circle(11.0, 0.0, 5.0)
+        ));
+    }
+
+    #[test]
+    fn test_circle_capsule_overlap() {
+        let tendril = capsule(Vec2::ZERO, Vec2::new(20.0, 0.0), 2.0);
+        assert!(shapes_overlap(&tendril, &circle(10.0, 5.0, 4.0)));
+        assert!(!shapes_overlap(&tendril, &circle(10.0, 7.0, 4.0)));
+        assert!(!shapes_overlap(&tendril, &circle(27.0, 0.0, 4.0)));
+    }
+
+    #[test]
+    fn test_capsule_capsule_overlap() {
+        let a = capsule(Vec2::ZERO, Vec2::new(20.0, 0.0), 1.0);
+        let crossing = capsule(Vec2::new(10.0, -10.0), Vec2::new(10.0, 10.0), 1.0);
+        let apart = capsule(Vec2::new(0.0, 5.0), Vec2::new(20.0, 5.0), 1.0);
+        assert!(shapes_overlap(&a, &crossing));
+        assert!(!shapes_overlap(&a, &apart));
+    }
+
+    #[test]
+    fn test_bounding_circle_encloses_capsule() {
+        let shape = capsule(Vec2::ZERO, Vec2::new(20.0, 0.0), 2.0);
+        assert_eq!(shape.bounding_center(), Vec2::new(10.0, 0.0));
+        assert_eq!(shape.bounding_radius(), 12.0);
+    }
+}
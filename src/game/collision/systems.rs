@@ -0,0 +1,141 @@
+//! Collision systems
+//!
+//! Shapes are rebuilt every frame, bucketed in a spatial grid for the broad
+//! phase, then tested exactly. Contacts are diffed against the previous
+//! frame to produce enter/stay/exit events.
+
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use super::components::{Collider, CollisionLayers, Layer, LayerMask};
+use super::events::{CollisionEnter, CollisionExit, CollisionStay};
+use super::resources::Contacts;
+use super::shapes::{shapes_overlap, Shape};
+use crate::game::network::{CoreNode, NetworkParent, TendrilPosition, TendrilSegment};
+use crate::game::spatial::SpatialGrid;
+
+/// Collider thickness of tendril segments
+pub const TENDRIL_COLLIDER_RADIUS: f32 = 4.0;
+/// Collider radius of the core node
+pub const CORE_COLLIDER_RADIUS: f32 = 16.0;
+/// Cell size of the broad-phase grid
+const GRID_CELL_SIZE: f32 = 64.0;
+
+/// World position of an entity: `TendrilPosition` first, then `Transform`
+fn world_position(
+    tendril: Option<&TendrilPosition>,
+    transform: Option<&Transform>,
+) -> Option<Vec2> {
+    tendril
+        .map(|t| t.position)
+        .or_else(|| transform.map(|t| t.translation.truncate()))
+}
+
+/// Give tendril segments and the core node colliders on their layers
+pub fn attach_network_colliders(
+    mut commands: Commands,
+    segments: Query<Entity, (With<TendrilSegment>, Without<Collider>)>,
+    cores: Query<Entity, (With<CoreNode>, Without<Collider>)>,
+) {
+    let targets = LayerMask::ENEMY | LayerMask::PICKUP;
+    for entity in segments.iter() {
+        commands.entity(entity).insert((
+            Collider::Capsule {
+                radius: TENDRIL_COLLIDER_RADIUS,
+            },
+            CollisionLayers::new(Layer::Tendril, targets),
+        ));
+    }
+    for entity in cores.iter() {
+        commands.entity(entity).insert((
+            Collider::Circle {
+                radius: CORE_COLLIDER_RADIUS,
+            },
+            CollisionLayers::new(Layer::Core, targets),
+        ));
+    }
+}
+
+/// Find overlapping colliders and send enter/stay/exit events
+pub fn detect_collisions(
+    colliders: Query<(
+        Entity,
+        &Collider,
+        &CollisionLayers,
+        Option<&TendrilPosition>,
+        Option<&Transform>,
+        Option<&NetworkParent>,
+    )>,
+    positions: Query<(Option<&TendrilPosition>, Option<&Transform>)>,
+    mut grid: Local<SpatialGrid>,
+    mut contacts: ResMut<Contacts>,
+    mut enter: EventWriter<CollisionEnter>,
+    mut stay: EventWriter<CollisionStay>,
+    mut exit: EventWriter<CollisionExit>,
+) {
+    if grid.cell_size() != GRID_CELL_SIZE {
+        *grid = SpatialGrid::new(GRID_CELL_SIZE);
+    }
+    grid.clear();
+
+    // Resolve shapes in world space
+    let mut shapes: HashMap<Entity, (Shape, CollisionLayers)> = HashMap::new();
+    let mut max_radius: f32 = 0.0;
+    for (entity, collider, layers, tendril, transform, parent) in colliders.iter() {
+        let Some(position) = world_position(tendril, transform) else {
+            continue;
+        };
+        let parent_position = parent
+            .and_then(|p| positions.get(p.0).ok())
+            .and_then(|(t, tr)| world_position(t, tr));
+        let shape = match (*collider, parent_position) {
+            (Collider::Capsule { radius }, Some(a)) => Shape::Capsule {
+                a,
+                b: position,
+                radius,
+            },
+            (collider, _) => Shape::Circle {
+                center: position,
+                radius: collider.radius(),
+            },
+        };
+        max_radius = max_radius.max(shape.bounding_radius());
+        grid.insert(entity, shape.bounding_center());
+        shapes.insert(entity, (shape, *layers));
+    }
+
+    // Broad phase through the grid, then exact tests
+    let mut current = HashSet::new();
+    for (&entity, (shape, layers)) in shapes.iter() {
+        let reach = shape.bounding_radius() + max_radius;
+        for (other, _) in grid.query_radius(shape.bounding_center(), reach) {
+            if other <= entity {
+                continue;
+            }
+            let Some((other_shape, other_layers)) = shapes.get(&other) else {
+                continue;
+            };
+            if layers.interacts(other_layers) && shapes_overlap(shape, other_shape) {
+                current.insert((entity, other));
+            }
+        }
+    }
+
+    for &(a, b) in current.iter() {
+        if !contacts.contains(a, b) {
+            enter.send(CollisionEnter { a, b });
+        }
+        stay.send(CollisionStay { a, b });
+    }
+    let previous = contacts.replace(current);
+    for (a, b) in previous {
+        if !contacts.contains(a, b) {
+            exit.send(CollisionExit { a, b });
+        }
+    }
+}
+
+/// Forget all contacts when a run ends
+pub fn clear_contacts(mut contacts: ResMut<Contacts>) {
+    contacts.clear();
+}
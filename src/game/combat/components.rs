@@ -0,0 +1,174 @@
+//! Combat components
+
+use bevy::prelude::*;
+
+use crate::game::network::TendrilType;
+
+/// Automatic attack emitter attached to a tendril segment
+///
+/// Stats (cooldown, range, damage, targeting) are looked up from the
+/// `AttackTable` by tendril type every frame, so upgrades to the table
+/// apply to existing emitters immediately. Only the cooldown is tracked
+/// per emitter.
+#[derive(Component, Debug, Clone)]
+pub struct AttackEmitter {
+    /// Tendril type used to look up stats in the `AttackTable`
+    pub tendril_type: TendrilType,
+    /// Seconds until this emitter can fire again
+    pub cooldown_remaining: f32,
+}
+
+impl AttackEmitter {
+    /// Create an emitter that is ready to fire immediately
+    #[must_use]
+    pub fn new(tendril_type: TendrilType) -> Self {
+        Self {
+            tendril_type,
+            cooldown_remaining: 0.0,
+        }
+    }
+
+    /// Whether the cooldown has elapsed
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.cooldown_remaining <= 0.0
+    }
+
+    /// Advance the cooldown by `delta` seconds
+    ///
+    /// Overshoot past zero is kept so that fire rate does not depend on
+    /// frame rate. An emitter that is already ready does not tick further.
+    pub fn tick(&mut self, delta: f32) {
+        if self.cooldown_remaining > 0.0 {
+            self.cooldown_remaining -= delta;
+        }
+    }
+
+    /// Restart the cooldown after firing, carrying over any overshoot
+    pub fn trigger(&mut self, cooldown: f32) {
+        self.cooldown_remaining = (self.cooldown_remaining + cooldown).max(0.0);
+    }
+
+    /// Wait at zero while there is nothing to shoot, so no shots are banked
+    pub fn hold(&mut self) {
+        self.cooldown_remaining = self.cooldown_remaining.max(0.0);
+    }
+}
+
+/// Marker for entities that attack emitters may target
+#[derive(Component, Debug, Default)]
+pub struct Hostile;
+
+/// Marker for entities that are never moved by knockback
+#[derive(Component, Debug, Default)]
+pub struct Static;
+
+/// Velocity pushing an entity away from an impact, decaying over time
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Knockback {
+    pub velocity: Vec2,
+}
+
+impl Knockback {
+    /// Fraction of velocity lost per second (exponential decay rate)
+    pub const DAMPING: f32 = 8.0;
+
+    /// Speed below which the knockback is considered finished
+    pub const MIN_SPEED: f32 = 1.0;
+
+    /// Add an impulse to the current velocity
+    pub fn push(&mut self, impulse: Vec2) {
+        self.velocity += impulse;
+    }
+
+    /// Decay the velocity by `delta` seconds of damping
+    pub fn decay(&mut self, delta: f32) {
+        self.velocity *= (-Self::DAMPING * delta).exp();
+    }
+
+    /// Whether the knockback has run out
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.velocity.length_squared() < Self::MIN_SPEED * Self::MIN_SPEED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attack_emitter_new_is_ready() {
+        let emitter = AttackEmitter::new(TendrilType::Toxic);
+        assert_eq!(emitter.tendril_type, TendrilType::Toxic);
+        assert!(emitter.is_ready());
+    }
+
+    #[test]
+    fn test_attack_emitter_trigger_starts_cooldown() {
+        let mut emitter = AttackEmitter::new(TendrilType::Basic);
+        emitter.trigger(1.0);
+        assert!(!emitter.is_ready());
+        assert_eq!(emitter.cooldown_remaining, 1.0);
+    }
+
+    #[test]
+    fn test_attack_emitter_tick_counts_down() {
+        let mut emitter = AttackEmitter::new(TendrilType::Basic);
+        emitter.trigger(1.0);
+        emitter.tick(0.25);
+        assert_eq!(emitter.cooldown_remaining, 0.75);
+        emitter.tick(0.75);
+        assert!(emitter.is_ready());
+    }
+
+    #[test]
+    fn test_attack_emitter_trigger_carries_overshoot() {
+        let mut emitter = AttackEmitter::new(TendrilType::Basic);
+        emitter.trigger(1.0);
+        emitter.tick(1.25);
+        emitter.trigger(1.0);
+        assert_eq!(emitter.cooldown_remaining, 0.75);
+    }
+
+    #[test]
+    fn test_attack_emitter_ready_does_not_tick_below_zero() {
+        let mut emitter = AttackEmitter::new(TendrilType::Basic);
+        emitter.tick(5.0);
+        assert_eq!(emitter.cooldown_remaining, 0.0);
+    }
+
+    #[test]
+    fn test_attack_emitter_hold_does_not_bank_shots() {
+        let mut emitter = AttackEmitter::new(TendrilType::Basic);
+        emitter.trigger(1.0);
+        emitter.tick(5.0);
+        emitter.hold();
+        assert_eq!(emitter.cooldown_remaining, 0.0);
+    }
+
+    #[test]
+    fn test_attack_emitter_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<AttackEmitter>();
+    }
+
+    #[test]
+    fn test_hostile_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<Hostile>();
+    }
+
+    #[test]
+    fn test_knockback_decays_to_finished() {
+        let mut knockback = Knockback::default();
+        knockback.push(Vec2::new(100.0, 0.0));
+        assert!(!knockback.is_finished());
+
+        knockback.decay(0.1);
+        assert!(knockback.velocity.x < 100.0 && knockback.velocity.x > 0.0);
+
+        knockback.decay(2.0);
+        assert!(knockback.is_finished());
+    }
+}
@@ -0,0 +1,198 @@
+//! Contact effects
+//!
+//! Consumers of collision events:
+//! - Contact damage from entities with `ContactDamage`
+//! - Toxic and Sticky tendril segments poisoning and slowing hostiles they touch
+
+use bevy::prelude::*;
+
+use super::components::Hostile;
+use super::damage::{DamageEvent, DamageType};
+use super::status::{ApplyStatus, OnHitStatusTable, StackingRule};
+use crate::game::collision::{CollisionEnter, CollisionLayers, CollisionStay, LayerMask};
+use crate::game::network::TendrilSegment;
+
+/// Damage dealt continuously to touching entities on the target layers
+#[derive(Component, Debug, Clone)]
+pub struct ContactDamage {
+    pub damage_per_second: f32,
+    pub damage_type: DamageType,
+    /// Layers that take contact damage
+    pub targets: LayerMask,
+}
+
+impl ContactDamage {
+    /// Physical contact damage against the fungal network
+    #[must_use]
+    pub fn against_network(damage_per_second: f32) -> Self {
+        Self {
+            damage_per_second,
+            damage_type: DamageType::Physical,
+            targets: LayerMask::TENDRIL | LayerMask::CORE,
+        }
+    }
+}
+
+/// Deal contact damage for every frame two entities touch
+pub fn contact_damage(
+    time: Res<Time>,
+    mut contacts: EventReader<CollisionStay>,
+    dealers: Query<&ContactDamage>,
+    layers: Query<&CollisionLayers>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    let delta = time.delta_secs();
+    for contact in contacts.read() {
+        for (source, target) in [(contact.a, contact.b), (contact.b, contact.a)] {
+            let Ok(dealer) = dealers.get(source) else {
+                continue;
+            };
+            let on_target_layer = layers
+                .get(target)
+                .is_ok_and(|l| l.membership.intersects(dealer.targets));
+            if on_target_layer {
+                damage.send(DamageEvent::new(
+                    Some(source),
+                    target,
+                    dealer.damage_per_second * delta,
+                    dealer.damage_type,
+                ));
+            }
+        }
+    }
+}
+
+/// Apply tendril on-hit statuses to hostiles touching a segment
+///
+/// Touching for the first time follows the effect's stacking rule; staying
+/// in contact only refreshes the duration.
+pub fn contact_status(
+    mut enter: EventReader<CollisionEnter>,
+    mut stay: EventReader<CollisionStay>,
+    table: Res<OnHitStatusTable>,
+    segments: Query<&TendrilSegment>,
+    hostiles: Query<(), With<Hostile>>,
+    mut apply: EventWriter<ApplyStatus>,
+) {
+    let entered = enter.read().map(|e| (e.a, e.b, true));
+    let stayed = stay.read().map(|e| (e.a, e.b, false));
+    for (a, b, first) in entered.chain(stayed) {
+        for (segment, target) in [(a, b), (b, a)] {
+            let Ok(tendril) = segments.get(segment) else {
+                continue;
+            };
+            if !hostiles.contains(target) {
+                continue;
+            }
+            let Some(effect) = table.get(tendril.tendril_type) else {
+                continue;
+            };
+            let mut effect = effect.clone().with_source(Some(segment));
+            if !first {
+                effect.stacking = StackingRule::Refresh;
+            }
+            apply.send(ApplyStatus { target, effect });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::collision::{Collider, Layer};
+    use crate::game::combat::test_utils::{create_test_app, enter_state};
+    use crate::game::combat::{StatusEffects, StatusKind};
+    use crate::game::network::{Health, TendrilPosition, TendrilType};
+    use crate::GameState;
+
+    fn create_contact_app() -> App {
+        let mut app = create_test_app();
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn spawn_segment(app: &mut App, tendril_type: TendrilType, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                TendrilSegment {
+                    tendril_type,
+                    ..default()
+                },
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id()
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Hostile,
+                Health::new(100.0),
+                ContactDamage::against_network(8.0),
+                CollisionLayers::new(Layer::Enemy, LayerMask::TENDRIL | LayerMask::CORE),
+                Collider::Circle { radius: 5.0 },
+                Transform::from_translation(position.extend(0.0)),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_contact_damage_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<ContactDamage>();
+    }
+
+    #[test]
+    fn test_contact_damage_hurts_touching_segment() {
+        let mut app = create_contact_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        spawn_enemy(&mut app, Vec2::new(4.0, 0.0));
+
+        // 1 second of contact at 8 damage per second
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let health = app.world().get::<TendrilSegment>(segment).unwrap().health;
+        assert_eq!(health, TendrilSegment::default().health - 8.0);
+    }
+
+    #[test]
+    fn test_no_contact_damage_at_distance() {
+        let mut app = create_contact_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        spawn_enemy(&mut app, Vec2::new(200.0, 0.0));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let health = app.world().get::<TendrilSegment>(segment).unwrap().health;
+        assert_eq!(health, TendrilSegment::default().health);
+    }
+
+    #[test]
+    fn test_sticky_contact_slows_hostile() {
+        let mut app = create_contact_app();
+        spawn_segment(&mut app, TendrilType::Sticky, Vec2::ZERO);
+        let enemy = spawn_enemy(&mut app, Vec2::new(4.0, 0.0));
+        app.update();
+        app.update();
+
+        let effects = app.world().get::<StatusEffects>(enemy).unwrap();
+        assert!(effects.speed_multiplier() < 1.0);
+    }
+
+    #[test]
+    fn test_toxic_contact_stacks_once_per_touch() {
+        let mut app = create_contact_app();
+        spawn_segment(&mut app, TendrilType::Toxic, Vec2::ZERO);
+        let enemy = spawn_enemy(&mut app, Vec2::new(4.0, 0.0));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let effects = app.world().get::<StatusEffects>(enemy).unwrap();
+        assert_eq!(effects.get(StatusKind::Poison).unwrap().stacks, 1);
+    }
+}
@@ -0,0 +1,430 @@
+//! Unified damage pipeline
+//!
+//! All damage goes through a single `DamageEvent` processed by
+//! `apply_damage`, which:
+//! - Applies resistances and armor
+//! - Updates either `Health` or `TendrilSegment::health`
+//! - Fires `DamageDealt` for on-hit effects and logging
+//! - Fires `Died` and counts kills when a target reaches zero health
+
+use bevy::prelude::*;
+
+use super::components::Hostile;
+use super::projectile::ProjectileHit;
+use crate::game::network::{Health, TendrilSegment, TendrilType};
+use crate::game::state::RunStats;
+
+/// Kind of damage, used to look up resistances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DamageType {
+    #[default]
+    Physical,
+    Toxic,
+    Explosive,
+    Corrupting,
+}
+
+impl DamageType {
+    /// Damage type dealt by a tendril type's attacks
+    #[must_use]
+    pub fn for_tendril(tendril_type: TendrilType) -> Self {
+        match tendril_type {
+            TendrilType::Basic | TendrilType::Sticky => Self::Physical,
+            TendrilType::Toxic => Self::Toxic,
+            TendrilType::Explosive => Self::Explosive,
+        }
+    }
+
+    /// Whether flat armor reduces this damage type
+    #[must_use]
+    pub fn is_armored(&self) -> bool {
+        matches!(self, Self::Physical | Self::Explosive)
+    }
+}
+
+/// Fractional damage reduction per damage type (0.0 = none, 1.0 = immune)
+///
+/// Negative values make the entity take extra damage.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Resistances {
+    pub physical: f32,
+    pub toxic: f32,
+    pub explosive: f32,
+    pub corrupting: f32,
+}
+
+impl Resistances {
+    /// Resistance to a damage type
+    #[must_use]
+    pub fn get(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Physical => self.physical,
+            DamageType::Toxic => self.toxic,
+            DamageType::Explosive => self.explosive,
+            DamageType::Corrupting => self.corrupting,
+        }
+    }
+}
+
+/// Flat damage reduction applied to physical and explosive hits
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Armor(pub f32);
+
+/// Request to damage an entity
+#[derive(Event, Debug, Clone)]
+pub struct DamageEvent {
+    /// Entity responsible, if any (None for environmental damage)
+    pub source: Option<Entity>,
+    /// Entity being damaged
+    pub target: Entity,
+    /// Damage before resistances and armor
+    pub amount: f32,
+    /// Kind of damage
+    pub damage_type: DamageType,
+}
+
+impl DamageEvent {
+    /// Create a new DamageEvent
+    #[must_use]
+    pub fn new(
+        source: Option<Entity>,
+        target: Entity,
+        amount: f32,
+        damage_type: DamageType,
+    ) -> Self {
+        Self {
+            source,
+            target,
+            amount,
+            damage_type,
+        }
+    }
+}
+
+/// Event fired after damage has been applied
+#[derive(Event, Debug, Clone)]
+pub struct DamageDealt {
+    pub source: Option<Entity>,
+    pub target: Entity,
+    /// Damage actually removed after resistances and armor
+    pub amount: f32,
+    pub damage_type: DamageType,
+}
+
+/// Event fired once when a damaged entity reaches zero health
+#[derive(Event, Debug, Clone)]
+pub struct Died {
+    /// Entity that died
+    pub entity: Entity,
+    /// Source of the killing blow, if any
+    pub killer: Option<Entity>,
+    /// Damage type of the killing blow
+    pub damage_type: DamageType,
+}
+
+/// Apply resistances and armor to a raw damage amount
+#[must_use]
+pub fn mitigate_damage(
+    amount: f32,
+    damage_type: DamageType,
+    resistances: Option<&Resistances>,
+    armor: Option<&Armor>,
+) -> f32 {
+    let resistance = resistances.map_or(0.0, |r| r.get(damage_type)).min(1.0);
+    let mut mitigated = amount * (1.0 - resistance);
+    if damage_type.is_armored() {
+        mitigated -= armor.map_or(0.0, |a| a.0);
+    }
+    mitigated.max(0.0)
+}
+
+/// Turn projectile hits into damage events
+pub fn projectile_hits_to_damage(
+    mut hits: EventReader<ProjectileHit>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    for hit in hits.read() {
+        damage.send(DamageEvent::new(
+            Some(hit.source),
+            hit.target,
+            hit.damage,
+            DamageType::for_tendril(hit.tendril_type),
+        ));
+    }
+}
+
+/// Process every damage event: the single place where health changes
+pub fn apply_damage(
+    mut events: EventReader<DamageEvent>,
+    mut targets: Query<(
+        Option<&mut Health>,
+        Option<&mut TendrilSegment>,
+        Option<&Resistances>,
+        Option<&Armor>,
+        Has<Hostile>,
+    )>,
+    mut dealt: EventWriter<DamageDealt>,
+    mut died: EventWriter<Died>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    for event in events.read() {
+        let Ok((health, segment, resistances, armor, hostile)) = targets.get_mut(event.target)
+        else {
+            continue;
+        };
+
+        let amount = mitigate_damage(event.amount, event.damage_type, resistances, armor);
+
+        let (applied, killed) = if let Some(mut health) = health {
+            if health.is_dead() {
+                continue;
+            }
+            let before = health.current;
+            health.damage(amount);
+            (before - health.current, health.is_dead())
+        } else if let Some(mut segment) = segment {
+            if segment.health <= 0.0 {
+                continue;
+            }
+            let before = segment.health;
+            segment.health = (segment.health - amount).max(0.0);
+            (before - segment.health, segment.health <= 0.0)
+        } else {
+            continue;
+        };
+
+        debug!(
+            "{:?} dealt {:.1} {:?} damage to {:?}",
+            event.source, applied, event.damage_type, event.target
+        );
+        dealt.send(DamageDealt {
+            source: event.source,
+            target: event.target,
+            amount: applied,
+            damage_type: event.damage_type,
+        });
+
+        if killed {
+            if hostile {
+                run_stats.enemies_killed += 1;
+            }
+            died.send(Died {
+                entity: event.target,
+                killer: event.source,
+                damage_type: event.damage_type,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::test_utils::{create_test_app, enter_state};
+    use crate::GameState;
+
+    /// Collects `Died` events across frames
+    #[derive(Resource, Default)]
+    struct Deaths(Vec<Entity>);
+
+    fn collect_deaths(mut events: EventReader<Died>, mut deaths: ResMut<Deaths>) {
+        deaths.0.extend(events.read().map(|e| e.entity));
+    }
+
+    fn create_damage_app() -> App {
+        let mut app = create_test_app();
+        app.init_resource::<Deaths>()
+            .add_systems(Update, collect_deaths.after(apply_damage));
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn damage(app: &mut App, target: Entity, amount: f32, damage_type: DamageType) {
+        app.world_mut()
+            .send_event(DamageEvent::new(None, target, amount, damage_type));
+        app.update();
+    }
+
+    #[test]
+    fn test_damage_type_for_tendril() {
+        assert_eq!(
+            DamageType::for_tendril(TendrilType::Basic),
+            DamageType::Physical
+        );
+        assert_eq!(
+            DamageType::for_tendril(TendrilType::Toxic),
+            DamageType::Toxic
+        );
+        assert_eq!(
+            DamageType::for_tendril(TendrilType::Explosive),
+            DamageType::Explosive
+        );
+    }
+
+    #[test]
+    fn test_mitigate_damage_without_defenses() {
+        assert_eq!(
+            mitigate_damage(10.0, DamageType::Physical, None, None),
+            10.0
+        );
+    }
+
+    #[test]
+    fn test_mitigate_damage_applies_resistance() {
+        let resistances = Resistances {
+            toxic: 0.5,
+            ..default()
+        };
+        let amount = mitigate_damage(10.0, DamageType::Toxic, Some(&resistances), None);
+        assert_eq!(amount, 5.0);
+        let amount = mitigate_damage(10.0, DamageType::Physical, Some(&resistances), None);
+        assert_eq!(amount, 10.0);
+    }
+
+    #[test]
+    fn test_mitigate_damage_negative_resistance_amplifies() {
+        let resistances = Resistances {
+            explosive: -0.5,
+            ..default()
+        };
+        let amount = mitigate_damage(10.0, DamageType::Explosive, Some(&resistances), None);
+        assert_eq!(amount, 15.0);
+    }
+
+    #[test]
+    fn test_mitigate_damage_armor_only_reduces_armored_types() {
+        let armor = Armor(3.0);
+        assert_eq!(
+            mitigate_damage(10.0, DamageType::Physical, None, Some(&armor)),
+            7.0
+        );
+        assert_eq!(
+            mitigate_damage(10.0, DamageType::Toxic, None, Some(&armor)),
+            10.0
+        );
+    }
+
+    #[test]
+    fn test_mitigate_damage_never_negative() {
+        let armor = Armor(50.0);
+        assert_eq!(
+            mitigate_damage(10.0, DamageType::Physical, None, Some(&armor)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_damage_events_are_events() {
+        fn assert_event<T: Event>() {}
+        assert_event::<DamageEvent>();
+        assert_event::<DamageDealt>();
+        assert_event::<Died>();
+    }
+
+    #[test]
+    fn test_damage_event_updates_health() {
+        let mut app = create_damage_app();
+        let target = app.world_mut().spawn(Health::new(50.0)).id();
+
+        damage(&mut app, target, 20.0, DamageType::Physical);
+
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 30.0);
+    }
+
+    #[test]
+    fn test_damage_event_updates_segment_health() {
+        let mut app = create_damage_app();
+        let segment = app.world_mut().spawn(TendrilSegment::default()).id();
+
+        damage(&mut app, segment, 40.0, DamageType::Corrupting);
+
+        assert_eq!(
+            app.world().get::<TendrilSegment>(segment).unwrap().health,
+            60.0
+        );
+    }
+
+    #[test]
+    fn test_damage_event_applies_armor_and_resistance() {
+        let mut app = create_damage_app();
+        let target = app
+            .world_mut()
+            .spawn((
+                Health::new(100.0),
+                Armor(2.0),
+                Resistances {
+                    physical: 0.5,
+                    ..default()
+                },
+            ))
+            .id();
+
+        damage(&mut app, target, 20.0, DamageType::Physical);
+
+        // 20 * 0.5 - 2 = 8
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 92.0);
+    }
+
+    #[test]
+    fn test_lethal_damage_fires_died_once() {
+        let mut app = create_damage_app();
+        let target = app.world_mut().spawn(Health::new(10.0)).id();
+
+        damage(&mut app, target, 15.0, DamageType::Physical);
+        damage(&mut app, target, 15.0, DamageType::Physical);
+
+        assert_eq!(app.world().resource::<Deaths>().0, vec![target]);
+    }
+
+    #[test]
+    fn test_hostile_deaths_count_as_kills() {
+        let mut app = create_damage_app();
+        let enemy = app.world_mut().spawn((Hostile, Health::new(10.0))).id();
+        let segment = app.world_mut().spawn(TendrilSegment::default()).id();
+
+        damage(&mut app, enemy, 10.0, DamageType::Toxic);
+        damage(&mut app, segment, 500.0, DamageType::Corrupting);
+
+        assert_eq!(app.world().resource::<RunStats>().enemies_killed, 1);
+        assert_eq!(app.world().resource::<Deaths>().0.len(), 2);
+    }
+
+    #[test]
+    fn test_projectile_hit_becomes_typed_damage() {
+        let mut app = create_damage_app();
+        let target = app
+            .world_mut()
+            .spawn((
+                Health::new(50.0),
+                Resistances {
+                    toxic: 0.5,
+                    ..default()
+                },
+            ))
+            .id();
+
+        app.world_mut().send_event(ProjectileHit {
+            projectile: Entity::PLACEHOLDER,
+            source: Entity::PLACEHOLDER,
+            target,
+            tendril_type: TendrilType::Toxic,
+            damage: 10.0,
+            position: Vec2::ZERO,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 45.0);
+    }
+
+    #[test]
+    fn test_damage_ignored_outside_playing() {
+        let mut app = create_damage_app();
+        let target = app.world_mut().spawn(Health::new(50.0)).id();
+
+        enter_state(&mut app, GameState::Paused);
+        damage(&mut app, target, 20.0, DamageType::Physical);
+
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 50.0);
+    }
+}
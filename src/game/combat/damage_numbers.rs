@@ -0,0 +1,378 @@
+//! Floating damage numbers
+//!
+//! World-space numbers that rise and fade above damaged entities:
+//! - Coloured by damage type
+//! - Hits of one damage type on one target merge into the live number for
+//!   them, so continuous damage shows a single running total
+//! - Entity pooling (numbers are recycled, never despawned mid-run)
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::damage::{DamageDealt, DamageType};
+use super::pool::{EntityPool, Pooled};
+use crate::game::network::TendrilPosition;
+
+/// Number of pooled damage numbers created when a run starts
+const POOL_PREWARM: usize = 64;
+/// Hard cap on pooled damage numbers; extra hits get no number
+const POOL_MAX: usize = 512;
+/// Seconds a number stays visible
+const NUMBER_LIFETIME: f32 = 0.8;
+/// Rise speed in world units per second
+const NUMBER_RISE_SPEED: f32 = 40.0;
+/// Vertical offset above the target where numbers appear
+const NUMBER_OFFSET: f32 = 12.0;
+/// Z layer so numbers draw above the world
+const NUMBER_Z: f32 = 50.0;
+/// Font size of damage numbers
+const NUMBER_FONT_SIZE: f32 = 14.0;
+
+/// Display colour for a damage type
+#[must_use]
+pub fn damage_type_color(damage_type: DamageType) -> Color {
+    match damage_type {
+        DamageType::Physical => Color::srgb(1.0, 1.0, 1.0),
+        DamageType::Toxic => Color::srgb(0.5, 1.0, 0.3),
+        DamageType::Explosive => Color::srgb(1.0, 0.6, 0.2),
+        DamageType::Corrupting => Color::srgb(0.7, 0.3, 0.9),
+    }
+}
+
+/// Sum damage events per target and damage type
+#[must_use]
+pub fn merge_hits<'a>(
+    events: impl IntoIterator<Item = &'a DamageDealt>,
+) -> HashMap<(Entity, DamageType), f32> {
+    let mut merged: HashMap<(Entity, DamageType), f32> = HashMap::new();
+    for event in events {
+        if event.amount <= 0.0 {
+            continue;
+        }
+        *merged.entry((event.target, event.damage_type)).or_default() += event.amount;
+    }
+    merged
+}
+
+/// A pooled floating damage number
+#[derive(Component, Debug, Clone, Default)]
+pub struct DamageNumber {
+    /// Whether this number is currently shown
+    pub active: bool,
+    /// Seconds since it appeared
+    pub age: f32,
+    /// Base colour before fading
+    pub color: Color,
+    /// Entity the shown damage was dealt to
+    pub target: Option<Entity>,
+    pub damage_type: DamageType,
+    /// Total damage shown
+    pub amount: f32,
+}
+
+/// Pool of reusable damage number entities
+pub type DamageNumberPool = EntityPool<DamageNumber>;
+
+impl Pooled for DamageNumber {
+    const POOL_MAX: usize = POOL_MAX;
+
+    fn spawn_pooled(commands: &mut Commands) -> Entity {
+        commands
+            .spawn((
+                DamageNumber::default(),
+                Text2d::default(),
+                TextFont {
+                    font_size: NUMBER_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Transform::default(),
+                Visibility::Hidden,
+            ))
+            .id()
+    }
+}
+
+/// Create the initial set of pooled damage numbers
+pub fn prewarm_damage_number_pool(mut commands: Commands, mut pool: ResMut<DamageNumberPool>) {
+    pool.prewarm(&mut commands, POOL_PREWARM);
+}
+
+/// Despawn every pooled damage number when the run ends
+pub fn despawn_damage_number_pool(
+    mut commands: Commands,
+    mut pool: ResMut<DamageNumberPool>,
+    numbers: Query<Entity, With<DamageNumber>>,
+) {
+    for entity in numbers.iter() {
+        commands.entity(entity).despawn();
+    }
+    pool.clear();
+}
+
+/// Show damage above its target, adding to the live number for the same
+/// target and damage type when there is one
+///
+/// A merged number restarts its lifetime and moves back above the target.
+pub fn spawn_damage_numbers(
+    mut commands: Commands,
+    mut dealt: EventReader<DamageDealt>,
+    mut pool: ResMut<DamageNumberPool>,
+    mut numbers: Query<(Entity, &mut DamageNumber, &mut Text2d, &mut Transform)>,
+    targets: Query<(Option<&TendrilPosition>, Option<&Transform>), Without<DamageNumber>>,
+) {
+    let merged = merge_hits(dealt.read());
+    if merged.is_empty() {
+        return;
+    }
+    let live: HashMap<(Entity, DamageType), Entity> = numbers
+        .iter()
+        .filter(|(_, number, _, _)| number.active)
+        .filter_map(|(entity, number, _, _)| {
+            number
+                .target
+                .map(|target| ((target, number.damage_type), entity))
+        })
+        .collect();
+
+    for ((target, damage_type), amount) in merged {
+        let Ok((tendril, transform)) = targets.get(target) else {
+            continue;
+        };
+        let Some(position) = tendril
+            .map(|t| t.position)
+            .or_else(|| transform.map(|t| t.translation.truncate()))
+        else {
+            continue;
+        };
+        let translation = (position + Vec2::Y * NUMBER_OFFSET).extend(NUMBER_Z);
+
+        if let Some((_, mut number, mut text, mut number_transform)) = live
+            .get(&(target, damage_type))
+            .and_then(|&entity| numbers.get_mut(entity).ok())
+        {
+            number.amount += amount;
+            number.age = 0.0;
+            text.0 = format!("{:.0}", number.amount.ceil());
+            number_transform.translation = translation;
+            continue;
+        }
+
+        let Some(entity) = pool.acquire(&mut commands) else {
+            continue;
+        };
+        let color = damage_type_color(damage_type);
+        commands.entity(entity).insert((
+            DamageNumber {
+                active: true,
+                age: 0.0,
+                color,
+                target: Some(target),
+                damage_type,
+                amount,
+            },
+            Text2d::new(format!("{:.0}", amount.ceil())),
+            TextColor(color),
+            Transform::from_translation(translation),
+            Visibility::Visible,
+        ));
+    }
+}
+
+/// Rise and fade active numbers, returning expired ones to the pool
+pub fn animate_damage_numbers(
+    time: Res<Time>,
+    mut pool: ResMut<DamageNumberPool>,
+    mut numbers: Query<(
+        Entity,
+        &mut DamageNumber,
+        &mut Transform,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut number, mut transform, mut color, mut visibility) in numbers.iter_mut() {
+        if !number.active {
+            continue;
+        }
+        number.age += delta;
+        if number.age >= NUMBER_LIFETIME {
+            number.active = false;
+            number.target = None;
+            *visibility = Visibility::Hidden;
+            pool.release(entity);
+            continue;
+        }
+        transform.translation.y += NUMBER_RISE_SPEED * delta;
+        color.0 = number.color.with_alpha(1.0 - number.age / NUMBER_LIFETIME);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::game::combat::test_utils::{create_test_app, enter_state};
+    use crate::game::combat::DamageEvent;
+    use crate::game::network::Health;
+    use crate::GameState;
+
+    fn dealt(target: Entity, amount: f32, damage_type: DamageType) -> DamageDealt {
+        DamageDealt {
+            source: None,
+            target,
+            amount,
+            damage_type,
+        }
+    }
+
+    fn create_numbers_app() -> App {
+        let mut app = create_test_app();
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn active_numbers(app: &mut App) -> Vec<(DamageNumber, String)> {
+        let mut query = app.world_mut().query::<(&DamageNumber, &Text2d)>();
+        query
+            .iter(app.world())
+            .filter(|(n, _)| n.active)
+            .map(|(n, t)| (n.clone(), t.0.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_damage_type_colors_are_distinct() {
+        let types = [
+            DamageType::Physical,
+            DamageType::Toxic,
+            DamageType::Explosive,
+            DamageType::Corrupting,
+        ];
+        for (i, a) in types.iter().enumerate() {
+            for b in &types[i + 1..] {
+                assert_ne!(damage_type_color(*a), damage_type_color(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_hits_sums_per_target_and_type() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let events = [
+            dealt(a, 2.0, DamageType::Physical),
+            dealt(a, 5.0, DamageType::Toxic),
+            dealt(a, 1.0, DamageType::Toxic),
+            dealt(b, 1.0, DamageType::Explosive),
+            dealt(b, 0.0, DamageType::Physical),
+        ];
+        let merged = merge_hits(&events);
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[&(a, DamageType::Physical)], 2.0);
+        assert_eq!(merged[&(a, DamageType::Toxic)], 6.0);
+        assert_eq!(merged[&(b, DamageType::Explosive)], 1.0);
+    }
+
+    #[test]
+    fn test_damage_number_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<DamageNumber>();
+        assert_resource::<DamageNumberPool>();
+    }
+
+    #[test]
+    fn test_pool_prewarms_on_playing() {
+        let app = create_numbers_app();
+        let pool = app.world().resource::<DamageNumberPool>();
+        assert_eq!(pool.total(), POOL_PREWARM);
+        assert_eq!(pool.active(), 0);
+    }
+
+    #[test]
+    fn test_hits_in_one_frame_merge_into_one_number() {
+        let mut app = create_numbers_app();
+        let target = app
+            .world_mut()
+            .spawn((Health::new(100.0), Transform::default()))
+            .id();
+
+        for amount in [3.0, 4.0, 5.0] {
+            app.world_mut()
+                .send_event(DamageEvent::new(None, target, amount, DamageType::Toxic));
+        }
+        app.update();
+
+        let numbers = active_numbers(&mut app);
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(numbers[0].1, "12");
+        assert_eq!(numbers[0].0.color, damage_type_color(DamageType::Toxic));
+    }
+
+    #[test]
+    fn test_continuous_drain_updates_one_number() {
+        let mut app = create_numbers_app();
+        let frame = 1.0 / 60.0;
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            frame,
+        )));
+        let target = app
+            .world_mut()
+            .spawn((Health::new(1000.0), Transform::default()))
+            .id();
+
+        for _ in 0..60 {
+            app.world_mut()
+                .send_event(DamageEvent::new(None, target, 0.125, DamageType::Physical));
+            app.update();
+        }
+
+        let numbers = active_numbers(&mut app);
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(numbers[0].1, "8");
+        assert_eq!(app.world().resource::<DamageNumberPool>().active(), 1);
+    }
+
+    #[test]
+    fn test_damage_types_get_separate_numbers() {
+        let mut app = create_numbers_app();
+        let target = app
+            .world_mut()
+            .spawn((Health::new(100.0), Transform::default()))
+            .id();
+        app.world_mut()
+            .send_event(DamageEvent::new(None, target, 3.0, DamageType::Physical));
+        app.update();
+        app.world_mut()
+            .send_event(DamageEvent::new(None, target, 4.0, DamageType::Toxic));
+        app.update();
+
+        assert_eq!(active_numbers(&mut app).len(), 2);
+    }
+
+    #[test]
+    fn test_numbers_return_to_pool_after_lifetime() {
+        let mut app = create_numbers_app();
+        let target = app
+            .world_mut()
+            .spawn((Health::new(100.0), Transform::default()))
+            .id();
+        app.world_mut()
+            .send_event(DamageEvent::new(None, target, 5.0, DamageType::Physical));
+        app.update();
+        assert_eq!(app.world().resource::<DamageNumberPool>().active(), 1);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<DamageNumberPool>().active(), 0);
+        assert!(active_numbers(&mut app).is_empty());
+    }
+}
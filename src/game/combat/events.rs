@@ -0,0 +1,33 @@
+//! Combat events
+
+use bevy::prelude::*;
+
+use crate::game::network::TendrilType;
+
+/// Event fired when an attack emitter fires at a target
+#[derive(Event, Debug, Clone)]
+pub struct AttackFired {
+    /// Emitter entity (the tendril segment)
+    pub emitter: Entity,
+    /// Targeted hostile
+    pub target: Entity,
+    /// Tendril type of the emitter
+    pub tendril_type: TendrilType,
+    /// World position the attack originates from
+    pub origin: Vec2,
+    /// World position of the target when fired
+    pub target_position: Vec2,
+    /// Damage carried by the attack
+    pub damage: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attack_fired_is_event() {
+        fn assert_event<T: Event>() {}
+        assert_event::<AttackFired>();
+    }
+}
@@ -0,0 +1,641 @@
+//! Explosive bursts
+//!
+//! Explosive tendril segments periodically release a radial spore burst:
+//! - Damage in a radius with a configurable falloff curve
+//! - Knockback away from the burst center
+//! - Optional chance to chain-trigger nearby explosive segments
+//! - Expanding gizmo ring as a placeholder visual
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::Rng;
+
+use super::components::{Hostile, Knockback, Static};
+use super::damage::{DamageEvent, DamageType};
+use crate::game::network::{
+    Faction, Health, TendrilPosition, TendrilSegment, TendrilStyle, TendrilType,
+};
+use crate::game::spatial::SpatialGrid;
+
+/// How burst damage and knockback scale with distance from the center
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Falloff {
+    /// Full strength across the whole radius
+    None,
+    /// Strength drops linearly to zero at the edge
+    #[default]
+    Linear,
+    /// Strength drops with the square of the distance fraction
+    Quadratic,
+}
+
+impl Falloff {
+    /// Strength multiplier at `distance` from the center of a burst of `radius`
+    #[must_use]
+    pub fn multiplier(self, distance: f32, radius: f32) -> f32 {
+        if radius <= 0.0 {
+            return 0.0;
+        }
+        let t = (distance / radius).clamp(0.0, 1.0);
+        match self {
+            Self::None => 1.0,
+            Self::Linear => 1.0 - t,
+            Self::Quadratic => (1.0 - t) * (1.0 - t),
+        }
+    }
+}
+
+/// Tuning for explosive segment bursts, modified by upgrades
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BurstStats {
+    /// Seconds between bursts of a segment
+    pub interval: f32,
+    /// Burst radius in world units
+    pub radius: f32,
+    /// Damage at the center of the burst
+    pub damage: f32,
+    /// Damage and knockback falloff toward the edge
+    pub falloff: Falloff,
+    /// Knockback speed at the center of the burst
+    pub knockback: f32,
+    /// Chance (0.0..=1.0) to chain-trigger each explosive segment in `chain_radius`
+    pub chain_chance: f32,
+    /// Distance within which other explosive segments can be chain-triggered
+    pub chain_radius: f32,
+    /// Delay before a chain-triggered segment bursts
+    pub chain_delay: f32,
+    /// A segment that burst more recently than this cannot be chain-triggered
+    pub chain_lockout: f32,
+    /// Lifetime of the ring visual in seconds
+    pub ring_duration: f32,
+}
+
+impl Default for BurstStats {
+    fn default() -> Self {
+        Self {
+            interval: 4.0,
+            radius: 90.0,
+            damage: 20.0,
+            falloff: Falloff::Linear,
+            knockback: 250.0,
+            chain_chance: 0.25,
+            chain_radius: 60.0,
+            chain_delay: 0.15,
+            chain_lockout: 1.0,
+            ring_duration: 0.35,
+        }
+    }
+}
+
+/// Periodic burst timer on an explosive tendril segment
+#[derive(Component, Debug, Clone, Default)]
+pub struct BurstEmitter {
+    /// Seconds until the next burst
+    pub cooldown_remaining: f32,
+    /// Seconds since the last burst
+    pub since_last_burst: f32,
+    /// Set when chain-triggered: bursts even with no hostiles in range
+    pub primed: bool,
+}
+
+impl BurstEmitter {
+    /// Whether the cooldown has elapsed
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.cooldown_remaining <= 0.0
+    }
+
+    /// Advance timers by `delta` seconds
+    pub fn tick(&mut self, delta: f32) {
+        if self.cooldown_remaining > 0.0 {
+            self.cooldown_remaining -= delta;
+        }
+        self.since_last_burst += delta;
+    }
+
+    /// Restart the timers after bursting
+    pub fn fire(&mut self, interval: f32) {
+        self.cooldown_remaining = (self.cooldown_remaining + interval).max(0.0);
+        self.since_last_burst = 0.0;
+        self.primed = false;
+    }
+
+    /// Wait at zero while there is nothing in range
+    pub fn hold(&mut self) {
+        self.cooldown_remaining = self.cooldown_remaining.max(0.0);
+    }
+
+    /// Schedule a chained burst after `delay` seconds
+    pub fn prime(&mut self, delay: f32) {
+        self.cooldown_remaining = self.cooldown_remaining.min(delay);
+        self.primed = true;
+    }
+}
+
+/// Event fired when a burst goes off
+#[derive(Event, Debug, Clone)]
+pub struct BurstTriggered {
+    /// Segment that burst
+    pub source: Entity,
+    pub position: Vec2,
+    pub radius: f32,
+    /// Whether this burst was set off by another burst
+    pub chained: bool,
+}
+
+/// An expanding ring drawn for a recent burst
+#[derive(Debug, Clone, PartialEq)]
+pub struct BurstRing {
+    pub position: Vec2,
+    pub radius: f32,
+    pub age: f32,
+    pub duration: f32,
+}
+
+impl BurstRing {
+    /// Expansion progress from 0.0 to 1.0
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.age / self.duration).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Burst rings currently being drawn
+#[derive(Resource, Debug, Default)]
+pub struct BurstRings(pub Vec<BurstRing>);
+
+/// Give the player's explosive tendril segments a burst emitter
+pub fn attach_burst_emitters(
+    mut commands: Commands,
+    stats: Res<BurstStats>,
+    segments: Query<(Entity, &TendrilSegment, Option<&Faction>), Without<BurstEmitter>>,
+) {
+    for (entity, segment, faction) in segments.iter() {
+        if segment.tendril_type == TendrilType::Explosive && Faction::is_player(faction) {
+            commands.entity(entity).insert(BurstEmitter {
+                since_last_burst: stats.chain_lockout,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Tick burst emitters, fire the ready ones and roll chain triggers
+pub fn burst_explosive_segments(
+    time: Res<Time>,
+    stats: Res<BurstStats>,
+    mut grid: Local<SpatialGrid>,
+    mut emitters: Query<(Entity, &mut BurstEmitter, &TendrilPosition)>,
+    hostiles: Query<(Entity, &Transform, Option<&Health>), With<Hostile>>,
+    mut events: EventWriter<BurstTriggered>,
+) {
+    let delta = time.delta_secs();
+
+    grid.clear();
+    for (entity, transform, health) in hostiles.iter() {
+        if !health.is_some_and(Health::is_dead) {
+            grid.insert(entity, transform.translation.truncate());
+        }
+    }
+
+    let mut fired = Vec::new();
+    for (entity, mut emitter, pos) in emitters.iter_mut() {
+        emitter.tick(delta);
+        if !emitter.is_ready() {
+            continue;
+        }
+        if !emitter.primed
+            && grid
+                .query_radius(pos.position, stats.radius)
+                .next()
+                .is_none()
+        {
+            emitter.hold();
+            continue;
+        }
+
+        let chained = emitter.primed;
+        emitter.fire(stats.interval);
+        fired.push(pos.position);
+        events.send(BurstTriggered {
+            source: entity,
+            position: pos.position,
+            radius: stats.radius,
+            chained,
+        });
+    }
+
+    if fired.is_empty() || stats.chain_chance <= 0.0 {
+        return;
+    }
+
+    let chance = f64::from(stats.chain_chance.min(1.0));
+    let chain_radius_sq = stats.chain_radius * stats.chain_radius;
+    let mut rng = rand::thread_rng();
+    for (_, mut emitter, pos) in emitters.iter_mut() {
+        if emitter.primed || emitter.since_last_burst < stats.chain_lockout {
+            continue;
+        }
+        let in_range = fired
+            .iter()
+            .any(|origin| origin.distance_squared(pos.position) <= chain_radius_sq);
+        if in_range && rng.gen_bool(chance) {
+            emitter.prime(stats.chain_delay);
+        }
+    }
+}
+
+/// Damage hostiles caught in bursts and knock back those that are not `Static`
+pub fn resolve_bursts(
+    mut commands: Commands,
+    stats: Res<BurstStats>,
+    mut bursts: EventReader<BurstTriggered>,
+    mut grid: Local<SpatialGrid>,
+    hostiles: Query<(Entity, &Transform), With<Hostile>>,
+    mut knockable: Query<Option<&mut Knockback>, (With<Hostile>, Without<Static>)>,
+    mut damage: EventWriter<DamageEvent>,
+    mut rings: ResMut<BurstRings>,
+) {
+    if bursts.is_empty() {
+        return;
+    }
+
+    grid.clear();
+    for (entity, transform) in hostiles.iter() {
+        grid.insert(entity, transform.translation.truncate());
+    }
+
+    // Impulses are summed first so an entity caught by several bursts in
+    // one frame gets all of them, not just the last inserted one
+    let mut impulses: HashMap<Entity, Vec2> = HashMap::new();
+    for burst in bursts.read() {
+        for (entity, position) in grid.query_radius(burst.position, burst.radius) {
+            let offset = position - burst.position;
+            let strength = stats.falloff.multiplier(offset.length(), burst.radius);
+            if strength <= 0.0 {
+                continue;
+            }
+
+            damage.send(DamageEvent::new(
+                Some(burst.source),
+                entity,
+                stats.damage * strength,
+                DamageType::Explosive,
+            ));
+
+            let impulse = offset.normalize_or(Vec2::X) * stats.knockback * strength;
+            *impulses.entry(entity).or_default() += impulse;
+        }
+
+        rings.0.push(BurstRing {
+            position: burst.position,
+            radius: burst.radius,
+            age: 0.0,
+            duration: stats.ring_duration,
+        });
+    }
+
+    for (entity, impulse) in impulses {
+        match knockable.get_mut(entity) {
+            Ok(Some(mut knockback)) => knockback.push(impulse),
+            Ok(None) => {
+                commands
+                    .entity(entity)
+                    .insert(Knockback { velocity: impulse });
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+/// Move knocked-back entities and decay their knockback
+pub fn apply_knockback(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut Knockback), Without<Static>>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut transform, mut knockback) in query.iter_mut() {
+        transform.translation += (knockback.velocity * delta).extend(0.0);
+        knockback.decay(delta);
+        if knockback.is_finished() {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
+
+/// Age burst rings and drop finished ones
+pub fn update_burst_rings(time: Res<Time>, mut rings: ResMut<BurstRings>) {
+    let delta = time.delta_secs();
+    for ring in rings.0.iter_mut() {
+        ring.age += delta;
+    }
+    rings.0.retain(|ring| ring.age < ring.duration);
+}
+
+/// Drop all burst rings when a run ends
+pub fn clear_burst_rings(mut rings: ResMut<BurstRings>) {
+    rings.0.clear();
+}
+
+/// Draw burst rings expanding out to their radius and fading
+pub fn render_burst_rings(mut gizmos: Gizmos, rings: Res<BurstRings>) {
+    let color = TendrilStyle::for_type(TendrilType::Explosive).color;
+    for ring in rings.0.iter() {
+        let progress = ring.progress();
+        gizmos.circle_2d(
+            ring.position,
+            ring.radius * progress,
+            color.with_alpha(1.0 - progress),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::test_utils::{create_test_app, enter_state};
+    use crate::game::combat::AttackTable;
+    use crate::GameState;
+
+    fn create_burst_app(stats: BurstStats) -> App {
+        let mut app = create_test_app();
+        app.insert_resource(stats);
+        // Keep the explosive projectile attack out of the damage numbers
+        app.world_mut()
+            .resource_mut::<AttackTable>()
+            .get_mut(TendrilType::Explosive)
+            .unwrap()
+            .damage = 0.0;
+        app.update();
+        app
+    }
+
+    fn test_stats() -> BurstStats {
+        BurstStats {
+            interval: 1.0,
+            radius: 100.0,
+            damage: 20.0,
+            falloff: Falloff::Linear,
+            knockback: 100.0,
+            chain_chance: 0.0,
+            ..default()
+        }
+    }
+
+    fn spawn_explosive(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                TendrilSegment {
+                    tendril_type: TendrilType::Explosive,
+                    ..default()
+                },
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id()
+    }
+
+    fn spawn_hostile(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Hostile,
+                Health::new(100.0),
+                Transform::from_translation(position.extend(0.0)),
+            ))
+            .id()
+    }
+
+    fn health(app: &App, entity: Entity) -> f32 {
+        app.world().get::<Health>(entity).unwrap().current
+    }
+
+    #[test]
+    fn test_falloff_multiplier() {
+        assert_eq!(Falloff::None.multiplier(80.0, 100.0), 1.0);
+        assert_eq!(Falloff::Linear.multiplier(0.0, 100.0), 1.0);
+        assert_eq!(Falloff::Linear.multiplier(50.0, 100.0), 0.5);
+        assert_eq!(Falloff::Quadratic.multiplier(50.0, 100.0), 0.25);
+        assert_eq!(Falloff::Linear.multiplier(150.0, 100.0), 0.0);
+        assert_eq!(Falloff::None.multiplier(10.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_burst_emitter_prime_shortens_cooldown() {
+        let mut emitter = BurstEmitter::default();
+        emitter.fire(4.0);
+        emitter.prime(0.15);
+        assert_eq!(emitter.cooldown_remaining, 0.15);
+        assert!(emitter.primed);
+
+        emitter.fire(4.0);
+        assert!(!emitter.primed);
+        assert_eq!(emitter.since_last_burst, 0.0);
+    }
+
+    #[test]
+    fn test_burst_ring_progress() {
+        let ring = BurstRing {
+            position: Vec2::ZERO,
+            radius: 50.0,
+            age: 0.1,
+            duration: 0.4,
+        };
+        assert_eq!(ring.progress(), 0.25);
+    }
+
+    #[test]
+    fn test_burst_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_event<T: Event>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<BurstEmitter>();
+        assert_event::<BurstTriggered>();
+        assert_resource::<BurstStats>();
+        assert_resource::<BurstRings>();
+    }
+
+    #[test]
+    fn test_only_explosive_segments_get_burst_emitters() {
+        let mut app = create_burst_app(test_stats());
+        enter_state(&mut app, GameState::Playing);
+        let explosive = spawn_explosive(&mut app, Vec2::ZERO);
+        let basic = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::ZERO, Vec2::X),
+            ))
+            .id();
+        app.update();
+
+        assert!(app.world().get::<BurstEmitter>(explosive).is_some());
+        assert!(app.world().get::<BurstEmitter>(basic).is_none());
+    }
+
+    #[test]
+    fn test_burst_damage_falls_off_with_distance() {
+        let mut app = create_burst_app(test_stats());
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let near = spawn_hostile(&mut app, Vec2::new(0.0, 0.0));
+        let mid = spawn_hostile(&mut app, Vec2::new(50.0, 0.0));
+        let far = spawn_hostile(&mut app, Vec2::new(150.0, 0.0));
+
+        enter_state(&mut app, GameState::Playing);
+        app.update();
+
+        assert_eq!(health(&app, near), 80.0);
+        assert_eq!(health(&app, mid), 90.0);
+        assert_eq!(health(&app, far), 100.0);
+    }
+
+    #[test]
+    fn test_burst_repeats_on_interval() {
+        let mut app = create_burst_app(test_stats());
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let hostile = spawn_hostile(&mut app, Vec2::ZERO);
+        app.world_mut()
+            .entity_mut(hostile)
+            .insert(Knockback::default());
+
+        enter_state(&mut app, GameState::Playing);
+        app.update();
+        // Bursts at 0.0, 1.0 and 2.0 seconds
+        for _ in 0..8 {
+            // Pin the hostile to the center so every burst deals full damage
+            app.world_mut()
+                .get_mut::<Transform>(hostile)
+                .unwrap()
+                .translation = Vec3::ZERO;
+            app.update();
+        }
+
+        assert_eq!(health(&app, hostile), 40.0);
+    }
+
+    #[test]
+    fn test_burst_waits_for_hostiles_in_range() {
+        let mut app = create_burst_app(test_stats());
+        spawn_explosive(&mut app, Vec2::ZERO);
+        enter_state(&mut app, GameState::Playing);
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(app.world().resource::<BurstRings>().0.is_empty());
+
+        let hostile = spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        app.update();
+        assert_eq!(health(&app, hostile), 82.0);
+    }
+
+    #[test]
+    fn test_burst_knocks_hostiles_away() {
+        let mut app = create_burst_app(test_stats());
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let hostile = spawn_hostile(&mut app, Vec2::new(20.0, 0.0));
+
+        enter_state(&mut app, GameState::Playing);
+        app.update();
+        app.update();
+
+        let x = app.world().get::<Transform>(hostile).unwrap().translation.x;
+        assert!(x > 20.0);
+    }
+
+    #[test]
+    fn test_overlapping_bursts_add_their_knockback() {
+        let knocked_to = |explosives: usize| {
+            let mut app = create_burst_app(test_stats());
+            for _ in 0..explosives {
+                spawn_explosive(&mut app, Vec2::new(-20.0, 0.0));
+            }
+            let hostile = spawn_hostile(&mut app, Vec2::ZERO);
+
+            enter_state(&mut app, GameState::Playing);
+            app.update();
+            app.world().get::<Knockback>(hostile).unwrap().velocity
+        };
+
+        let single = knocked_to(1);
+        let double = knocked_to(2);
+        assert!(single.x > 0.0);
+        assert!(double.abs_diff_eq(single * 2.0, 1e-3));
+    }
+
+    #[test]
+    fn test_chain_triggers_nearby_explosive_segments() {
+        let mut app = create_burst_app(BurstStats {
+            chain_chance: 1.0,
+            chain_radius: 60.0,
+            ring_duration: 10.0,
+            ..test_stats()
+        });
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let chained = spawn_explosive(&mut app, Vec2::new(500.0, 0.0));
+        spawn_explosive(&mut app, Vec2::new(50.0, 0.0));
+        spawn_hostile(&mut app, Vec2::new(-90.0, 0.0));
+
+        enter_state(&mut app, GameState::Playing);
+        app.update();
+
+        // The far segment is out of chain range
+        assert!(!app.world().get::<BurstEmitter>(chained).unwrap().primed);
+
+        // Original burst plus one chained burst, no infinite ping-pong
+        for _ in 0..2 {
+            app.update();
+        }
+        let rings = &app.world().resource::<BurstRings>().0;
+        assert_eq!(rings.len(), 2);
+    }
+
+    #[test]
+    fn test_no_chain_with_zero_chance() {
+        let mut app = create_burst_app(test_stats());
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let neighbor = spawn_explosive(&mut app, Vec2::new(50.0, 0.0));
+        spawn_hostile(&mut app, Vec2::new(-90.0, 0.0));
+
+        enter_state(&mut app, GameState::Playing);
+        app.update();
+
+        assert!(!app.world().get::<BurstEmitter>(neighbor).unwrap().primed);
+    }
+
+    #[test]
+    fn test_rings_expire() {
+        let mut app = create_burst_app(BurstStats {
+            ring_duration: 0.5,
+            ..test_stats()
+        });
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let hostile = spawn_hostile(&mut app, Vec2::ZERO);
+
+        enter_state(&mut app, GameState::Playing);
+        assert_eq!(app.world().resource::<BurstRings>().0.len(), 1);
+
+        // Remove the hostile so no new bursts go off
+        app.world_mut().entity_mut(hostile).despawn();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().resource::<BurstRings>().0.is_empty());
+    }
+
+    #[test]
+    fn test_bursts_pause_outside_playing() {
+        let mut app = create_burst_app(test_stats());
+        spawn_explosive(&mut app, Vec2::ZERO);
+        let hostile = spawn_hostile(&mut app, Vec2::ZERO);
+        app.update();
+
+        enter_state(&mut app, GameState::Paused);
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(health(&app, hostile), 100.0);
+    }
+}
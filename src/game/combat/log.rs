@@ -0,0 +1,421 @@
+//! Combat log
+//!
+//! In-memory ring buffer of applied damage for balancing:
+//! - Records source, target, amount, damage type and run time
+//! - Hits with the same source, target and damage type merge into one entry
+//!   per `LOG_TICK`, so continuous damage does not flood the buffer
+//! - Shown in the debug overlay
+//! - Written to a file when the run ends, only when enabled with the
+//!   `--combat-log [path]` command line flag
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::damage::{DamageDealt, DamageType};
+use crate::game::state::RunStats;
+
+/// Default number of entries kept in the log
+const DEFAULT_CAPACITY: usize = 2048;
+/// Seconds of run time over which matching hits merge into one entry
+pub const LOG_TICK: f32 = 0.5;
+/// Command line flag that enables dumping the log
+pub const DUMP_FLAG: &str = "--combat-log";
+/// File the log is written to when the flag is given without a path
+const DEFAULT_DUMP_PATH: &str = "combat_log.txt";
+
+/// A single applied hit
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombatLogEntry {
+    /// Run time in seconds when the damage was applied
+    pub timestamp: f32,
+    pub source: Option<Entity>,
+    pub target: Entity,
+    pub amount: f32,
+    pub damage_type: DamageType,
+}
+
+impl CombatLogEntry {
+    /// One-line text form used by the overlay and the dump file
+    #[must_use]
+    pub fn format(&self) -> String {
+        let source = self
+            .source
+            .map_or_else(|| "-".to_string(), |s| format!("{s}"));
+        format!(
+            "[{:8.2}] {} -> {}: {:.1} {:?}",
+            self.timestamp, source, self.target, self.amount, self.damage_type
+        )
+    }
+}
+
+/// Source, target and damage type of a log entry
+type EntryKey = (Option<Entity>, Entity, DamageType);
+
+/// Ring buffer of recent combat events
+#[derive(Resource, Debug)]
+pub struct CombatLog {
+    entries: VecDeque<CombatLogEntry>,
+    capacity: usize,
+    /// Entries pushed since the log was last cleared
+    pushed: u64,
+    /// Tick the open entries belong to
+    open_tick: i64,
+    /// Sequence number of the entry each key merges into this tick
+    open: HashMap<EntryKey, u64>,
+    /// File written at the end of a run, `None` (the default) to disable
+    /// dumping
+    pub dump_path: Option<PathBuf>,
+}
+
+impl Default for CombatLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl CombatLog {
+    /// Create an empty log holding at most `capacity` entries
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity.max(1)),
+            capacity: capacity.max(1),
+            pushed: 0,
+            open_tick: 0,
+            open: HashMap::new(),
+            dump_path: None,
+        }
+    }
+
+    /// Dump path requested on the command line, if any
+    ///
+    /// `--combat-log` alone uses `combat_log.txt`; a following argument that
+    /// is not another flag is taken as the path.
+    #[must_use]
+    pub fn dump_path_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+        let mut args = args.into_iter().skip_while(|arg| arg != DUMP_FLAG);
+        args.next()?;
+        let path = args
+            .next()
+            .filter(|arg| !arg.starts_with("--"))
+            .unwrap_or_else(|| DEFAULT_DUMP_PATH.to_string());
+        Some(PathBuf::from(path))
+    }
+
+    /// Add an entry, dropping the oldest when full
+    pub fn push(&mut self, entry: CombatLogEntry) {
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+        self.pushed += 1;
+    }
+
+    /// Add a hit, merging it into this tick's entry with the same source,
+    /// target and damage type
+    pub fn record(&mut self, entry: CombatLogEntry) {
+        let tick = (entry.timestamp / LOG_TICK).floor() as i64;
+        if tick != self.open_tick {
+            self.open.clear();
+            self.open_tick = tick;
+        }
+        let key = (entry.source, entry.target, entry.damage_type);
+        let first = self.pushed - self.entries.len() as u64;
+        if let Some(&sequence) = self.open.get(&key) {
+            if let Some(open) = sequence
+                .checked_sub(first)
+                .and_then(|index| self.entries.get_mut(index as usize))
+            {
+                open.amount += entry.amount;
+                return;
+            }
+        }
+        self.open.insert(key, self.pushed);
+        self.push(entry);
+    }
+
+    /// Entries from oldest to newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &CombatLogEntry> {
+        self.entries.iter()
+    }
+
+    /// Up to `count` most recent entries, oldest first
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = &CombatLogEntry> {
+        self.entries
+            .iter()
+            .skip(self.entries.len().saturating_sub(count))
+    }
+
+    /// Number of entries held
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the log is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Maximum number of entries held
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Remove all entries
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.open.clear();
+    }
+
+    /// Whole log as text, one entry per line
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for entry in self.entries.iter() {
+            let _ = writeln!(text, "{}", entry.format());
+        }
+        text
+    }
+}
+
+/// Record applied hits in the combat log
+pub fn record_combat_log(
+    mut dealt: EventReader<DamageDealt>,
+    run_stats: Res<RunStats>,
+    mut log: ResMut<CombatLog>,
+) {
+    for event in dealt.read() {
+        log.record(CombatLogEntry {
+            timestamp: run_stats.elapsed_time,
+            source: event.source,
+            target: event.target,
+            amount: event.amount,
+            damage_type: event.damage_type,
+        });
+    }
+}
+
+/// Write the combat log to its dump file when the run ends
+pub fn dump_combat_log(log: Res<CombatLog>) {
+    let Some(path) = log.dump_path.as_ref() else {
+        return;
+    };
+    match std::fs::write(path, log.to_text()) {
+        Ok(()) => info!(
+            "Wrote {} combat log entries to {}",
+            log.len(),
+            path.display()
+        ),
+        Err(err) => warn!("Failed to write combat log to {}: {err}", path.display()),
+    }
+}
+
+/// Empty the combat log when a new run starts
+pub fn clear_combat_log(mut log: ResMut<CombatLog>) {
+    log.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::test_utils::{create_test_app, enter_state};
+    use crate::game::combat::DamageEvent;
+    use crate::game::network::Health;
+    use crate::GameState;
+
+    fn entry(timestamp: f32) -> CombatLogEntry {
+        CombatLogEntry {
+            timestamp,
+            source: None,
+            target: Entity::from_raw(1),
+            amount: 5.0,
+            damage_type: DamageType::Physical,
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut log = CombatLog::new(3);
+        for i in 0..5 {
+            log.push(entry(i as f32));
+        }
+        assert_eq!(log.len(), 3);
+        let stamps: Vec<_> = log.iter().map(|e| e.timestamp).collect();
+        assert_eq!(stamps, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_recent_returns_newest_oldest_first() {
+        let mut log = CombatLog::new(10);
+        for i in 0..5 {
+            log.push(entry(i as f32));
+        }
+        let stamps: Vec<_> = log.recent(2).map(|e| e.timestamp).collect();
+        assert_eq!(stamps, vec![3.0, 4.0]);
+        assert_eq!(log.recent(50).count(), 5);
+    }
+
+    #[test]
+    fn test_to_text_has_one_line_per_entry() {
+        let mut log = CombatLog::new(10);
+        log.push(entry(1.0));
+        log.push(entry(2.5));
+        let text = log.to_text();
+        assert_eq!(text.lines().count(), 2);
+        assert!(text.contains("Physical"));
+    }
+
+    #[test]
+    fn test_dump_disabled_by_default() {
+        assert!(CombatLog::default().dump_path.is_none());
+    }
+
+    #[test]
+    fn test_dump_path_from_args() {
+        let args = |list: &[&str]| {
+            list.iter()
+                .map(|arg| (*arg).to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(CombatLog::dump_path_from_args(args(&["mycelia"])), None);
+        assert_eq!(
+            CombatLog::dump_path_from_args(args(&["mycelia", "--combat-log"])),
+            Some(PathBuf::from(DEFAULT_DUMP_PATH))
+        );
+        assert_eq!(
+            CombatLog::dump_path_from_args(args(&["mycelia", "--combat-log", "--other"])),
+            Some(PathBuf::from(DEFAULT_DUMP_PATH))
+        );
+        assert_eq!(
+            CombatLog::dump_path_from_args(args(&["mycelia", "--combat-log", "run.txt"])),
+            Some(PathBuf::from("run.txt"))
+        );
+    }
+
+    #[test]
+    fn test_record_merges_matching_hits_per_tick() {
+        let mut log = CombatLog::new(10);
+        let other = CombatLogEntry {
+            damage_type: DamageType::Toxic,
+            ..entry(0.1)
+        };
+        log.record(entry(0.0));
+        log.record(entry(0.2));
+        log.record(other);
+        log.record(entry(LOG_TICK));
+
+        let amounts: Vec<_> = log.iter().map(|e| e.amount).collect();
+        assert_eq!(amounts, vec![10.0, 5.0, 5.0]);
+    }
+
+    #[test]
+    fn test_record_survives_ring_buffer_wrap() {
+        let mut log = CombatLog::new(2);
+        log.record(entry(0.0));
+        for i in 0..3 {
+            log.record(CombatLogEntry {
+                target: Entity::from_raw(10 + i),
+                ..entry(0.0)
+            });
+        }
+        // The merged entry was dropped, so the hit starts a new one
+        log.record(entry(0.1));
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.iter().last().unwrap().target, Entity::from_raw(1));
+    }
+
+    #[test]
+    fn test_continuous_drain_is_logged_per_tick() {
+        let mut app = create_test_app();
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        let frame = 1.0 / 60.0;
+        let source = app.world_mut().spawn_empty().id();
+        let target = app.world_mut().spawn(Health::new(1000.0)).id();
+
+        for _ in 0..60 {
+            app.world_mut().send_event(DamageEvent::new(
+                Some(source),
+                target,
+                0.125,
+                DamageType::Physical,
+            ));
+            app.update();
+            app.world_mut().resource_mut::<RunStats>().elapsed_time += frame;
+        }
+
+        let log = app.world().resource::<CombatLog>();
+        assert!(log.len() <= 3, "{} entries", log.len());
+        let total: f32 = log.iter().map(|e| e.amount).sum();
+        assert_eq!(total, 7.5);
+    }
+
+    #[test]
+    fn test_combat_log_is_resource() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<CombatLog>();
+    }
+
+    #[test]
+    fn test_applied_damage_is_logged_with_run_time() {
+        let mut app = create_test_app();
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app.world_mut().resource_mut::<RunStats>().elapsed_time = 12.5;
+        let source = app.world_mut().spawn_empty().id();
+        let target = app.world_mut().spawn(Health::new(100.0)).id();
+
+        app.world_mut().send_event(DamageEvent::new(
+            Some(source),
+            target,
+            7.0,
+            DamageType::Explosive,
+        ));
+        app.update();
+
+        let log = app.world().resource::<CombatLog>();
+        let logged = log.iter().last().unwrap();
+        assert_eq!(logged.source, Some(source));
+        assert_eq!(logged.target, target);
+        assert_eq!(logged.amount, 7.0);
+        assert_eq!(logged.damage_type, DamageType::Explosive);
+        assert_eq!(logged.timestamp, 12.5);
+    }
+
+    #[test]
+    fn test_log_dumped_on_game_over() {
+        let path = std::env::temp_dir().join(format!("combat_log_test_{}.txt", std::process::id()));
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<CombatLog>().dump_path = Some(path.clone());
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        let target = app.world_mut().spawn(Health::new(100.0)).id();
+        app.world_mut()
+            .send_event(DamageEvent::new(None, target, 3.0, DamageType::Toxic));
+        app.update();
+
+        enter_state(&mut app, GameState::GameOver);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        assert!(text.contains("Toxic"));
+    }
+
+    #[test]
+    fn test_log_cleared_on_new_run() {
+        let mut app = create_test_app();
+        app.update();
+        app.world_mut().resource_mut::<CombatLog>().push(entry(0.0));
+
+        enter_state(&mut app, GameState::Playing);
+        assert!(app.world().resource::<CombatLog>().is_empty());
+    }
+}
@@ -2,16 +2,384 @@
 //!
 //! Handles attack mechanics:
 //! - Spore attacks with cooldowns
-//! - Damage dealing and receiving
+//! - Data-driven attack stats per tendril type
+//! - Pooled spore projectiles
+//! - Unified damage pipeline with damage types and resistances
+//! - Status effects (poison, slow, root, corruption)
+//! - Explosive segment bursts with falloff, knockback and chaining
+//! - Contact damage and contact statuses from collision events
+//! - Floating damage numbers and a combat log for balancing
 //! - Network abilities
 
 use bevy::prelude::*;
 
+use crate::game::collision::CollisionSet;
+use crate::GameState;
+
+pub mod components;
+pub mod contact;
+pub mod damage;
+pub mod damage_numbers;
+pub mod events;
+pub mod explosion;
+pub mod log;
+pub mod pool;
+pub mod projectile;
+pub mod resources;
+pub mod status;
+mod systems;
+
+pub use components::*;
+pub use contact::ContactDamage;
+pub use damage::{Armor, DamageDealt, DamageEvent, DamageType, Died, Resistances};
+pub use damage_numbers::{damage_type_color, DamageNumber, DamageNumberPool};
+pub use events::*;
+pub use explosion::{BurstEmitter, BurstRing, BurstRings, BurstStats, BurstTriggered, Falloff};
+pub use log::{CombatLog, CombatLogEntry};
+pub use pool::{EntityPool, Pooled};
+pub use projectile::{
+    ProjectileHit, ProjectileKind, ProjectileMotion, ProjectilePool, ProjectileStats,
+};
+pub use resources::*;
+pub use status::{
+    ApplyStatus, CorruptionOnHit, OnHitStatusTable, StackingRule, StatusEffect, StatusEffects,
+    StatusKind,
+};
+pub use systems::{select_target, TargetCandidate};
+
+/// System set for the combat pipeline; systems that undo its results run after it
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CombatSet;
+
 /// Plugin for the combat system
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: Register combat systems
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<AttackTable>()
+            .init_resource::<ProjectilePool>()
+            .init_resource::<OnHitStatusTable>()
+            .init_resource::<BurstStats>()
+            .init_resource::<BurstRings>()
+            .init_resource::<DamageNumberPool>()
+            .init_resource::<CombatLog>()
+            // Events
+            .add_event::<AttackFired>()
+            .add_event::<ProjectileHit>()
+            .add_event::<DamageEvent>()
+            .add_event::<DamageDealt>()
+            .add_event::<Died>()
+            .add_event::<ApplyStatus>()
+            .add_event::<BurstTriggered>()
+            // Pool and log lifecycle
+            .add_systems(
+                OnEnter(GameState::Playing),
+                (
+                    projectile::prewarm_projectile_pool,
+                    damage_numbers::prewarm_damage_number_pool,
+                ),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                log::clear_combat_log,
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::GameOver,
+                    entered: GameState::Playing,
+                },
+                log::clear_combat_log,
+            )
+            .add_systems(
+                OnEnter(GameState::Menu),
+                (
+                    projectile::despawn_projectile_pool,
+                    damage_numbers::despawn_damage_number_pool,
+                    explosion::clear_burst_rings,
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (
+                    projectile::despawn_projectile_pool,
+                    damage_numbers::despawn_damage_number_pool,
+                    explosion::clear_burst_rings,
+                    log::dump_combat_log,
+                ),
+            )
+            // Systems - attacks fire and projectiles fly only while Playing,
+            // so everything freezes in Paused and Upgrading
+            .add_systems(
+                Update,
+                (
+                    (
+                        systems::attach_attack_emitters,
+                        explosion::attach_burst_emitters,
+                    ),
+                    systems::fire_attack_emitters,
+                    projectile::launch_projectiles,
+                    projectile::move_projectiles,
+                    projectile::resolve_projectile_hits,
+                    (
+                        damage::projectile_hits_to_damage,
+                        status::projectile_hits_to_status,
+                        explosion::burst_explosive_segments,
+                        contact::contact_damage,
+                        contact::contact_status,
+                    ),
+                    explosion::resolve_bursts,
+                    status::tick_status_effects,
+                    damage::apply_damage,
+                    (
+                        status::corruption_on_hit,
+                        damage_numbers::spawn_damage_numbers,
+                        log::record_combat_log,
+                    ),
+                    status::apply_status_effects,
+                    explosion::apply_knockback,
+                    explosion::update_burst_rings,
+                    damage_numbers::animate_damage_numbers,
+                )
+                    .chain()
+                    .in_set(CombatSet)
+                    .after(CollisionSet)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Rendering (Playing and Paused so frozen projectiles stay visible)
+            .add_systems(
+                Update,
+                (
+                    projectile::render_projectiles,
+                    explosion::render_burst_rings,
+                )
+                    .run_if(
+                        resource_exists::<bevy::gizmos::config::GizmoConfigStore>
+                            .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                    ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{create_test_app, enter_state};
+    use super::*;
+    use crate::game::network::{Health, TendrilPosition, TendrilSegment, TendrilType};
+
+    /// Counts `AttackFired` events across frames
+    #[derive(Resource, Default)]
+    struct FireCount(u32);
+
+    fn count_fires(mut events: EventReader<AttackFired>, mut count: ResMut<FireCount>) {
+        count.0 += events.read().count() as u32;
+    }
+
+    fn create_counting_app() -> App {
+        let mut app = create_test_app();
+        app.init_resource::<FireCount>()
+            .add_systems(Update, count_fires.after(systems::fire_attack_emitters));
+        app.update();
+        app
+    }
+
+    fn set_stats(app: &mut App, tendril_type: TendrilType, cooldown: f32, range: f32) {
+        app.world_mut().resource_mut::<AttackTable>().set(
+            tendril_type,
+            AttackStats {
+                cooldown,
+                range,
+                damage: 5.0,
+                targeting: TargetingRule::Nearest,
+                projectile: ProjectileStats::default(),
+            },
+        );
+    }
+
+    fn spawn_segment(app: &mut App, tendril_type: TendrilType, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                TendrilSegment {
+                    tendril_type,
+                    ..default()
+                },
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id()
+    }
+
+    fn spawn_hostile(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Hostile,
+                Health::new(50.0),
+                Transform::from_translation(position.extend(0.0)),
+            ))
+            .id()
+    }
+
+    fn fire_count(app: &App) -> u32 {
+        app.world().resource::<FireCount>().0
+    }
+
+    #[test]
+    fn test_combat_plugin_builds() {
+        let mut app = create_test_app();
+        app.update();
+    }
+
+    #[test]
+    fn test_attack_table_resource_exists() {
+        let mut app = create_test_app();
+        app.update();
+
+        assert!(app.world().get_resource::<AttackTable>().is_some());
+    }
+
+    #[test]
+    fn test_segments_get_emitter_matching_type() {
+        let mut app = create_counting_app();
+        enter_state(&mut app, GameState::Playing);
+
+        let segment = spawn_segment(&mut app, TendrilType::Toxic, Vec2::ZERO);
+        app.update();
+
+        let emitter = app.world().get::<AttackEmitter>(segment).unwrap();
+        assert_eq!(emitter.tendril_type, TendrilType::Toxic);
+    }
+
+    #[test]
+    fn test_emitter_fires_exact_count_over_time() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 0.5, 100.0);
+        spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+
+        // Emitter is attached and fires on the first Playing frame
+        enter_state(&mut app, GameState::Playing);
+        assert_eq!(fire_count(&app), 1);
+
+        // 2 more seconds at a 0.5s cooldown: fires at 0.5, 1.0, 1.5 and 2.0
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 5);
+    }
+
+    #[test]
+    fn test_emitters_have_independent_cooldowns() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 0.5, 100.0);
+        set_stats(&mut app, TendrilType::Sticky, 1.0, 100.0);
+        spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        spawn_segment(&mut app, TendrilType::Sticky, Vec2::ZERO);
+        enter_state(&mut app, GameState::Playing);
+
+        // Over 0..=2 seconds: Basic fires 5 times, Sticky fires 3 times
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 8);
+    }
+
+    #[test]
+    fn test_emitter_does_not_fire_without_target_in_range() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 0.5, 100.0);
+        spawn_hostile(&mut app, Vec2::new(500.0, 0.0));
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        enter_state(&mut app, GameState::Playing);
+
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 0);
+    }
+
+    #[test]
+    fn test_emitter_does_not_bank_shots_while_idle() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 0.5, 100.0);
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        enter_state(&mut app, GameState::Playing);
+
+        for _ in 0..8 {
+            app.update();
+        }
+
+        // A target appearing after a long idle period gets one shot, not a burst
+        spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        app.update();
+        assert_eq!(fire_count(&app), 1);
+    }
+
+    #[test]
+    fn test_emitter_ignores_dead_hostiles() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 0.5, 100.0);
+        let hostile = spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        app.world_mut().get_mut::<Health>(hostile).unwrap().current = 0.0;
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        enter_state(&mut app, GameState::Playing);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 0);
+    }
+
+    #[test]
+    fn test_emitters_only_fire_while_playing() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 0.5, 100.0);
+        spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+
+        // Still in Menu
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 0);
+
+        enter_state(&mut app, GameState::Playing);
+        app.update();
+        assert_eq!(fire_count(&app), 1);
+
+        enter_state(&mut app, GameState::Paused);
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 1);
+    }
+
+    #[test]
+    fn test_table_upgrade_applies_to_existing_emitters() {
+        let mut app = create_counting_app();
+        set_stats(&mut app, TendrilType::Basic, 1.0, 100.0);
+        spawn_hostile(&mut app, Vec2::new(10.0, 0.0));
+        spawn_segment(&mut app, TendrilType::Basic, Vec2::ZERO);
+        enter_state(&mut app, GameState::Playing);
+
+        // First shot restarts a 1.0s cooldown, then the cooldown is halved
+        assert_eq!(fire_count(&app), 1);
+        app.world_mut()
+            .resource_mut::<AttackTable>()
+            .get_mut(TendrilType::Basic)
+            .unwrap()
+            .cooldown = 0.5;
+
+        // Pending 1.0s cooldown ends at 1.0, then shots at 1.5 and 2.0
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(fire_count(&app), 4);
     }
 }
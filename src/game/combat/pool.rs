@@ -0,0 +1,144 @@
+//! Entity pooling
+//!
+//! Short-lived combat entities are recycled rather than despawned mid-run:
+//! - `Pooled` says how to spawn a fresh hidden entity and caps the pool
+//! - `EntityPool<T>` hands out free entities, spawning only when none are left
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+/// A component whose entities live in an `EntityPool`
+pub trait Pooled: Component {
+    /// Hard cap on pooled entities; requests beyond it are dropped
+    const POOL_MAX: usize;
+
+    /// Spawn an inactive, hidden entity for the pool
+    fn spawn_pooled(commands: &mut Commands) -> Entity;
+}
+
+/// Pool of reusable entities carrying `T`
+#[derive(Resource, Debug)]
+pub struct EntityPool<T: Pooled> {
+    /// Inactive entities ready for reuse
+    free: Vec<Entity>,
+    /// Every entity owned by the pool
+    all: Vec<Entity>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Pooled> Default for EntityPool<T> {
+    fn default() -> Self {
+        Self {
+            free: Vec::new(),
+            all: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Pooled> EntityPool<T> {
+    /// Number of entities owned by the pool
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.all.len()
+    }
+
+    /// Number of entities available for reuse
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Number of entities currently in use
+    #[must_use]
+    pub fn active(&self) -> usize {
+        self.all.len() - self.free.len()
+    }
+
+    /// Take an entity, spawning a new one only if none are free
+    ///
+    /// Returns `None` once the pool has reached its hard cap.
+    pub fn acquire(&mut self, commands: &mut Commands) -> Option<Entity> {
+        if let Some(entity) = self.free.pop() {
+            return Some(entity);
+        }
+        if self.all.len() >= T::POOL_MAX {
+            return None;
+        }
+        let entity = T::spawn_pooled(commands);
+        self.all.push(entity);
+        Some(entity)
+    }
+
+    /// Return an entity to the pool
+    pub fn release(&mut self, entity: Entity) {
+        self.free.push(entity);
+    }
+
+    /// Spawn inactive entities until the pool owns at least `count`
+    pub fn prewarm(&mut self, commands: &mut Commands, count: usize) {
+        while self.all.len() < count.min(T::POOL_MAX) {
+            let entity = T::spawn_pooled(commands);
+            self.all.push(entity);
+            self.free.push(entity);
+        }
+    }
+
+    /// Forget all entities (after they have been despawned)
+    pub fn clear(&mut self) {
+        self.free.clear();
+        self.all.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Debug)]
+    struct Token;
+
+    impl Pooled for Token {
+        const POOL_MAX: usize = 3;
+
+        fn spawn_pooled(commands: &mut Commands) -> Entity {
+            commands.spawn(Token).id()
+        }
+    }
+
+    #[test]
+    fn test_pool_reuses_released_entities() {
+        let mut world = World::new();
+        let mut pool = EntityPool::<Token>::default();
+        let mut commands = world.commands();
+        let first = pool.acquire(&mut commands).unwrap();
+        assert_eq!(pool.active(), 1);
+        pool.release(first);
+        assert_eq!(pool.acquire(&mut commands), Some(first));
+        assert_eq!(pool.total(), 1);
+    }
+
+    #[test]
+    fn test_pool_stops_at_cap() {
+        let mut world = World::new();
+        let mut pool = EntityPool::<Token>::default();
+        let mut commands = world.commands();
+        pool.prewarm(&mut commands, 10);
+        assert_eq!(pool.total(), Token::POOL_MAX);
+        assert_eq!(pool.available(), Token::POOL_MAX);
+        for _ in 0..Token::POOL_MAX {
+            assert!(pool.acquire(&mut commands).is_some());
+        }
+        assert!(pool.acquire(&mut commands).is_none());
+
+        pool.clear();
+        assert_eq!(pool.total(), 0);
+    }
+
+    #[test]
+    fn test_entity_pool_is_resource() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<EntityPool<Token>>();
+    }
+}
@@ -0,0 +1,811 @@
+//! Projectile subsystem
+//!
+//! Spore shots fired by attack emitters:
+//! - Linear, homing and arcing motion
+//! - Lifetime and pierce count
+//! - Hit detection against living hostile colliders on the layers projectiles
+//!   hit, looked up through a spatial grid
+//! - Entity pooling (projectiles are recycled, never despawned mid-run)
+
+use bevy::prelude::*;
+
+use super::components::Hostile;
+use super::events::AttackFired;
+use super::pool::{EntityPool, Pooled};
+use super::resources::AttackTable;
+use crate::game::collision::{Collider, CollisionLayers, Layer, LayerMask};
+use crate::game::network::{Health, TendrilStyle, TendrilType};
+use crate::game::spatial::SpatialGrid;
+
+/// Number of pooled projectiles created when a run starts
+const POOL_PREWARM: usize = 256;
+/// Hard cap on pooled projectiles; shots beyond it are dropped
+const POOL_MAX: usize = 4096;
+/// Gizmo radius used to draw projectiles
+const PROJECTILE_DRAW_RADIUS: f32 = 3.0;
+
+/// Kind of motion a projectile follows, as configured in the attack table
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ProjectileKind {
+    /// Straight line toward where the target was when fired
+    #[default]
+    Linear,
+    /// Steers toward the target at `turn_rate` radians per second
+    Homing { turn_rate: f32 },
+    /// Lobbed toward the target position, only hits on landing
+    Arcing { height: f32 },
+}
+
+/// Projectile parameters for one kind of attack
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectileStats {
+    /// Motion kind
+    pub kind: ProjectileKind,
+    /// Travel speed in world units per second
+    pub speed: f32,
+    /// Seconds before the projectile expires
+    pub lifetime: f32,
+    /// Number of extra targets the projectile can pass through
+    pub pierce: u32,
+    /// Hit radius in world units
+    pub radius: f32,
+}
+
+impl Default for ProjectileStats {
+    fn default() -> Self {
+        Self {
+            kind: ProjectileKind::Linear,
+            speed: 300.0,
+            lifetime: 2.0,
+            pierce: 0,
+            radius: 6.0,
+        }
+    }
+}
+
+/// Runtime motion state of an active projectile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectileMotion {
+    /// Constant velocity
+    Linear { velocity: Vec2 },
+    /// Velocity rotates toward the target each frame
+    Homing {
+        velocity: Vec2,
+        target: Entity,
+        turn_rate: f32,
+    },
+    /// Ground position moves from `start` to `end` over `flight_time`
+    Arcing {
+        start: Vec2,
+        end: Vec2,
+        height: f32,
+        flight_time: f32,
+    },
+}
+
+/// A pooled projectile entity
+///
+/// Inactive projectiles stay spawned but hidden and are skipped by every
+/// system; `ProjectilePool` hands them out again.
+#[derive(Component, Debug, Clone)]
+pub struct Projectile {
+    /// Whether this projectile is currently in flight
+    pub active: bool,
+    /// Entity that fired this projectile
+    pub source: Entity,
+    /// Tendril type of the emitter that fired it
+    pub tendril_type: TendrilType,
+    /// Damage dealt on hit
+    pub damage: f32,
+    /// Current motion
+    pub motion: ProjectileMotion,
+    /// Ground position (the visual may be offset for arcing shots)
+    pub position: Vec2,
+    /// Seconds since the projectile was fired
+    pub age: f32,
+    /// Seconds before the projectile expires
+    pub lifetime: f32,
+    /// Remaining extra targets it can pass through
+    pub pierce_remaining: u32,
+    /// Hit radius in world units
+    pub radius: f32,
+    /// Targets already hit (a projectile never hits the same target twice)
+    pub hits: Vec<Entity>,
+}
+
+impl Default for Projectile {
+    fn default() -> Self {
+        Self {
+            active: false,
+            source: Entity::PLACEHOLDER,
+            tendril_type: TendrilType::Basic,
+            damage: 0.0,
+            motion: ProjectileMotion::Linear {
+                velocity: Vec2::ZERO,
+            },
+            position: Vec2::ZERO,
+            age: 0.0,
+            lifetime: 0.0,
+            pierce_remaining: 0,
+            radius: 0.0,
+            hits: Vec::new(),
+        }
+    }
+}
+
+impl Projectile {
+    /// Build an active projectile for an attack
+    #[must_use]
+    pub fn from_attack(attack: &AttackFired, stats: &ProjectileStats) -> Self {
+        let to_target = attack.target_position - attack.origin;
+        let velocity = to_target.normalize_or(Vec2::X) * stats.speed;
+
+        let motion = match stats.kind {
+            ProjectileKind::Linear => ProjectileMotion::Linear { velocity },
+            ProjectileKind::Homing { turn_rate } => ProjectileMotion::Homing {
+                velocity,
+                target: attack.target,
+                turn_rate,
+            },
+            ProjectileKind::Arcing { height } => ProjectileMotion::Arcing {
+                start: attack.origin,
+                end: attack.target_position,
+                height,
+                flight_time: if stats.speed > 0.0 {
+                    to_target.length() / stats.speed
+                } else {
+                    0.0
+                },
+            },
+        };
+
+        Self {
+            active: true,
+            source: attack.emitter,
+            tendril_type: attack.tendril_type,
+            damage: attack.damage,
+            motion,
+            position: attack.origin,
+            age: 0.0,
+            lifetime: stats.lifetime,
+            pierce_remaining: stats.pierce,
+            radius: stats.radius,
+            hits: Vec::new(),
+        }
+    }
+
+    /// Whether the projectile can currently hit things
+    ///
+    /// Arcing projectiles only hit once they land.
+    #[must_use]
+    pub fn can_hit(&self) -> bool {
+        match self.motion {
+            ProjectileMotion::Arcing { flight_time, .. } => self.age >= flight_time,
+            _ => true,
+        }
+    }
+
+    /// Height above the ground for drawing (non-zero only while arcing)
+    #[must_use]
+    pub fn visual_height(&self) -> f32 {
+        match self.motion {
+            ProjectileMotion::Arcing {
+                height,
+                flight_time,
+                ..
+            } if flight_time > 0.0 => {
+                let t = (self.age / flight_time).clamp(0.0, 1.0);
+                4.0 * height * t * (1.0 - t)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// Rotate `velocity` toward `desired` by at most `max_angle` radians
+#[must_use]
+pub fn steer_toward(velocity: Vec2, desired: Vec2, max_angle: f32) -> Vec2 {
+    let speed = velocity.length();
+    if speed == 0.0 || desired == Vec2::ZERO {
+        return velocity;
+    }
+    let angle = velocity.angle_to(desired);
+    let step = angle.clamp(-max_angle, max_angle);
+    Vec2::from_angle(step).rotate(velocity)
+}
+
+/// Event fired when a projectile hits a target
+#[derive(Event, Debug, Clone)]
+pub struct ProjectileHit {
+    /// Projectile entity
+    pub projectile: Entity,
+    /// Entity that fired the projectile
+    pub source: Entity,
+    /// Entity that was hit
+    pub target: Entity,
+    /// Tendril type of the emitter that fired it
+    pub tendril_type: TendrilType,
+    /// Damage carried by the projectile
+    pub damage: f32,
+    /// Where the hit happened
+    pub position: Vec2,
+}
+
+/// Pool of reusable projectile entities
+pub type ProjectilePool = EntityPool<Projectile>;
+
+impl Pooled for Projectile {
+    const POOL_MAX: usize = POOL_MAX;
+
+    fn spawn_pooled(commands: &mut Commands) -> Entity {
+        commands
+            .spawn((
+                Projectile::default(),
+                CollisionLayers::new(Layer::Projectile, LayerMask::ENEMY),
+                Transform::default(),
+                Visibility::Hidden,
+            ))
+            .id()
+    }
+}
+
+/// Create the initial set of pooled projectiles
+pub fn prewarm_projectile_pool(mut commands: Commands, mut pool: ResMut<ProjectilePool>) {
+    pool.prewarm(&mut commands, POOL_PREWARM);
+}
+
+/// Despawn every pooled projectile when the run ends
+pub fn despawn_projectile_pool(
+    mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
+    projectiles: Query<Entity, With<Projectile>>,
+) {
+    for entity in projectiles.iter() {
+        commands.entity(entity).despawn();
+    }
+    pool.clear();
+}
+
+/// Launch a pooled projectile for every attack fired this frame
+pub fn launch_projectiles(
+    mut commands: Commands,
+    mut attacks: EventReader<AttackFired>,
+    table: Res<AttackTable>,
+    mut pool: ResMut<ProjectilePool>,
+) {
+    for attack in attacks.read() {
+        let Some(stats) = table.get(attack.tendril_type) else {
+            continue;
+        };
+        let Some(entity) = pool.acquire(&mut commands) else {
+            continue;
+        };
+        commands.entity(entity).insert((
+            Projectile::from_attack(attack, &stats.projectile),
+            Transform::from_translation(attack.origin.extend(0.0)),
+            Visibility::Visible,
+        ));
+    }
+}
+
+/// Advance active projectiles along their motion and age them
+pub fn move_projectiles(
+    time: Res<Time>,
+    mut projectiles: Query<(&mut Projectile, &mut Transform)>,
+    targets: Query<&Transform, Without<Projectile>>,
+) {
+    let delta = time.delta_secs();
+
+    for (mut projectile, mut transform) in projectiles.iter_mut() {
+        if !projectile.active {
+            continue;
+        }
+        projectile.age += delta;
+
+        let position = projectile.position;
+        let age = projectile.age;
+        projectile.position = match &mut projectile.motion {
+            ProjectileMotion::Linear { velocity } => position + *velocity * delta,
+            ProjectileMotion::Homing {
+                velocity,
+                target,
+                turn_rate,
+            } => {
+                if let Ok(target_transform) = targets.get(*target) {
+                    let desired = target_transform.translation.truncate() - position;
+                    *velocity = steer_toward(*velocity, desired, *turn_rate * delta);
+                }
+                position + *velocity * delta
+            }
+            ProjectileMotion::Arcing {
+                start,
+                end,
+                flight_time,
+                ..
+            } => {
+                let t = if *flight_time > 0.0 {
+                    (age / *flight_time).min(1.0)
+                } else {
+                    1.0
+                };
+                start.lerp(*end, t)
+            }
+        };
+
+        let visual = projectile.position + Vec2::Y * projectile.visual_height();
+        transform.translation.x = visual.x;
+        transform.translation.y = visual.y;
+    }
+}
+
+/// Detect hits against hostiles, then retire spent and expired projectiles
+///
+/// Dead hostiles are skipped so they do not use up pierce.
+pub fn resolve_projectile_hits(
+    mut pool: ResMut<ProjectilePool>,
+    mut grid: Local<SpatialGrid>,
+    mut projectiles: Query<(Entity, &mut Projectile, &CollisionLayers, &mut Visibility)>,
+    hostiles: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&Collider>,
+            Option<&CollisionLayers>,
+            Option<&Health>,
+        ),
+        With<Hostile>,
+    >,
+    mut hits: EventWriter<ProjectileHit>,
+) {
+    grid.clear();
+    let mut max_collider_radius: f32 = 0.0;
+    for (target, transform, collider, _, health) in hostiles.iter() {
+        if health.is_some_and(|h| h.current <= 0.0) {
+            continue;
+        }
+        grid.insert(target, transform.translation.truncate());
+        max_collider_radius = max_collider_radius.max(collider.map_or(0.0, Collider::radius));
+    }
+
+    let mut candidates: Vec<(Entity, Vec2)> = Vec::new();
+    for (entity, mut projectile, layers, mut visibility) in projectiles.iter_mut() {
+        if !projectile.active {
+            continue;
+        }
+
+        let mut spent = false;
+        if projectile.can_hit() {
+            let position = projectile.position;
+            candidates.clear();
+            candidates.extend(grid.query_radius(position, projectile.radius + max_collider_radius));
+            candidates.sort_by(|a, b| {
+                a.1.distance_squared(position)
+                    .total_cmp(&b.1.distance_squared(position))
+                    .then_with(|| a.0.cmp(&b.0))
+            });
+            for &(target, target_pos) in candidates.iter() {
+                if projectile.hits.contains(&target) {
+                    continue;
+                }
+                let Ok((_, _, collider, target_layers, _)) = hostiles.get(target) else {
+                    continue;
+                };
+                // Hostiles without layers are always hit
+                if target_layers.is_some_and(|l| !layers.filter.intersects(l.membership)) {
+                    continue;
+                }
+                let reach = projectile.radius + collider.map_or(0.0, Collider::radius);
+                if target_pos.distance_squared(position) > reach * reach {
+                    continue;
+                }
+
+                projectile.hits.push(target);
+                hits.send(ProjectileHit {
+                    projectile: entity,
+                    source: projectile.source,
+                    target,
+                    tendril_type: projectile.tendril_type,
+                    damage: projectile.damage,
+                    position,
+                });
+
+                if projectile.pierce_remaining == 0 {
+                    spent = true;
+                    break;
+                }
+                projectile.pierce_remaining -= 1;
+            }
+        }
+
+        // Arcing shots burst on landing whether or not they hit anything
+        let landed =
+            matches!(projectile.motion, ProjectileMotion::Arcing { .. }) && projectile.can_hit();
+
+        if spent || landed || projectile.age >= projectile.lifetime {
+            projectile.active = false;
+            projectile.hits.clear();
+            *visibility = Visibility::Hidden;
+            pool.release(entity);
+        }
+    }
+}
+
+/// Draw active projectiles
+pub fn render_projectiles(mut gizmos: Gizmos, projectiles: Query<(&Projectile, &Transform)>) {
+    for (projectile, transform) in projectiles.iter() {
+        if !projectile.active {
+            continue;
+        }
+        let color = TendrilStyle::for_type(projectile.tendril_type).color;
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            PROJECTILE_DRAW_RADIUS,
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::test_utils::{create_test_app, enter_state, TEST_FRAME_SECS};
+    use crate::game::combat::AttackStats;
+    use crate::GameState;
+
+    fn attack(origin: Vec2, target: Entity, target_position: Vec2) -> AttackFired {
+        AttackFired {
+            emitter: Entity::PLACEHOLDER,
+            target,
+            tendril_type: TendrilType::Basic,
+            origin,
+            target_position,
+            damage: 10.0,
+        }
+    }
+
+    /// Counts `ProjectileHit` events across frames
+    #[derive(Resource, Default)]
+    struct HitCount(u32);
+
+    fn count_hits(mut events: EventReader<ProjectileHit>, mut count: ResMut<HitCount>) {
+        count.0 += events.read().count() as u32;
+    }
+
+    fn create_projectile_app(stats: ProjectileStats) -> App {
+        let mut app = create_test_app();
+        app.init_resource::<HitCount>()
+            .add_systems(Update, count_hits.after(resolve_projectile_hits));
+        app.world_mut().resource_mut::<AttackTable>().set(
+            TendrilType::Basic,
+            AttackStats {
+                cooldown: 1.0,
+                range: 100.0,
+                damage: 10.0,
+                targeting: Default::default(),
+                projectile: stats,
+            },
+        );
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn fire(app: &mut App, attack: AttackFired) {
+        app.world_mut().send_event(attack);
+        app.update();
+    }
+
+    fn spawn_hostile(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((Hostile, Transform::from_translation(position.extend(0.0))))
+            .id()
+    }
+
+    fn active_projectiles(app: &mut App) -> Vec<Projectile> {
+        app.world_mut()
+            .query::<&Projectile>()
+            .iter(app.world())
+            .filter(|p| p.active)
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_steer_toward_limits_turn() {
+        let steered = steer_toward(Vec2::X * 10.0, Vec2::Y, 0.1);
+        assert!((steered.to_angle() - 0.1).abs() < 0.001);
+        assert!((steered.length() - 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_steer_toward_reaches_desired_within_limit() {
+        let steered = steer_toward(Vec2::X * 10.0, Vec2::Y, 10.0);
+        assert!((steered.normalize() - Vec2::Y).length() < 0.001);
+    }
+
+    #[test]
+    fn test_projectile_from_attack_linear_aims_at_target() {
+        let attack = attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::new(0.0, 50.0));
+        let projectile = Projectile::from_attack(&attack, &ProjectileStats::default());
+        assert!(projectile.active);
+        assert_eq!(
+            projectile.motion,
+            ProjectileMotion::Linear {
+                velocity: Vec2::new(0.0, 300.0)
+            }
+        );
+    }
+
+    #[test]
+    fn test_arcing_projectile_only_hits_after_landing() {
+        let stats = ProjectileStats {
+            kind: ProjectileKind::Arcing { height: 10.0 },
+            speed: 100.0,
+            ..default()
+        };
+        let attack = attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::new(100.0, 0.0));
+        let mut projectile = Projectile::from_attack(&attack, &stats);
+        assert!(!projectile.can_hit());
+        projectile.age = 0.5;
+        assert!((projectile.visual_height() - 10.0).abs() < 0.001);
+        projectile.age = 1.0;
+        assert!(projectile.can_hit());
+        assert_eq!(projectile.visual_height(), 0.0);
+    }
+
+    #[test]
+    fn test_projectile_pool_is_resource() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<ProjectilePool>();
+    }
+
+    #[test]
+    fn test_projectile_hit_is_event() {
+        fn assert_event<T: Event>() {}
+        assert_event::<ProjectileHit>();
+    }
+
+    #[test]
+    fn test_pool_prewarms_on_playing() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        let pool = app.world().resource::<ProjectilePool>();
+        assert_eq!(pool.total(), POOL_PREWARM);
+        assert_eq!(pool.active(), 0);
+        assert_eq!(active_projectiles(&mut app).len(), 0);
+    }
+
+    #[test]
+    fn test_attack_launches_pooled_projectile() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 50.0),
+        );
+
+        assert_eq!(active_projectiles(&mut app).len(), 1);
+        let pool = app.world().resource::<ProjectilePool>();
+        assert_eq!(pool.total(), POOL_PREWARM);
+        assert_eq!(pool.active(), 1);
+    }
+
+    #[test]
+    fn test_expired_projectiles_return_to_pool_and_are_reused() {
+        let stats = ProjectileStats {
+            lifetime: 0.5,
+            ..default()
+        };
+        let mut app = create_projectile_app(stats);
+
+        for _ in 0..3 {
+            for _ in 0..POOL_PREWARM {
+                app.world_mut()
+                    .send_event(attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X));
+            }
+            app.update();
+            assert_eq!(
+                app.world().resource::<ProjectilePool>().active(),
+                POOL_PREWARM
+            );
+            app.update();
+            app.update();
+            assert_eq!(app.world().resource::<ProjectilePool>().active(), 0);
+        }
+
+        // Three full volleys never grew the pool
+        assert_eq!(
+            app.world().resource::<ProjectilePool>().total(),
+            POOL_PREWARM
+        );
+    }
+
+    #[test]
+    fn test_linear_projectile_moves_at_speed() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 500.0),
+        );
+
+        let projectile = &active_projectiles(&mut app)[0];
+        let expected = 300.0 * TEST_FRAME_SECS;
+        assert!((projectile.position.x - expected).abs() < 0.001);
+        assert_eq!(projectile.position.y, 0.0);
+    }
+
+    #[test]
+    fn test_homing_projectile_turns_toward_target() {
+        let stats = ProjectileStats {
+            kind: ProjectileKind::Homing { turn_rate: 2.0 },
+            speed: 100.0,
+            ..default()
+        };
+        let mut app = create_projectile_app(stats);
+        let target = spawn_hostile(&mut app, Vec2::new(0.0, 1000.0));
+
+        // Fired along +X, target is along +Y
+        fire(&mut app, attack(Vec2::ZERO, target, Vec2::new(1000.0, 0.0)));
+        app.update();
+
+        let ProjectileMotion::Homing { velocity, .. } = active_projectiles(&mut app)[0].motion
+        else {
+            panic!("expected homing motion");
+        };
+        assert!(velocity.y > 0.0);
+    }
+
+    #[test]
+    fn test_projectile_hit_fires_event_and_releases() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        spawn_hostile(&mut app, Vec2::new(75.0, 0.0));
+
+        // 300 units/s at 0.25s per frame: reaches x=75 on the first frame
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 75.0),
+        );
+
+        assert_eq!(app.world().resource::<HitCount>().0, 1);
+        assert_eq!(active_projectiles(&mut app).len(), 0);
+    }
+
+    #[test]
+    fn test_projectile_hits_edge_of_large_collider() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        let target = spawn_hostile(&mut app, Vec2::new(105.0, 0.0));
+        app.world_mut()
+            .entity_mut(target)
+            .insert(Collider::Circle { radius: 30.0 });
+
+        // After one frame the shot is at x=75, inside the collider but far
+        // from its center, and the next frame carries it past
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 75.0),
+        );
+        assert_eq!(app.world().resource::<HitCount>().0, 1);
+    }
+
+    #[test]
+    fn test_projectiles_skip_hostiles_off_their_layers() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        let target = spawn_hostile(&mut app, Vec2::new(75.0, 0.0));
+        app.world_mut()
+            .entity_mut(target)
+            .insert(CollisionLayers::new(Layer::Pickup, LayerMask::ALL));
+
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 75.0),
+        );
+        assert_eq!(app.world().resource::<HitCount>().0, 0);
+    }
+
+    #[test]
+    fn test_pierce_allows_extra_hits() {
+        let stats = ProjectileStats {
+            speed: 100.0,
+            pierce: 1,
+            ..default()
+        };
+        let mut app = create_projectile_app(stats);
+        spawn_hostile(&mut app, Vec2::new(25.0, 0.0));
+        spawn_hostile(&mut app, Vec2::new(50.0, 0.0));
+        spawn_hostile(&mut app, Vec2::new(75.0, 0.0));
+
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 100.0),
+        );
+        app.update();
+        app.update();
+
+        // One hit plus one pierce, then spent before the third hostile
+        assert_eq!(app.world().resource::<HitCount>().0, 2);
+        assert_eq!(active_projectiles(&mut app).len(), 0);
+    }
+
+    #[test]
+    fn test_dead_hostiles_do_not_use_up_pierce() {
+        let stats = ProjectileStats {
+            speed: 100.0,
+            ..default()
+        };
+        let mut app = create_projectile_app(stats);
+        let dead = spawn_hostile(&mut app, Vec2::new(25.0, 0.0));
+        let mut health = Health::new(10.0);
+        health.current = 0.0;
+        app.world_mut().entity_mut(dead).insert(health);
+        let alive = spawn_hostile(&mut app, Vec2::new(50.0, 0.0));
+        app.world_mut().entity_mut(alive).insert(Health::new(100.0));
+
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 100.0),
+        );
+        app.update();
+        app.update();
+
+        // The shot passes over the dead hostile and is spent on the living one
+        assert_eq!(app.world().resource::<HitCount>().0, 1);
+        assert!(app.world().get::<Health>(alive).unwrap().current < 100.0);
+    }
+
+    #[test]
+    fn test_arcing_projectile_passes_over_hostiles_in_flight() {
+        let stats = ProjectileStats {
+            kind: ProjectileKind::Arcing { height: 20.0 },
+            speed: 100.0,
+            ..default()
+        };
+        let mut app = create_projectile_app(stats);
+        spawn_hostile(&mut app, Vec2::new(25.0, 0.0));
+        spawn_hostile(&mut app, Vec2::new(100.0, 0.0));
+
+        // Lands at x=100 after 1.0s (4 frames)
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 100.0),
+        );
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<HitCount>().0, 0);
+
+        app.update();
+        assert_eq!(app.world().resource::<HitCount>().0, 1);
+        assert_eq!(active_projectiles(&mut app).len(), 0);
+    }
+
+    #[test]
+    fn test_projectiles_freeze_while_paused_and_upgrading() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        fire(
+            &mut app,
+            attack(Vec2::ZERO, Entity::PLACEHOLDER, Vec2::X * 500.0),
+        );
+        let before = active_projectiles(&mut app)[0].clone();
+
+        for state in [GameState::Paused, GameState::Upgrading] {
+            enter_state(&mut app, state);
+            for _ in 0..4 {
+                app.update();
+            }
+            let frozen = &active_projectiles(&mut app)[0];
+            assert_eq!(frozen.position, before.position);
+            assert_eq!(frozen.age, before.age);
+        }
+    }
+
+    #[test]
+    fn test_pool_despawned_when_returning_to_menu() {
+        let mut app = create_projectile_app(ProjectileStats::default());
+        enter_state(&mut app, GameState::Menu);
+
+        let count = app
+            .world_mut()
+            .query::<&Projectile>()
+            .iter(app.world())
+            .count();
+        assert_eq!(count, 0);
+        assert_eq!(app.world().resource::<ProjectilePool>().total(), 0);
+    }
+}
@@ -0,0 +1,175 @@
+//! Combat resources
+//!
+//! Data tables for attack stats. Upgrades modify these tables rather than
+//! individual emitters.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::projectile::{ProjectileKind, ProjectileStats};
+use crate::game::network::TendrilType;
+
+/// How an emitter picks its target among hostiles in range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetingRule {
+    /// Closest hostile to the emitter
+    #[default]
+    Nearest,
+    /// Hostile furthest away that is still in range
+    Farthest,
+    /// Hostile with the lowest current health
+    LowestHealth,
+}
+
+/// Stats for one kind of automatic attack
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttackStats {
+    /// Seconds between shots
+    pub cooldown: f32,
+    /// Maximum distance to a target in world units
+    pub range: f32,
+    /// Damage dealt per shot
+    pub damage: f32,
+    /// How a target is chosen
+    pub targeting: TargetingRule,
+    /// Projectile launched by each shot
+    pub projectile: ProjectileStats,
+}
+
+/// Attack stats for every tendril type
+#[derive(Resource, Debug)]
+pub struct AttackTable {
+    stats: HashMap<TendrilType, AttackStats>,
+}
+
+impl Default for AttackTable {
+    fn default() -> Self {
+        let mut stats = HashMap::new();
+        stats.insert(
+            TendrilType::Basic,
+            AttackStats {
+                cooldown: 1.0,
+                range: 120.0,
+                damage: 10.0,
+                targeting: TargetingRule::Nearest,
+                projectile: ProjectileStats::default(),
+            },
+        );
+        stats.insert(
+            TendrilType::Toxic,
+            AttackStats {
+                cooldown: 1.5,
+                range: 100.0,
+                damage: 4.0,
+                targeting: TargetingRule::LowestHealth,
+                projectile: ProjectileStats {
+                    kind: ProjectileKind::Homing { turn_rate: 4.0 },
+                    speed: 220.0,
+                    ..default()
+                },
+            },
+        );
+        stats.insert(
+            TendrilType::Sticky,
+            AttackStats {
+                cooldown: 2.0,
+                range: 80.0,
+                damage: 2.0,
+                targeting: TargetingRule::Nearest,
+                projectile: ProjectileStats {
+                    speed: 180.0,
+                    pierce: 2,
+                    radius: 8.0,
+                    ..default()
+                },
+            },
+        );
+        stats.insert(
+            TendrilType::Explosive,
+            AttackStats {
+                cooldown: 3.0,
+                range: 150.0,
+                damage: 25.0,
+                targeting: TargetingRule::Farthest,
+                projectile: ProjectileStats {
+                    kind: ProjectileKind::Arcing { height: 40.0 },
+                    speed: 200.0,
+                    radius: 24.0,
+                    ..default()
+                },
+            },
+        );
+        Self { stats }
+    }
+}
+
+impl AttackTable {
+    /// Stats for a tendril type, if it has an attack
+    #[must_use]
+    pub fn get(&self, tendril_type: TendrilType) -> Option<&AttackStats> {
+        self.stats.get(&tendril_type)
+    }
+
+    /// Mutable stats for a tendril type, used by upgrades
+    pub fn get_mut(&mut self, tendril_type: TendrilType) -> Option<&mut AttackStats> {
+        self.stats.get_mut(&tendril_type)
+    }
+
+    /// Replace the stats for a tendril type
+    pub fn set(&mut self, tendril_type: TendrilType, stats: AttackStats) {
+        self.stats.insert(tendril_type, stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_targeting_rule_default_is_nearest() {
+        assert_eq!(TargetingRule::default(), TargetingRule::Nearest);
+    }
+
+    #[test]
+    fn test_attack_table_has_every_tendril_type() {
+        let table = AttackTable::default();
+        for tendril_type in [
+            TendrilType::Basic,
+            TendrilType::Toxic,
+            TendrilType::Sticky,
+            TendrilType::Explosive,
+        ] {
+            let stats = table.get(tendril_type).unwrap();
+            assert!(stats.cooldown > 0.0);
+            assert!(stats.range > 0.0);
+            assert!(stats.damage > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_attack_table_get_mut_modifies_stats() {
+        let mut table = AttackTable::default();
+        table.get_mut(TendrilType::Basic).unwrap().damage = 99.0;
+        assert_eq!(table.get(TendrilType::Basic).unwrap().damage, 99.0);
+    }
+
+    #[test]
+    fn test_attack_table_set_replaces_stats() {
+        let mut table = AttackTable::default();
+        let stats = AttackStats {
+            cooldown: 0.5,
+            range: 10.0,
+            damage: 1.0,
+            targeting: TargetingRule::Farthest,
+            projectile: ProjectileStats::default(),
+        };
+        table.set(TendrilType::Sticky, stats.clone());
+        assert_eq!(table.get(TendrilType::Sticky), Some(&stats));
+    }
+
+    #[test]
+    fn test_attack_table_is_resource() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<AttackTable>();
+    }
+}
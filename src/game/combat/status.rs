@@ -0,0 +1,554 @@
+//! Status effects
+//!
+//! A generic stack of timed effects on an entity:
+//! - Poison: damage over time through the shared damage pipeline
+//! - Slow and Root: movement speed multipliers
+//! - Corruption: raises `TendrilSegment::corruption_level` over time
+//!
+//! Effects are applied with `ApplyStatus` and only tick while Playing.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::damage::{DamageDealt, DamageEvent, DamageType};
+use super::projectile::ProjectileHit;
+use crate::game::network::{TendrilSegment, TendrilType};
+
+/// Kind of status effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Toxic damage per tick (magnitude = damage per tick per stack)
+    Poison,
+    /// Movement slow (magnitude = fraction of speed removed per stack)
+    Slow,
+    /// Movement fully stopped
+    Root,
+    /// Corruption per tick on tendril segments (magnitude = level per tick per stack)
+    Corruption,
+}
+
+/// What happens when an effect is applied to an entity that already has it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StackingRule {
+    /// Reset the duration, keep a single stack
+    #[default]
+    Refresh,
+    /// Add a stack without limit and reset the duration
+    Stack,
+    /// Add a stack up to the given cap and reset the duration
+    MaxStacks(u32),
+}
+
+/// A single timed status effect
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    /// Entity that applied the effect (credited for its damage)
+    pub source: Option<Entity>,
+    /// Full duration in seconds
+    pub duration: f32,
+    /// Seconds left before the effect expires
+    pub remaining: f32,
+    /// Seconds between ticks (0.0 for effects that do not tick)
+    pub tick_interval: f32,
+    /// Time accumulated toward the next tick
+    pub tick_timer: f32,
+    /// Strength per stack, meaning depends on `kind`
+    pub magnitude: f32,
+    /// Current number of stacks
+    pub stacks: u32,
+    /// How re-application is handled
+    pub stacking: StackingRule,
+}
+
+impl StatusEffect {
+    /// Create an effect with a single stack
+    #[must_use]
+    pub fn new(kind: StatusKind, magnitude: f32, duration: f32, tick_interval: f32) -> Self {
+        Self {
+            kind,
+            source: None,
+            duration,
+            remaining: duration,
+            tick_interval,
+            tick_timer: 0.0,
+            magnitude,
+            stacks: 1,
+            stacking: StackingRule::Refresh,
+        }
+    }
+
+    /// Set the stacking rule
+    #[must_use]
+    pub fn with_stacking(mut self, stacking: StackingRule) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    /// Set the source entity
+    #[must_use]
+    pub fn with_source(mut self, source: Option<Entity>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Total strength across all stacks
+    #[must_use]
+    pub fn total_magnitude(&self) -> f32 {
+        self.magnitude * self.stacks as f32
+    }
+
+    /// Advance the effect and return how many ticks elapsed
+    pub fn advance(&mut self, delta: f32) -> u32 {
+        let elapsed = delta.min(self.remaining.max(0.0));
+        self.remaining -= delta;
+        if self.tick_interval <= 0.0 {
+            return 0;
+        }
+        self.tick_timer += elapsed;
+        let mut ticks = 0;
+        while self.tick_timer >= self.tick_interval {
+            self.tick_timer -= self.tick_interval;
+            ticks += 1;
+        }
+        ticks
+    }
+
+    /// Whether the effect has run out
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
+/// Stack of status effects on an entity (at most one entry per kind)
+#[derive(Component, Debug, Clone, Default)]
+pub struct StatusEffects(pub Vec<StatusEffect>);
+
+impl StatusEffects {
+    /// Apply an effect, merging with an existing one of the same kind
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let Some(existing) = self.0.iter_mut().find(|e| e.kind == effect.kind) else {
+            self.0.push(effect);
+            return;
+        };
+
+        existing.stacks = match effect.stacking {
+            StackingRule::Refresh => existing.stacks,
+            StackingRule::Stack => existing.stacks + effect.stacks,
+            StackingRule::MaxStacks(max) => (existing.stacks + effect.stacks).min(max.max(1)),
+        };
+        existing.remaining = existing.remaining.max(effect.duration);
+        existing.duration = effect.duration;
+        existing.magnitude = existing.magnitude.max(effect.magnitude);
+        existing.tick_interval = effect.tick_interval;
+        existing.stacking = effect.stacking;
+        existing.source = effect.source.or(existing.source);
+    }
+
+    /// Active effect of a kind, if any
+    #[must_use]
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.0.iter().find(|e| e.kind == kind)
+    }
+
+    /// Whether an effect of this kind is active
+    #[must_use]
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    /// Movement speed multiplier from slows and roots (1.0 = unaffected)
+    #[must_use]
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.has(StatusKind::Root) {
+            return 0.0;
+        }
+        self.get(StatusKind::Slow)
+            .map_or(1.0, |slow| (1.0 - slow.total_magnitude()).clamp(0.0, 1.0))
+    }
+
+    /// Remove every effect of a kind
+    pub fn remove(&mut self, kind: StatusKind) {
+        self.0.retain(|e| e.kind != kind);
+    }
+}
+
+/// Request to apply a status effect to an entity
+#[derive(Event, Debug, Clone)]
+pub struct ApplyStatus {
+    pub target: Entity,
+    pub effect: StatusEffect,
+}
+
+/// Status effect applied by each tendril type's attacks on hit
+#[derive(Resource, Debug)]
+pub struct OnHitStatusTable {
+    effects: HashMap<TendrilType, StatusEffect>,
+}
+
+impl Default for OnHitStatusTable {
+    fn default() -> Self {
+        let mut effects = HashMap::new();
+        effects.insert(
+            TendrilType::Toxic,
+            StatusEffect::new(StatusKind::Poison, 3.0, 3.0, 0.5)
+                .with_stacking(StackingRule::MaxStacks(5)),
+        );
+        effects.insert(
+            TendrilType::Sticky,
+            StatusEffect::new(StatusKind::Slow, 0.4, 2.0, 0.0),
+        );
+        Self { effects }
+    }
+}
+
+impl OnHitStatusTable {
+    /// Effect applied by a tendril type, if any
+    #[must_use]
+    pub fn get(&self, tendril_type: TendrilType) -> Option<&StatusEffect> {
+        self.effects.get(&tendril_type)
+    }
+
+    /// Mutable effect for a tendril type, used by upgrades
+    pub fn get_mut(&mut self, tendril_type: TendrilType) -> Option<&mut StatusEffect> {
+        self.effects.get_mut(&tendril_type)
+    }
+
+    /// Replace the effect for a tendril type
+    pub fn set(&mut self, tendril_type: TendrilType, effect: StatusEffect) {
+        self.effects.insert(tendril_type, effect);
+    }
+}
+
+/// Attacker component: damage it deals to tendril segments also corrupts them
+#[derive(Component, Debug, Clone)]
+pub struct CorruptionOnHit(pub StatusEffect);
+
+impl Default for CorruptionOnHit {
+    fn default() -> Self {
+        Self(
+            StatusEffect::new(StatusKind::Corruption, 0.05, 4.0, 0.5)
+                .with_stacking(StackingRule::MaxStacks(4)),
+        )
+    }
+}
+
+/// Apply on-hit statuses from Toxic and Sticky projectiles
+pub fn projectile_hits_to_status(
+    mut hits: EventReader<ProjectileHit>,
+    table: Res<OnHitStatusTable>,
+    mut apply: EventWriter<ApplyStatus>,
+) {
+    for hit in hits.read() {
+        if let Some(effect) = table.get(hit.tendril_type) {
+            apply.send(ApplyStatus {
+                target: hit.target,
+                effect: effect.clone().with_source(Some(hit.source)),
+            });
+        }
+    }
+}
+
+/// Corrupt tendril segments damaged by attackers with `CorruptionOnHit`
+pub fn corruption_on_hit(
+    mut dealt: EventReader<DamageDealt>,
+    attackers: Query<&CorruptionOnHit>,
+    segments: Query<(), With<TendrilSegment>>,
+    mut apply: EventWriter<ApplyStatus>,
+) {
+    for event in dealt.read() {
+        let Some(source) = event.source else {
+            continue;
+        };
+        let Ok(on_hit) = attackers.get(source) else {
+            continue;
+        };
+        if segments.contains(event.target) {
+            apply.send(ApplyStatus {
+                target: event.target,
+                effect: on_hit.0.clone().with_source(Some(source)),
+            });
+        }
+    }
+}
+
+/// Merge `ApplyStatus` requests into each target's `StatusEffects`
+pub fn apply_status_effects(
+    mut commands: Commands,
+    mut events: EventReader<ApplyStatus>,
+    mut targets: Query<Option<&mut StatusEffects>>,
+) {
+    let mut pending: HashMap<Entity, StatusEffects> = HashMap::new();
+
+    for event in events.read() {
+        match targets.get_mut(event.target) {
+            Ok(Some(mut effects)) => effects.apply(event.effect.clone()),
+            Ok(None) => pending
+                .entry(event.target)
+                .or_default()
+                .apply(event.effect.clone()),
+            Err(_) => {}
+        }
+    }
+
+    for (entity, effects) in pending {
+        commands.entity(entity).insert(effects);
+    }
+}
+
+/// Advance effects, apply their ticks and drop expired ones
+pub fn tick_status_effects(
+    time: Res<Time>,
+    mut targets: Query<(Entity, &mut StatusEffects, Option<&mut TendrilSegment>)>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut effects, mut segment) in targets.iter_mut() {
+        for effect in effects.0.iter_mut() {
+            let ticks = effect.advance(delta);
+            if ticks == 0 {
+                continue;
+            }
+            let amount = effect.total_magnitude() * ticks as f32;
+            match effect.kind {
+                StatusKind::Poison => {
+                    damage.send(DamageEvent::new(
+                        effect.source,
+                        entity,
+                        amount,
+                        DamageType::Toxic,
+                    ));
+                }
+                StatusKind::Corruption => {
+                    if let Some(segment) = segment.as_mut() {
+                        segment.corruption_level = (segment.corruption_level + amount).min(1.0);
+                        segment.corrupted = true;
+                    }
+                }
+                StatusKind::Slow | StatusKind::Root => {}
+            }
+        }
+        effects.0.retain(|e| !e.is_expired());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::test_utils::{create_test_app, enter_state};
+    use crate::game::network::Health;
+    use crate::GameState;
+
+    fn poison() -> StatusEffect {
+        StatusEffect::new(StatusKind::Poison, 2.0, 3.0, 1.0)
+    }
+
+    fn create_status_app() -> App {
+        let mut app = create_test_app();
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn apply(app: &mut App, target: Entity, effect: StatusEffect) {
+        app.world_mut().send_event(ApplyStatus { target, effect });
+        app.update();
+    }
+
+    #[test]
+    fn test_status_effect_advance_counts_ticks() {
+        let mut effect = poison();
+        assert_eq!(effect.advance(0.5), 0);
+        assert_eq!(effect.advance(0.5), 1);
+        assert_eq!(effect.advance(2.5), 2);
+        assert!(effect.is_expired());
+    }
+
+    #[test]
+    fn test_status_effect_without_interval_never_ticks() {
+        let mut effect = StatusEffect::new(StatusKind::Slow, 0.5, 2.0, 0.0);
+        assert_eq!(effect.advance(1.0), 0);
+        assert!(!effect.is_expired());
+    }
+
+    #[test]
+    fn test_refresh_resets_duration_without_stacking() {
+        let mut effects = StatusEffects::default();
+        effects.apply(poison());
+        effects.0[0].remaining = 0.5;
+        effects.apply(poison());
+
+        assert_eq!(effects.0.len(), 1);
+        assert_eq!(effects.0[0].stacks, 1);
+        assert_eq!(effects.0[0].remaining, 3.0);
+    }
+
+    #[test]
+    fn test_stack_adds_stacks_without_limit() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..10 {
+            effects.apply(poison().with_stacking(StackingRule::Stack));
+        }
+        assert_eq!(effects.get(StatusKind::Poison).unwrap().stacks, 10);
+    }
+
+    #[test]
+    fn test_max_stacks_caps_stacks() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..10 {
+            effects.apply(poison().with_stacking(StackingRule::MaxStacks(3)));
+        }
+        let effect = effects.get(StatusKind::Poison).unwrap();
+        assert_eq!(effect.stacks, 3);
+        assert_eq!(effect.total_magnitude(), 6.0);
+    }
+
+    #[test]
+    fn test_reapply_keeps_source_attribution() {
+        let source = Entity::from_raw(7);
+        let mut effects = StatusEffects::default();
+        effects.apply(poison().with_source(Some(source)));
+        effects.apply(poison());
+        assert_eq!(
+            effects.get(StatusKind::Poison).unwrap().source,
+            Some(source)
+        );
+    }
+
+    #[test]
+    fn test_speed_multiplier() {
+        let mut effects = StatusEffects::default();
+        assert_eq!(effects.speed_multiplier(), 1.0);
+
+        effects.apply(StatusEffect::new(StatusKind::Slow, 0.25, 2.0, 0.0));
+        assert_eq!(effects.speed_multiplier(), 0.75);
+
+        effects.apply(StatusEffect::new(StatusKind::Root, 0.0, 1.0, 0.0));
+        assert_eq!(effects.speed_multiplier(), 0.0);
+
+        effects.remove(StatusKind::Root);
+        assert_eq!(effects.speed_multiplier(), 0.75);
+    }
+
+    #[test]
+    fn test_status_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_event<T: Event>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<StatusEffects>();
+        assert_component::<CorruptionOnHit>();
+        assert_event::<ApplyStatus>();
+        assert_resource::<OnHitStatusTable>();
+    }
+
+    #[test]
+    fn test_apply_status_inserts_component() {
+        let mut app = create_status_app();
+        let target = app.world_mut().spawn(Health::new(50.0)).id();
+
+        apply(&mut app, target, poison());
+
+        let effects = app.world().get::<StatusEffects>(target).unwrap();
+        assert!(effects.has(StatusKind::Poison));
+    }
+
+    #[test]
+    fn test_poison_deals_damage_through_health() {
+        let mut app = create_status_app();
+        let target = app.world_mut().spawn(Health::new(50.0)).id();
+
+        apply(&mut app, target, poison());
+
+        // 3s duration with a 1s interval: exactly 3 ticks of 2 damage
+        for _ in 0..16 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 44.0);
+        assert!(!app
+            .world()
+            .get::<StatusEffects>(target)
+            .unwrap()
+            .has(StatusKind::Poison));
+    }
+
+    #[test]
+    fn test_corruption_raises_segment_corruption() {
+        let mut app = create_status_app();
+        let segment = app.world_mut().spawn(TendrilSegment::default()).id();
+
+        apply(
+            &mut app,
+            segment,
+            StatusEffect::new(StatusKind::Corruption, 0.1, 2.0, 0.5),
+        );
+        for _ in 0..8 {
+            app.update();
+        }
+
+        let segment = app.world().get::<TendrilSegment>(segment).unwrap();
+        assert!(segment.corrupted);
+        assert!((segment.corruption_level - 0.4).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_toxic_projectile_hit_applies_poison_and_sticky_applies_slow() {
+        let mut app = create_status_app();
+        let target = app.world_mut().spawn(Health::new(50.0)).id();
+
+        for tendril_type in [TendrilType::Toxic, TendrilType::Sticky] {
+            app.world_mut().send_event(ProjectileHit {
+                projectile: Entity::PLACEHOLDER,
+                source: Entity::PLACEHOLDER,
+                target,
+                tendril_type,
+                damage: 1.0,
+                position: Vec2::ZERO,
+            });
+        }
+        app.update();
+        app.update();
+
+        let effects = app.world().get::<StatusEffects>(target).unwrap();
+        assert!(effects.has(StatusKind::Poison));
+        assert!(effects.speed_multiplier() < 1.0);
+    }
+
+    #[test]
+    fn test_corruption_on_hit_corrupts_damaged_segments() {
+        let mut app = create_status_app();
+        let bacterium = app.world_mut().spawn(CorruptionOnHit::default()).id();
+        let segment = app.world_mut().spawn(TendrilSegment::default()).id();
+
+        app.world_mut().send_event(DamageEvent::new(
+            Some(bacterium),
+            segment,
+            5.0,
+            DamageType::Physical,
+        ));
+        app.update();
+        app.update();
+
+        let effects = app.world().get::<StatusEffects>(segment).unwrap();
+        let corruption = effects.get(StatusKind::Corruption).unwrap();
+        assert_eq!(corruption.source, Some(bacterium));
+    }
+
+    #[test]
+    fn test_effects_pause_outside_playing() {
+        let mut app = create_status_app();
+        let target = app.world_mut().spawn(Health::new(50.0)).id();
+        apply(&mut app, target, poison());
+        let before = app.world().get::<StatusEffects>(target).unwrap().0[0].remaining;
+
+        enter_state(&mut app, GameState::Paused);
+        for _ in 0..8 {
+            app.update();
+        }
+
+        let effects = app.world().get::<StatusEffects>(target).unwrap();
+        assert_eq!(effects.0[0].remaining, before);
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 50.0);
+    }
+}
@@ -0,0 +1,165 @@
+//! Combat systems
+//!
+//! Systems for automatic attack emitters.
+
+use bevy::prelude::*;
+
+use super::components::{AttackEmitter, Hostile};
+use super::events::AttackFired;
+use super::resources::{AttackTable, TargetingRule};
+use crate::game::network::{Faction, Health, TendrilPosition, TendrilSegment};
+
+/// A hostile considered as an attack target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetCandidate {
+    pub entity: Entity,
+    pub position: Vec2,
+    /// Current health, `f32::MAX` if the hostile has no `Health`
+    pub health: f32,
+}
+
+/// Pick a target in range of `origin` according to `rule`
+#[must_use]
+pub fn select_target(
+    rule: TargetingRule,
+    origin: Vec2,
+    range: f32,
+    candidates: impl IntoIterator<Item = TargetCandidate>,
+) -> Option<TargetCandidate> {
+    let range_sq = range * range;
+    let in_range = candidates
+        .into_iter()
+        .filter(|c| c.position.distance_squared(origin) <= range_sq);
+
+    match rule {
+        TargetingRule::Nearest => in_range.min_by(|a, b| {
+            a.position
+                .distance_squared(origin)
+                .total_cmp(&b.position.distance_squared(origin))
+        }),
+        TargetingRule::Farthest => in_range.max_by(|a, b| {
+            a.position
+                .distance_squared(origin)
+                .total_cmp(&b.position.distance_squared(origin))
+        }),
+        TargetingRule::LowestHealth => in_range.min_by(|a, b| a.health.total_cmp(&b.health)),
+    }
+}
+
+/// Give every player tendril segment an attack emitter matching its type
+pub fn attach_attack_emitters(
+    mut commands: Commands,
+    segments: Query<(Entity, &TendrilSegment, Option<&Faction>), Without<AttackEmitter>>,
+) {
+    for (entity, segment, faction) in segments.iter() {
+        if !Faction::is_player(faction) {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(AttackEmitter::new(segment.tendril_type));
+    }
+}
+
+/// Tick emitter cooldowns and fire at targets in range
+pub fn fire_attack_emitters(
+    time: Res<Time>,
+    table: Res<AttackTable>,
+    mut emitters: Query<(Entity, &mut AttackEmitter, &TendrilPosition)>,
+    hostiles: Query<(Entity, &Transform, Option<&Health>), With<Hostile>>,
+    mut events: EventWriter<AttackFired>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut emitter, pos) in emitters.iter_mut() {
+        emitter.tick(delta);
+        if !emitter.is_ready() {
+            continue;
+        }
+
+        let Some(stats) = table.get(emitter.tendril_type) else {
+            continue;
+        };
+
+        let candidates = hostiles
+            .iter()
+            .filter(|(_, _, health)| !health.is_some_and(|h| h.is_dead()))
+            .map(|(target, transform, health)| TargetCandidate {
+                entity: target,
+                position: transform.translation.truncate(),
+                health: health.map_or(f32::MAX, |h| h.current),
+            });
+
+        let Some(target) = select_target(stats.targeting, pos.position, stats.range, candidates)
+        else {
+            emitter.hold();
+            continue;
+        };
+
+        emitter.trigger(stats.cooldown);
+        events.send(AttackFired {
+            emitter: entity,
+            target: target.entity,
+            tendril_type: emitter.tendril_type,
+            origin: pos.position,
+            target_position: target.position,
+            damage: stats.damage,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(index: u32, position: Vec2, health: f32) -> TargetCandidate {
+        TargetCandidate {
+            entity: Entity::from_raw(index),
+            position,
+            health,
+        }
+    }
+
+    #[test]
+    fn test_select_target_nearest() {
+        let candidates = [
+            candidate(1, Vec2::new(50.0, 0.0), 10.0),
+            candidate(2, Vec2::new(20.0, 0.0), 10.0),
+        ];
+        let target = select_target(TargetingRule::Nearest, Vec2::ZERO, 100.0, candidates);
+        assert_eq!(target.unwrap().entity, Entity::from_raw(2));
+    }
+
+    #[test]
+    fn test_select_target_farthest_within_range() {
+        let candidates = [
+            candidate(1, Vec2::new(50.0, 0.0), 10.0),
+            candidate(2, Vec2::new(20.0, 0.0), 10.0),
+            candidate(3, Vec2::new(500.0, 0.0), 10.0),
+        ];
+        let target = select_target(TargetingRule::Farthest, Vec2::ZERO, 100.0, candidates);
+        assert_eq!(target.unwrap().entity, Entity::from_raw(1));
+    }
+
+    #[test]
+    fn test_select_target_lowest_health() {
+        let candidates = [
+            candidate(1, Vec2::new(10.0, 0.0), 30.0),
+            candidate(2, Vec2::new(90.0, 0.0), 5.0),
+        ];
+        let target = select_target(TargetingRule::LowestHealth, Vec2::ZERO, 100.0, candidates);
+        assert_eq!(target.unwrap().entity, Entity::from_raw(2));
+    }
+
+    #[test]
+    fn test_select_target_none_in_range() {
+        let candidates = [candidate(1, Vec2::new(200.0, 0.0), 10.0)];
+        assert!(select_target(TargetingRule::Nearest, Vec2::ZERO, 100.0, candidates).is_none());
+    }
+
+    #[test]
+    fn test_select_target_at_exact_range() {
+        let candidates = [candidate(1, Vec2::new(100.0, 0.0), 10.0)];
+        assert!(select_target(TargetingRule::Nearest, Vec2::ZERO, 100.0, candidates).is_some());
+    }
+}
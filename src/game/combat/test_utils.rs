@@ -0,0 +1,41 @@
+//! Shared test utilities for combat module tests
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use super::CombatPlugin;
+use crate::game::collision::CollisionPlugin;
+use crate::game::state::RunStats;
+use crate::GameState;
+
+/// Fixed frame time used by combat tests (exact in binary floating point)
+pub const TEST_FRAME_SECS: f32 = 0.25;
+
+/// Helper to create a test app with the combat and collision plugins and a
+/// fixed time step
+///
+/// The first update after creation has a zero delta; every later update
+/// advances time by exactly `TEST_FRAME_SECS`.
+pub fn create_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(StatesPlugin)
+        .init_state::<GameState>()
+        .init_resource::<RunStats>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            TEST_FRAME_SECS,
+        )))
+        .add_plugins((CollisionPlugin, CombatPlugin));
+    app
+}
+
+/// Transition the test app into `state` and let the transition settle
+pub fn enter_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
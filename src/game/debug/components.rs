@@ -32,6 +32,10 @@ pub struct GameStateText;
 #[derive(Component, Debug, Default)]
 pub struct CursorPositionText;
 
+/// Marker for combat log text element
+#[derive(Component, Debug, Default)]
+pub struct CombatLogText;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +81,10 @@ mod tests {
         fn assert_component<T: Component>() {}
         assert_component::<CursorPositionText>();
     }
+
+    #[test]
+    fn test_combat_log_text_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<CombatLogText>();
+    }
 }
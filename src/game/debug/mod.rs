@@ -6,8 +6,9 @@
 //! - Network statistics
 //! - Nutrient values
 //! - Game state
-//! - Cursor world position
+//! - Cursor world position and the biome under it
 //! - Network graph visualization (F4)
+//! - Recent combat log entries (F5)
 //!
 //! Toggle with F3 key.
 
@@ -37,6 +38,7 @@ impl Plugin for DebugPlugin {
                 (
                     systems::toggle_debug_overlay,
                     systems::toggle_network_graph,
+                    systems::toggle_combat_log,
                     systems::track_frame_time,
                 ),
             )
@@ -51,6 +53,7 @@ impl Plugin for DebugPlugin {
                     systems::update_nutrients_display,
                     systems::update_game_state_display,
                     systems::update_cursor_position_display,
+                    systems::update_combat_log_display,
                 ),
             )
             // Gizmo rendering (only when GizmoConfigStore available)
@@ -65,10 +68,10 @@ impl Plugin for DebugPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bevy::state::app::StatesPlugin;
     use crate::game::input::CursorWorldPosition;
     use crate::game::network::NetworkStats;
     use crate::game::progression::Nutrients;
+    use bevy::state::app::StatesPlugin;
 
     /// Create a minimal test app with just the resources (no UI or input systems)
     fn create_test_app() -> App {
@@ -24,6 +24,8 @@ pub struct DebugSettings {
     pub show_network_graph: bool,
     /// Show cursor world position
     pub show_cursor_position: bool,
+    /// Show the most recent combat log entries
+    pub show_combat_log: bool,
 }
 
 impl Default for DebugSettings {
@@ -37,6 +39,7 @@ impl Default for DebugSettings {
             show_game_state: true,
             show_network_graph: false, // Off by default, can be heavy
             show_cursor_position: true,
+            show_combat_log: false, // Off by default, F5 to toggle
         }
     }
 }
@@ -54,6 +57,7 @@ impl DebugSettings {
             show_game_state: true,
             show_network_graph: false, // Off by default, can be heavy
             show_cursor_position: true,
+            show_combat_log: false, // Off by default, F5 to toggle
         }
     }
 
@@ -66,6 +70,11 @@ impl DebugSettings {
     pub fn toggle_network_graph(&mut self) {
         self.show_network_graph = !self.show_network_graph;
     }
+
+    /// Toggle combat log display
+    pub fn toggle_combat_log(&mut self) {
+        self.show_combat_log = !self.show_combat_log;
+    }
 }
 
 /// Tracks frame timing for FPS calculation
@@ -164,6 +173,7 @@ mod tests {
         assert!(settings.show_cursor_position);
         // Network graph off by default (can be heavy)
         assert!(!settings.show_network_graph);
+        assert!(!settings.show_combat_log);
     }
 
     #[test]
@@ -178,6 +188,7 @@ mod tests {
         assert!(settings.show_cursor_position);
         // Network graph off by default even in all_enabled
         assert!(!settings.show_network_graph);
+        assert!(!settings.show_combat_log);
     }
 
     #[test]
@@ -204,6 +215,18 @@ mod tests {
         assert!(!settings.show_network_graph);
     }
 
+    #[test]
+    fn test_debug_settings_toggle_combat_log() {
+        let mut settings = DebugSettings::default();
+        assert!(!settings.show_combat_log);
+
+        settings.toggle_combat_log();
+        assert!(settings.show_combat_log);
+
+        settings.toggle_combat_log();
+        assert!(!settings.show_combat_log);
+    }
+
     #[test]
     fn test_debug_settings_is_resource() {
         fn assert_resource<T: Resource>() {}
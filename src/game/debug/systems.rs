@@ -6,11 +6,16 @@ use bevy::prelude::*;
 
 use super::components::*;
 use super::resources::*;
+use crate::game::combat::CombatLog;
 use crate::game::input::CursorWorldPosition;
+use crate::game::map::BiomeMap;
 use crate::game::network::{NetworkParent, NetworkStats, TendrilPosition};
 use crate::game::progression::Nutrients;
 use crate::GameState;
 
+/// Number of combat log entries shown in the overlay
+const COMBAT_LOG_LINES: usize = 8;
+
 /// Toggle debug overlay with F3 key
 pub fn toggle_debug_overlay(input: Res<ButtonInput<KeyCode>>, mut settings: ResMut<DebugSettings>) {
     if input.just_pressed(KeyCode::F3) {
@@ -25,6 +30,13 @@ pub fn toggle_network_graph(input: Res<ButtonInput<KeyCode>>, mut settings: ResM
     }
 }
 
+/// Toggle combat log display with F5 key
+pub fn toggle_combat_log(input: Res<ButtonInput<KeyCode>>, mut settings: ResMut<DebugSettings>) {
+    if input.just_pressed(KeyCode::F5) {
+        settings.toggle_combat_log();
+    }
+}
+
 /// Track frame times for FPS calculation
 pub fn track_frame_time(time: Res<Time>, mut tracker: ResMut<FrameTimeTracker>) {
     tracker.record(time.delta_secs());
@@ -91,10 +103,11 @@ pub fn update_network_stats_display(
 
     for mut text in query.iter_mut() {
         **text = format!(
-            "Network: {} segs, {:.0} mass, {:.1}% territory",
+            "Network: {} segs, {:.0} mass, {:.1}% territory, {} latched",
             network_stats.segment_count,
             network_stats.total_mass,
-            network_stats.territory_coverage * 100.0
+            network_stats.territory_coverage * 100.0,
+            network_stats.latched_bacteria
         );
     }
 }
@@ -142,10 +155,11 @@ pub fn update_game_state_display(
     }
 }
 
-/// Update cursor position display text
+/// Update cursor position display text, with the biome under the cursor
 pub fn update_cursor_position_display(
     settings: Res<DebugSettings>,
     cursor_pos: Res<CursorWorldPosition>,
+    biome_map: Res<BiomeMap>,
     mut query: Query<&mut Text, With<CursorPositionText>>,
 ) {
     if !settings.enabled || !settings.show_cursor_position {
@@ -154,12 +168,41 @@ pub fn update_cursor_position_display(
 
     for mut text in query.iter_mut() {
         **text = match cursor_pos.position {
-            Some(pos) => format!("Cursor: ({:.0}, {:.0})", pos.x, pos.y),
+            Some(pos) => format!(
+                "Cursor: ({:.0}, {:.0}) {:?}",
+                pos.x,
+                pos.y,
+                biome_map.biome_at(pos)
+            ),
             None => "Cursor: --".to_string(),
         };
     }
 }
 
+/// Update combat log display with the most recent entries
+pub fn update_combat_log_display(
+    settings: Res<DebugSettings>,
+    log: Res<CombatLog>,
+    mut query: Query<&mut Text, With<CombatLogText>>,
+) {
+    if !settings.enabled {
+        return;
+    }
+
+    for mut text in query.iter_mut() {
+        **text = if settings.show_combat_log {
+            let mut lines = format!("Combat log ({} entries):", log.len());
+            for entry in log.recent(COMBAT_LOG_LINES) {
+                lines.push('\n');
+                lines.push_str(&entry.format());
+            }
+            lines
+        } else {
+            String::new()
+        };
+    }
+}
+
 /// Visualize network graph edges with debug gizmos
 pub fn render_network_graph_debug(
     settings: Res<DebugSettings>,
@@ -244,9 +287,12 @@ pub fn spawn_debug_overlay(mut commands: Commands) {
             parent.spawn((
                 CursorPositionText,
                 Text::new("Cursor: --"),
-                text_font,
+                text_font.clone(),
                 text_color,
             ));
+
+            // Combat log (F5)
+            parent.spawn((CombatLogText, Text::new(""), text_font, text_color));
         });
 }
 
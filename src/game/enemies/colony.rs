@@ -0,0 +1,452 @@
+//! Hostile fungal colonies
+//!
+//! Static colonies placed from the map layout at the start of a run:
+//! - Occupy a circular region and periodically corrupt player segments in it
+//! - Take damage like any hostile and are destroyed at zero health
+//! - Release a nutrient burst when destroyed, with a chance of an upgrade
+//!   that opens upgrade selection
+//! - Colonies flagged as major threats carry `MajorThreat`
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::MajorThreat;
+use super::events::ColonyDestroyed;
+use crate::game::combat::{ApplyStatus, Died, Hostile, Static, StatusEffect, StatusKind};
+use crate::game::map::{ColonySite, MapLayout};
+use crate::game::network::{Faction, Health, TendrilPosition, TendrilSegment};
+use crate::game::progression::{NutrientSource, Nutrients, NutrientsGained};
+use crate::GameState;
+
+/// Tuning shared by every colony
+#[derive(Resource, Debug, Clone)]
+pub struct ColonyConfig {
+    pub health: f32,
+    /// Seconds between corruption pulses
+    pub spread_interval: f32,
+    /// Corruption applied to player segments inside the region each pulse
+    pub corruption: StatusEffect,
+    /// Nutrients released on destruction
+    pub nutrient_burst: f32,
+    /// Chance a regular colony grants an upgrade (major threats always do)
+    pub upgrade_chance: f64,
+    /// Health and nutrient multiplier for major threats
+    pub major_threat_multiplier: f32,
+}
+
+impl Default for ColonyConfig {
+    fn default() -> Self {
+        Self {
+            health: 300.0,
+            spread_interval: 2.0,
+            corruption: StatusEffect::new(StatusKind::Corruption, 0.1, 4.0, 0.5),
+            nutrient_burst: 60.0,
+            upgrade_chance: 0.35,
+            major_threat_multiplier: 2.5,
+        }
+    }
+}
+
+/// A static hostile colony occupying a region
+#[derive(Component, Debug, Clone)]
+pub struct Colony {
+    /// Radius of the occupied region
+    pub radius: f32,
+    /// Seconds until the next corruption pulse
+    pub spread_timer: f32,
+    /// Nutrients released on destruction
+    pub nutrient_burst: f32,
+    /// Chance of granting an upgrade on destruction
+    pub upgrade_chance: f64,
+}
+
+impl Colony {
+    /// Whether a point lies inside the colony's region
+    #[must_use]
+    pub fn contains(&self, center: Vec2, point: Vec2) -> bool {
+        center.distance_squared(point) <= self.radius * self.radius
+    }
+}
+
+/// Spawn a colony for a map site and return its entity
+pub fn spawn_colony(commands: &mut Commands, site: &ColonySite, config: &ColonyConfig) -> Entity {
+    let multiplier = if site.major_threat {
+        config.major_threat_multiplier
+    } else {
+        1.0
+    };
+    let mut entity = commands.spawn((
+        Colony {
+            radius: site.radius,
+            spread_timer: config.spread_interval,
+            nutrient_burst: config.nutrient_burst * multiplier,
+            upgrade_chance: if site.major_threat {
+                1.0
+            } else {
+                config.upgrade_chance
+            },
+        },
+        Hostile,
+        Static,
+        Health::new(config.health * multiplier),
+        Transform::from_translation(site.position.extend(0.0)),
+        Visibility::default(),
+    ));
+    if site.major_threat {
+        entity.insert(MajorThreat);
+    }
+    entity.id()
+}
+
+/// Spawn every colony in the map layout
+pub fn spawn_map_colonies(
+    mut commands: Commands,
+    layout: Res<MapLayout>,
+    config: Res<ColonyConfig>,
+) {
+    for site in layout.colonies.iter() {
+        spawn_colony(&mut commands, site, &config);
+    }
+}
+
+/// Periodically corrupt player segments inside colony regions
+pub fn spread_colony_corruption(
+    time: Res<Time>,
+    config: Res<ColonyConfig>,
+    mut colonies: Query<(Entity, &mut Colony, &Transform)>,
+    segments: Query<(Entity, &TendrilPosition, Option<&Faction>), With<TendrilSegment>>,
+    mut apply: EventWriter<ApplyStatus>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut colony, transform) in colonies.iter_mut() {
+        colony.spread_timer -= delta;
+        if colony.spread_timer > 0.0 {
+            continue;
+        }
+        colony.spread_timer += config.spread_interval;
+
+        let center = transform.translation.truncate();
+        for (target, pos, faction) in segments.iter() {
+            if Faction::is_player(faction) && colony.contains(center, pos.position) {
+                apply.send(ApplyStatus {
+                    target,
+                    effect: config.corruption.clone().with_source(Some(entity)),
+                });
+            }
+        }
+    }
+}
+
+/// Release nutrients for destroyed colonies and remove them
+pub fn handle_colony_deaths(
+    mut commands: Commands,
+    mut died: EventReader<Died>,
+    colonies: Query<(&Colony, &Transform, Has<MajorThreat>)>,
+    mut nutrients: ResMut<Nutrients>,
+    mut gained: EventWriter<NutrientsGained>,
+    mut destroyed: EventWriter<ColonyDestroyed>,
+) {
+    let mut rng = rand::thread_rng();
+    for event in died.read() {
+        let Ok((colony, transform, major_threat)) = colonies.get(event.entity) else {
+            continue;
+        };
+        nutrients.add(colony.nutrient_burst);
+        gained.send(NutrientsGained::new(
+            colony.nutrient_burst,
+            NutrientSource::EnemyDrop,
+        ));
+        destroyed.send(ColonyDestroyed {
+            position: transform.translation.truncate(),
+            nutrients: colony.nutrient_burst,
+            major_threat,
+            grants_upgrade: rng.gen_bool(colony.upgrade_chance.clamp(0.0, 1.0)),
+        });
+        commands.entity(event.entity).despawn_recursive();
+    }
+}
+
+/// Enter upgrade selection when a destroyed colony earned an upgrade
+pub fn offer_colony_upgrades(
+    mut destroyed: EventReader<ColonyDestroyed>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let upgrades = destroyed
+        .read()
+        .filter(|event| event.grants_upgrade)
+        .count();
+    if upgrades > 0 {
+        next_state.set(GameState::Upgrading);
+    }
+}
+
+/// Remove all colonies when the run ends
+pub fn despawn_colonies(mut commands: Commands, colonies: Query<Entity, With<Colony>>) {
+    for entity in colonies.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Draw colony regions and cores, skipping those hidden by fog
+pub fn render_colonies(
+    mut gizmos: Gizmos,
+    colonies: Query<(&Colony, &Transform, &Visibility, Has<MajorThreat>)>,
+) {
+    for (colony, transform, visibility, major_threat) in colonies.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        let center = transform.translation.truncate();
+        let color = if major_threat {
+            Color::srgb(0.85, 0.2, 0.45)
+        } else {
+            Color::srgb(0.6, 0.3, 0.5)
+        };
+        gizmos.circle_2d(center, colony.radius, color.with_alpha(0.4));
+        gizmos.circle_2d(center, 20.0, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::explosion::BurstTriggered;
+    use crate::game::combat::{DamageEvent, DamageType, StatusEffects};
+    use crate::game::enemies::test_utils::{create_test_app, enter_state, TEST_FRAME_SECS};
+    use crate::game::enemies::SpawnDirectorConfig;
+    use crate::GameState;
+
+    /// Collects `ColonyDestroyed` events across frames
+    #[derive(Resource, Default)]
+    struct Destroyed(Vec<ColonyDestroyed>);
+
+    fn collect_destroyed(mut events: EventReader<ColonyDestroyed>, mut out: ResMut<Destroyed>) {
+        out.0.extend(events.read().cloned());
+    }
+
+    fn site(position: Vec2, major_threat: bool) -> ColonySite {
+        ColonySite {
+            position,
+            radius: 100.0,
+            major_threat,
+        }
+    }
+
+    fn create_colony_app(sites: Vec<ColonySite>) -> App {
+        let mut app = create_test_app();
+        app.insert_resource(MapLayout {
+            colonies: sites,
+            bosses: Vec::new(),
+        })
+        .init_resource::<Destroyed>()
+        .add_systems(Update, collect_destroyed);
+        app.world_mut()
+            .resource_mut::<SpawnDirectorConfig>()
+            .max_alive = 0;
+        app.world_mut()
+            .resource_mut::<ColonyConfig>()
+            .spread_interval = TEST_FRAME_SECS;
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn colonies(app: &mut App) -> Vec<Entity> {
+        let mut query = app.world_mut().query_filtered::<Entity, With<Colony>>();
+        query.iter(app.world()).collect()
+    }
+
+    #[test]
+    fn test_colonies_spawn_from_map_layout() {
+        let mut app = create_colony_app(vec![
+            site(Vec2::new(500.0, 0.0), false),
+            site(Vec2::new(-500.0, 0.0), true),
+        ]);
+
+        let spawned = colonies(&mut app);
+        assert_eq!(spawned.len(), 2);
+        let config = ColonyConfig::default();
+        for entity in spawned {
+            let world = app.world();
+            let major = world.get::<MajorThreat>(entity).is_some();
+            let x = world.get::<Transform>(entity).unwrap().translation.x;
+            assert_eq!(major, x < 0.0);
+            let expected = if major {
+                config.health * config.major_threat_multiplier
+            } else {
+                config.health
+            };
+            assert_eq!(world.get::<Health>(entity).unwrap().max, expected);
+            assert!(world.get::<Hostile>(entity).is_some());
+        }
+    }
+
+    #[test]
+    fn test_colony_corrupts_player_segments_inside_region() {
+        let mut app = create_colony_app(vec![site(Vec2::new(500.0, 0.0), false)]);
+        let inside = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(450.0, 0.0), Vec2::X),
+            ))
+            .id();
+        let outside = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(300.0, 0.0), Vec2::X),
+            ))
+            .id();
+        let rival = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(500.0, 0.0), Vec2::X),
+                Faction::Rival,
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let world = app.world();
+        assert!(world
+            .get::<StatusEffects>(inside)
+            .is_some_and(|e| e.has(StatusKind::Corruption)));
+        assert!(world.get::<StatusEffects>(outside).is_none());
+        assert!(world.get::<StatusEffects>(rival).is_none());
+    }
+
+    #[test]
+    fn test_destroyed_colony_releases_nutrients() {
+        let mut app = create_colony_app(vec![site(Vec2::new(500.0, 0.0), true)]);
+        let colony = colonies(&mut app)[0];
+        let before = app.world().resource::<Nutrients>().current;
+
+        app.world_mut().send_event(DamageEvent::new(
+            None,
+            colony,
+            100_000.0,
+            DamageType::Physical,
+        ));
+        app.update();
+        app.update();
+
+        assert!(colonies(&mut app).is_empty());
+        let config = ColonyConfig::default();
+        let burst = config.nutrient_burst * config.major_threat_multiplier;
+        let after = app.world().resource::<Nutrients>().current;
+        assert_eq!(
+            after,
+            (before + burst).min(app.world().resource::<Nutrients>().max)
+        );
+
+        let destroyed = &app.world().resource::<Destroyed>().0;
+        assert_eq!(destroyed.len(), 1);
+        assert!(destroyed[0].major_threat);
+        assert!(destroyed[0].grants_upgrade);
+        assert_eq!(destroyed[0].position, Vec2::new(500.0, 0.0));
+    }
+
+    #[test]
+    fn test_burst_next_to_colony_does_not_move_it() {
+        let mut app = create_colony_app(vec![site(Vec2::new(500.0, 0.0), false)]);
+        let colony = colonies(&mut app)[0];
+        assert!(app.world().get::<Static>(colony).is_some());
+        let source = app.world_mut().spawn_empty().id();
+
+        app.world_mut().send_event(BurstTriggered {
+            source,
+            position: Vec2::new(470.0, 0.0),
+            radius: 100.0,
+            chained: false,
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Transform>(colony).unwrap().translation,
+            Vec3::new(500.0, 0.0, 0.0)
+        );
+        let health = world.get::<Health>(colony).unwrap();
+        assert!(health.current < health.max);
+    }
+
+    fn state(app: &App) -> GameState {
+        *app.world().resource::<State<GameState>>().get()
+    }
+
+    fn destroy(app: &mut App, colony: Entity) {
+        app.world_mut().send_event(DamageEvent::new(
+            None,
+            colony,
+            100_000.0,
+            DamageType::Physical,
+        ));
+        for _ in 0..3 {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn test_colony_granting_upgrade_opens_upgrade_selection() {
+        let mut app = create_colony_app(vec![site(Vec2::new(500.0, 0.0), true)]);
+        let colony = colonies(&mut app)[0];
+
+        destroy(&mut app, colony);
+
+        assert!(app.world().resource::<Destroyed>().0[0].grants_upgrade);
+        assert_eq!(state(&app), GameState::Upgrading);
+    }
+
+    #[test]
+    fn test_colony_without_upgrade_keeps_playing() {
+        let mut app = create_colony_app(vec![site(Vec2::new(500.0, 0.0), false)]);
+        let colony = colonies(&mut app)[0];
+        app.world_mut()
+            .get_mut::<Colony>(colony)
+            .unwrap()
+            .upgrade_chance = 0.0;
+
+        destroy(&mut app, colony);
+
+        assert_eq!(app.world().resource::<Destroyed>().0.len(), 1);
+        assert_eq!(state(&app), GameState::Playing);
+    }
+
+    #[test]
+    fn test_colonies_reset_between_runs() {
+        let mut app = create_colony_app(vec![site(Vec2::new(500.0, 0.0), false)]);
+        enter_state(&mut app, GameState::Paused);
+        assert_eq!(colonies(&mut app).len(), 1);
+
+        enter_state(&mut app, GameState::Playing);
+        enter_state(&mut app, GameState::GameOver);
+        assert!(colonies(&mut app).is_empty());
+
+        enter_state(&mut app, GameState::Playing);
+        assert_eq!(colonies(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn test_colony_region_contains() {
+        let colony = Colony {
+            radius: 10.0,
+            spread_timer: 0.0,
+            nutrient_burst: 0.0,
+            upgrade_chance: 0.0,
+        };
+        assert!(colony.contains(Vec2::ZERO, Vec2::new(10.0, 0.0)));
+        assert!(!colony.contains(Vec2::ZERO, Vec2::new(10.0, 0.1)));
+    }
+
+    #[test]
+    fn test_colony_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<Colony>();
+        assert_resource::<ColonyConfig>();
+    }
+}
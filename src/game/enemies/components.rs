@@ -0,0 +1,57 @@
+//! Enemy components
+
+use bevy::prelude::*;
+
+/// Enemy archetypes from the spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnemyKind {
+    /// Fast swarming insects that chew through tendrils
+    Insect,
+    /// Slow competing fungus that grows and corrupts
+    RivalFungus,
+    /// Tiny bacteria that latch onto segments
+    Bacteria,
+}
+
+/// How an enemy archetype behaves, used by AI systems to pick a controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BehaviourKind {
+    /// Flocks toward the network
+    Swarm,
+    /// Grows its own network toward resources
+    Grow,
+    /// Latches onto a segment and drains it
+    Latch,
+}
+
+/// An enemy instance
+///
+/// Health lives in the shared `Health` component so all damage goes through
+/// the combat pipeline.
+#[derive(Component, Debug, Clone)]
+pub struct Enemy {
+    pub kind: EnemyKind,
+    pub behaviour: BehaviourKind,
+    /// Movement speed in world units per second
+    pub speed: f32,
+    /// Collision radius in world units
+    pub radius: f32,
+    /// Nutrients dropped as orbs when killed
+    pub nutrient_drop: f32,
+}
+
+/// Marker for enemies that must be destroyed to win the run
+#[derive(Component, Debug, Default)]
+pub struct MajorThreat;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enemy_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<Enemy>();
+        assert_component::<MajorThreat>();
+    }
+}
@@ -0,0 +1,629 @@
+//! Spawn director
+//!
+//! Decides how many enemies to spawn, which kinds and where:
+//! - Spawn budget grows with territory coverage, segment count and run time
+//! - Archetype mix shifts with run time and the biome at the spawn point
+//! - Enemies appear just off-screen, outward from the network frontier and
+//!   from rich nutrient nodes the player is tapping, never inside obstacles
+//!
+//! All difficulty curves are data tables in `SpawnDirectorConfig`.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::Rng;
+
+use super::components::{Enemy, EnemyKind};
+use super::events::SpawnEnemy;
+use crate::game::camera::MainCamera;
+use crate::game::map::{BiomeMap, BiomeTable, Obstacles};
+use crate::game::network::{Faction, GrowthTip, NetworkStats, TendrilPosition, TendrilSegment};
+use crate::game::pickups::NutrientNode;
+use crate::game::state::RunStats;
+
+/// Piecewise-linear curve through `(x, y)` points sorted by `x`
+///
+/// Values outside the table are clamped to the first and last points.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Curve {
+    points: Vec<(f32, f32)>,
+}
+
+impl Curve {
+    /// Build a curve, sorting the points by `x`
+    #[must_use]
+    pub fn new(points: &[(f32, f32)]) -> Self {
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { points }
+    }
+
+    /// Curve with the same value everywhere
+    #[must_use]
+    pub fn constant(value: f32) -> Self {
+        Self::new(&[(0.0, value)])
+    }
+
+    /// Value at `x`
+    #[must_use]
+    pub fn sample(&self, x: f32) -> f32 {
+        let Some(&(first_x, first_y)) = self.points.first() else {
+            return 0.0;
+        };
+        if x <= first_x {
+            return first_y;
+        }
+        for pair in self.points.windows(2) {
+            let (x0, y0) = pair[0];
+            let (x1, y1) = pair[1];
+            if x <= x1 {
+                let span = x1 - x0;
+                if span <= 0.0 {
+                    return y1;
+                }
+                return y0 + (y1 - y0) * (x - x0) / span;
+            }
+        }
+        self.points.last().map_or(0.0, |p| p.1)
+    }
+}
+
+/// Difficulty tables for the spawn director
+#[derive(Resource, Debug, Clone)]
+pub struct SpawnDirectorConfig {
+    /// Budget points per second by territory coverage (0.0..=1.0)
+    pub budget_by_coverage: Curve,
+    /// Extra budget points per second by segment count
+    pub budget_by_segments: Curve,
+    /// Budget multiplier by run time in seconds
+    pub budget_multiplier_by_time: Curve,
+    /// Budget cost of each enemy kind
+    pub costs: HashMap<EnemyKind, f32>,
+    /// Spawn weight of each enemy kind by run time in seconds
+    pub weights_by_time: HashMap<EnemyKind, Curve>,
+    /// Maximum enemies alive at once
+    pub max_alive: usize,
+    /// Maximum enemies spawned in a single frame
+    pub max_spawns_per_frame: usize,
+    /// Budget that can be banked while at the alive cap
+    pub max_banked_budget: f32,
+    /// Distance beyond the screen edge where enemies appear
+    pub offscreen_margin: f32,
+    /// Random spread (radians) around the outward frontier direction
+    pub direction_jitter: f32,
+    /// Half size of the view used when the camera has no projection
+    pub fallback_half_extents: Vec2,
+}
+
+impl Default for SpawnDirectorConfig {
+    fn default() -> Self {
+        let mut costs = HashMap::new();
+        costs.insert(EnemyKind::Insect, 1.0);
+        costs.insert(EnemyKind::Bacteria, 0.5);
+        costs.insert(EnemyKind::RivalFungus, 6.0);
+
+        let mut weights_by_time = HashMap::new();
+        weights_by_time.insert(EnemyKind::Insect, Curve::constant(1.0));
+        weights_by_time.insert(
+            EnemyKind::Bacteria,
+            Curve::new(&[(0.0, 0.0), (60.0, 0.5), (300.0, 1.0)]),
+        );
+        weights_by_time.insert(
+            EnemyKind::RivalFungus,
+            Curve::new(&[(0.0, 0.0), (120.0, 0.1), (400.0, 0.3)]),
+        );
+
+        Self {
+            budget_by_coverage: Curve::new(&[
+                (0.0, 0.5),
+                (0.1, 1.0),
+                (0.3, 2.5),
+                (0.6, 5.0),
+                (1.0, 9.0),
+            ]),
+            budget_by_segments: Curve::new(&[(0.0, 0.0), (50.0, 0.5), (200.0, 2.0), (500.0, 4.0)]),
+            budget_multiplier_by_time: Curve::new(&[
+                (0.0, 1.0),
+                (120.0, 1.3),
+                (300.0, 1.8),
+                (600.0, 2.5),
+            ]),
+            costs,
+            weights_by_time,
+            max_alive: 150,
+            max_spawns_per_frame: 8,
+            max_banked_budget: 30.0,
+            offscreen_margin: 48.0,
+            direction_jitter: 0.6,
+            fallback_half_extents: Vec2::new(640.0, 360.0),
+        }
+    }
+}
+
+impl SpawnDirectorConfig {
+    /// Budget points per second for the current network and run time
+    #[must_use]
+    pub fn budget_rate(&self, coverage: f32, segment_count: u32, elapsed: f32) -> f32 {
+        let base = self.budget_by_coverage.sample(coverage)
+            + self.budget_by_segments.sample(segment_count as f32);
+        base * self.budget_multiplier_by_time.sample(elapsed)
+    }
+
+    /// Affordable kinds and their spawn weights at a run time
+    #[must_use]
+    pub fn mix(&self, elapsed: f32, budget: f32) -> Vec<(EnemyKind, f32)> {
+        let mut mix: Vec<_> = self
+            .weights_by_time
+            .iter()
+            .filter(|(kind, _)| self.costs.get(*kind).is_some_and(|&c| c <= budget))
+            .map(|(&kind, curve)| (kind, curve.sample(elapsed)))
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+        // Stable order so weighted picks are reproducible for a given roll
+        mix.sort_by_key(|(kind, _)| *kind as u8);
+        mix
+    }
+}
+
+/// Pick a kind from a weighted mix with `roll` in `0.0..1.0`
+#[must_use]
+pub fn pick_weighted(mix: &[(EnemyKind, f32)], roll: f32) -> Option<EnemyKind> {
+    let total: f32 = mix.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut target = roll.clamp(0.0, 1.0) * total;
+    for &(kind, weight) in mix {
+        if target < weight {
+            return Some(kind);
+        }
+        target -= weight;
+    }
+    mix.last().map(|(kind, _)| *kind)
+}
+
+/// Point where a ray from `anchor` along `direction` leaves `view` grown by `margin`
+///
+/// An anchor already outside the grown view is returned unchanged.
+#[must_use]
+pub fn offscreen_point(view: Rect, anchor: Vec2, direction: Vec2, margin: f32) -> Vec2 {
+    let bounds = view.inflate(margin);
+    if !bounds.contains(anchor) {
+        return anchor;
+    }
+    let direction = direction.normalize_or(Vec2::X);
+    let exit_along = |min: f32, max: f32, start: f32, d: f32| {
+        if d > 0.0 {
+            (max - start) / d
+        } else if d < 0.0 {
+            (min - start) / d
+        } else {
+            f32::INFINITY
+        }
+    };
+    let tx = exit_along(bounds.min.x, bounds.max.x, anchor.x, direction.x);
+    let ty = exit_along(bounds.min.y, bounds.max.y, anchor.y, direction.y);
+    anchor + direction * tx.min(ty)
+}
+
+/// Runtime state of the spawn director
+#[derive(Resource, Debug, Default)]
+pub struct SpawnDirector {
+    /// Unspent budget points
+    pub budget: f32,
+    /// Budget rate used on the last frame (points per second)
+    pub current_rate: f32,
+}
+
+/// Reset the director when a new run starts
+pub fn reset_spawn_director(mut director: ResMut<SpawnDirector>) {
+    *director = SpawnDirector::default();
+}
+
+/// Accumulate budget and spend it on spawn requests
+pub fn run_spawn_director(
+    time: Res<Time>,
+    config: Res<SpawnDirectorConfig>,
+    stats: Res<NetworkStats>,
+    run_stats: Res<RunStats>,
+    mut director: ResMut<SpawnDirector>,
+    cameras: Query<(&Transform, Option<&OrthographicProjection>), With<MainCamera>>,
+    tips: Query<(&TendrilPosition, Option<&Faction>), With<GrowthTip>>,
+    segments: Query<(&TendrilPosition, Option<&Faction>), With<TendrilSegment>>,
+    enemies: Query<(), With<Enemy>>,
+    nodes: Query<(&NutrientNode, &Transform)>,
+    biome_map: Res<BiomeMap>,
+    biomes: Res<BiomeTable>,
+    obstacles: Res<Obstacles>,
+    mut spawns: EventWriter<SpawnEnemy>,
+) {
+    let rate = config.budget_rate(
+        stats.territory_coverage,
+        stats.segment_count,
+        run_stats.elapsed_time,
+    );
+    director.current_rate = rate;
+    director.budget = (director.budget + rate * time.delta_secs()).min(config.max_banked_budget);
+
+    let room = config
+        .max_alive
+        .saturating_sub(enemies.iter().count())
+        .min(config.max_spawns_per_frame);
+    if room == 0 {
+        return;
+    }
+
+    let view = match cameras.get_single() {
+        Ok((transform, projection)) => {
+            let center = transform.translation.truncate();
+            let half = projection.map_or(config.fallback_half_extents, |p| p.area.half_size());
+            Rect::from_center_half_size(center, half)
+        }
+        Err(_) => Rect::from_center_half_size(Vec2::ZERO, config.fallback_half_extents),
+    };
+
+    // Frontier anchors: player growth tips first, any player segment
+    // otherwise, the core last
+    let mut frontier: Vec<Vec2> = tips
+        .iter()
+        .filter(|(_, faction)| Faction::is_player(*faction))
+        .map(|(tip, _)| tip.position)
+        .collect();
+    if frontier.is_empty() {
+        frontier = segments
+            .iter()
+            .filter(|(_, faction)| Faction::is_player(*faction))
+            .map(|(segment, _)| segment.position)
+            .collect();
+    }
+    if frontier.is_empty() {
+        frontier.push(Vec2::ZERO);
+    }
+    // Held rich nodes draw attacks as well
+    frontier.extend(
+        nodes
+            .iter()
+            .filter(|(node, _)| node.rich && node.tapped)
+            .map(|(_, transform)| transform.translation.truncate()),
+    );
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..room {
+        let anchor = frontier[rng.gen_range(0..frontier.len())];
+        let outward = if anchor.length_squared() > f32::EPSILON {
+            anchor.normalize()
+        } else {
+            Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU))
+        };
+        let jitter = rng.gen_range(-config.direction_jitter..=config.direction_jitter);
+        let direction = Vec2::from_angle(jitter).rotate(outward);
+        let position = offscreen_point(view, anchor, direction, config.offscreen_margin);
+        if obstacles.contains(position) {
+            continue;
+        }
+
+        let mut mix = config.mix(run_stats.elapsed_time, director.budget);
+        for (kind, weight) in mix.iter_mut() {
+            *weight *= biomes.spawn_weight(&biome_map, position, *kind);
+        }
+        let Some(kind) = pick_weighted(&mix, rng.gen()) else {
+            break;
+        };
+        director.budget -= config.costs[&kind];
+
+        spawns.send(SpawnEnemy::new(kind, position));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::enemies::test_utils::{create_test_app, enter_state};
+    use crate::game::enemies::EnemyArchetypes;
+    use crate::game::map::{Biome, BiomeRegion, ObstacleSite};
+    use crate::GameState;
+
+    #[test]
+    fn test_curve_interpolates_and_clamps() {
+        let curve = Curve::new(&[(10.0, 1.0), (0.0, 0.0), (20.0, 3.0)]);
+        assert_eq!(curve.sample(-5.0), 0.0);
+        assert_eq!(curve.sample(5.0), 0.5);
+        assert_eq!(curve.sample(15.0), 2.0);
+        assert_eq!(curve.sample(100.0), 3.0);
+        assert_eq!(Curve::default().sample(1.0), 0.0);
+        assert_eq!(Curve::constant(2.0).sample(50.0), 2.0);
+    }
+
+    #[test]
+    fn test_budget_rate_monotonic_in_coverage() {
+        let config = SpawnDirectorConfig::default();
+        let mut previous = 0.0;
+        for step in 0..=100 {
+            let coverage = step as f32 / 100.0;
+            let rate = config.budget_rate(coverage, 40, 60.0);
+            assert!(rate >= previous, "rate dropped at coverage {coverage}");
+            previous = rate;
+        }
+    }
+
+    #[test]
+    fn test_budget_rate_grows_with_segments_and_time() {
+        let config = SpawnDirectorConfig::default();
+        assert!(config.budget_rate(0.2, 200, 0.0) > config.budget_rate(0.2, 10, 0.0));
+        assert!(config.budget_rate(0.2, 10, 300.0) > config.budget_rate(0.2, 10, 0.0));
+    }
+
+    #[test]
+    fn test_mix_shifts_with_time_and_budget() {
+        let config = SpawnDirectorConfig::default();
+        let early = config.mix(0.0, 100.0);
+        assert_eq!(early, vec![(EnemyKind::Insect, 1.0)]);
+
+        let late = config.mix(400.0, 100.0);
+        assert_eq!(late.len(), 3);
+
+        // Rival fungi cost more than a small budget
+        let poor = config.mix(400.0, 2.0);
+        assert!(poor.iter().all(|(kind, _)| *kind != EnemyKind::RivalFungus));
+    }
+
+    #[test]
+    fn test_pick_weighted() {
+        let mix = [(EnemyKind::Insect, 3.0), (EnemyKind::Bacteria, 1.0)];
+        assert_eq!(pick_weighted(&mix, 0.0), Some(EnemyKind::Insect));
+        assert_eq!(pick_weighted(&mix, 0.74), Some(EnemyKind::Insect));
+        assert_eq!(pick_weighted(&mix, 0.76), Some(EnemyKind::Bacteria));
+        assert_eq!(pick_weighted(&mix, 1.0), Some(EnemyKind::Bacteria));
+        assert_eq!(pick_weighted(&[], 0.5), None);
+    }
+
+    #[test]
+    fn test_offscreen_point_leaves_view_in_direction() {
+        let view = Rect::from_center_half_size(Vec2::ZERO, Vec2::new(100.0, 50.0));
+        assert_eq!(
+            offscreen_point(view, Vec2::ZERO, Vec2::X, 10.0),
+            Vec2::new(110.0, 0.0)
+        );
+        assert_eq!(
+            offscreen_point(view, Vec2::new(20.0, 0.0), Vec2::NEG_Y, 10.0),
+            Vec2::new(20.0, -60.0)
+        );
+        // Already off-screen anchors are used as-is
+        let far = Vec2::new(500.0, 0.0);
+        assert_eq!(offscreen_point(view, far, Vec2::X, 10.0), far);
+    }
+
+    #[test]
+    fn test_director_types_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<SpawnDirector>();
+        assert_resource::<SpawnDirectorConfig>();
+    }
+
+    fn spawned_enemies(app: &mut App) -> Vec<(EnemyKind, Vec2)> {
+        let mut query = app.world_mut().query::<(&Enemy, &Transform)>();
+        query
+            .iter(app.world())
+            .map(|(e, t)| (e.kind, t.translation.truncate()))
+            .collect()
+    }
+
+    #[test]
+    fn test_headless_budget_rate_grows_with_coverage() {
+        let mut app = create_test_app();
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+
+        let mut previous = 0.0;
+        for step in 0..=10 {
+            app.world_mut()
+                .resource_mut::<NetworkStats>()
+                .territory_coverage = step as f32 / 10.0;
+            app.update();
+            let rate = app.world().resource::<SpawnDirector>().current_rate;
+            assert!(rate >= previous);
+            previous = rate;
+        }
+        assert!(previous > 0.0);
+    }
+
+    #[test]
+    fn test_director_spawns_offscreen_near_frontier() {
+        let mut app = create_test_app();
+        // Freeze insects where they spawn
+        app.world_mut()
+            .resource_mut::<EnemyArchetypes>()
+            .get_mut(EnemyKind::Insect)
+            .unwrap()
+            .speed = 0.0;
+        app.world_mut().spawn((MainCamera, Transform::default()));
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            GrowthTip::default(),
+            TendrilPosition::new(Vec2::new(200.0, 0.0), Vec2::X),
+        ));
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app.world_mut()
+            .resource_mut::<NetworkStats>()
+            .territory_coverage = 1.0;
+        for _ in 0..8 {
+            app.update();
+        }
+
+        let enemies = spawned_enemies(&mut app);
+        assert!(!enemies.is_empty());
+        let config = SpawnDirectorConfig::default();
+        let view = Rect::from_center_half_size(Vec2::ZERO, config.fallback_half_extents);
+        for (_, position) in enemies {
+            assert!(!view.contains(position), "{position} is on screen");
+            // Spawned outward from the tip on the +x side
+            assert!(position.x > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_director_weights_kinds_by_spawn_biome() {
+        let mut app = create_test_app();
+        app.insert_resource(SpawnDirectorConfig {
+            budget_by_coverage: Curve::constant(100.0),
+            ..default()
+        });
+        app.insert_resource(BiomeMap::new(vec![BiomeRegion {
+            biome: Biome::Swamp,
+            center: Vec2::ZERO,
+        }]));
+        // Early runs only field insects, which this swamp never spawns
+        app.world_mut()
+            .resource_mut::<BiomeTable>()
+            .get_mut(Biome::Swamp)
+            .unwrap()
+            .spawn_weights
+            .insert(EnemyKind::Insect, 0.0);
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(spawned_enemies(&mut app).is_empty());
+        assert!(app.world().resource::<SpawnDirector>().budget > 0.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct Requested(Vec<Vec2>);
+
+    fn collect_requests(mut requests: EventReader<SpawnEnemy>, mut out: ResMut<Requested>) {
+        out.0
+            .extend(requests.read().map(|request| request.position));
+    }
+
+    #[test]
+    fn test_director_sends_enemies_at_tapped_rich_nodes() {
+        let mut app = create_test_app();
+        app.init_resource::<Requested>()
+            .add_systems(Update, collect_requests);
+        app.world_mut()
+            .resource_mut::<EnemyArchetypes>()
+            .get_mut(EnemyKind::Insect)
+            .unwrap()
+            .speed = 0.0;
+        // Enter the menu first, which clears nodes left from a previous run
+        app.update();
+        app.world_mut().spawn((MainCamera, Transform::default()));
+        // The only tip is west of the camera, the tapped rich node far east
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            GrowthTip::default(),
+            TendrilPosition::new(Vec2::new(-200.0, 0.0), Vec2::X),
+        ));
+        app.world_mut().spawn((
+            NutrientNode::new(1000.0, 0.0, true),
+            Transform::from_xyz(3000.0, 0.0, 0.0),
+        ));
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::new(3000.0, 0.0), Vec2::X),
+        ));
+        enter_state(&mut app, GameState::Playing);
+        app.world_mut()
+            .resource_mut::<NetworkStats>()
+            .territory_coverage = 1.0;
+        for _ in 0..20 {
+            app.update();
+        }
+
+        // Enemies spawned on the node's segment may be shot down at once,
+        // so check where they were requested
+        let requested = &app.world().resource::<Requested>().0;
+        assert!(requested.iter().any(|p| p.x > 2000.0));
+        assert!(requested.iter().any(|p| p.x < 0.0));
+    }
+
+    #[test]
+    fn test_director_ignores_rival_tips() {
+        let mut app = create_test_app();
+        app.init_resource::<Requested>()
+            .add_systems(Update, collect_requests);
+        app.update();
+        app.world_mut().spawn((MainCamera, Transform::default()));
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            GrowthTip::default(),
+            TendrilPosition::new(Vec2::new(200.0, 0.0), Vec2::X),
+        ));
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            GrowthTip::default(),
+            Faction::Rival,
+            TendrilPosition::new(Vec2::new(-3000.0, 0.0), Vec2::X),
+        ));
+        enter_state(&mut app, GameState::Playing);
+        app.world_mut()
+            .resource_mut::<NetworkStats>()
+            .territory_coverage = 1.0;
+        for _ in 0..8 {
+            app.update();
+        }
+
+        let requested = &app.world().resource::<Requested>().0;
+        assert!(!requested.is_empty());
+        assert!(requested.iter().all(|p| p.x > 0.0));
+    }
+
+    #[test]
+    fn test_director_rejects_spawns_inside_obstacles() {
+        let mut app = create_test_app();
+        app.init_resource::<Requested>()
+            .add_systems(Update, collect_requests);
+        app.insert_resource(SpawnDirectorConfig {
+            budget_by_coverage: Curve::constant(100.0),
+            ..default()
+        });
+        // One obstacle covering every possible spawn point
+        app.insert_resource(Obstacles::new(&[ObstacleSite {
+            vertices: vec![
+                Vec2::splat(-10000.0),
+                Vec2::new(10000.0, -10000.0),
+                Vec2::splat(10000.0),
+                Vec2::new(-10000.0, 10000.0),
+            ],
+        }]));
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        for _ in 0..8 {
+            app.update();
+        }
+
+        assert!(app.world().resource::<Requested>().0.is_empty());
+        assert!(app.world().resource::<SpawnDirector>().budget > 0.0);
+    }
+
+    #[test]
+    fn test_director_respects_alive_cap() {
+        let mut app = create_test_app();
+        app.insert_resource(SpawnDirectorConfig {
+            max_alive: 3,
+            budget_by_coverage: Curve::constant(100.0),
+            ..default()
+        });
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(spawned_enemies(&mut app).len(), 3);
+    }
+
+    #[test]
+    fn test_director_idle_outside_playing() {
+        let mut app = create_test_app();
+        app.insert_resource(SpawnDirectorConfig {
+            budget_by_coverage: Curve::constant(100.0),
+            ..default()
+        });
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(spawned_enemies(&mut app).is_empty());
+        assert_eq!(app.world().resource::<SpawnDirector>().budget, 0.0);
+    }
+}
@@ -0,0 +1,723 @@
+//! Elite enemies
+//!
+//! Random affixes layered on top of the base archetypes:
+//! - Affixes are data in `EliteAffixes` and compose with each other
+//! - Elite chance and affix count scale with run difficulty (time and
+//!   territory), and stronger affixes unlock at higher difficulty
+//! - Elites drop more nutrients and are drawn with an outline
+//!
+//! Only enemies spawned through `SpawnEnemy` can become elites; the children
+//! of splitting elites are regular enemies.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::Rng;
+
+use super::components::Enemy;
+use super::director::Curve;
+use super::events::EnemySpawned;
+use super::resources::EnemyArchetypes;
+use super::systems::spawn_enemy;
+use crate::game::combat::{ApplyStatus, Armor, Died, StatusEffect, StatusKind};
+use crate::game::network::{Faction, Health, NetworkStats, TendrilPosition, TendrilSegment};
+use crate::game::state::RunStats;
+
+/// Elite affixes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EliteAffix {
+    /// Flat damage reduction and extra health
+    Armored,
+    /// Moves much faster
+    Fast,
+    /// Splits into smaller copies on death
+    Splitting,
+    /// Corrupts nearby segments
+    CorruptingAura,
+    /// Heals over time
+    Regenerating,
+}
+
+impl EliteAffix {
+    /// Every affix, in a stable order
+    pub const ALL: [Self; 5] = [
+        Self::Armored,
+        Self::Fast,
+        Self::Splitting,
+        Self::CorruptingAura,
+        Self::Regenerating,
+    ];
+}
+
+/// Children left behind by a splitting elite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplitStats {
+    pub count: u32,
+    /// Child health as a fraction of the archetype's
+    pub health_fraction: f32,
+    /// Child radius as a fraction of the archetype's
+    pub scale: f32,
+}
+
+/// Corruption pulsed onto nearby player segments
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuraStats {
+    pub radius: f32,
+    /// Seconds between pulses
+    pub interval: f32,
+    pub effect: StatusEffect,
+}
+
+/// Data definition of one affix
+///
+/// Multipliers compose by multiplying and flat bonuses by adding; of several
+/// splits or auras the strongest wins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AffixStats {
+    /// Relative chance of being rolled
+    pub weight: f32,
+    /// Run difficulty below which the affix never rolls
+    pub min_difficulty: f32,
+    pub health_multiplier: f32,
+    pub speed_multiplier: f32,
+    /// Flat armor added
+    pub armor: f32,
+    /// Health regenerated per second
+    pub regeneration: f32,
+    pub split: Option<SplitStats>,
+    pub aura: Option<AuraStats>,
+}
+
+impl Default for AffixStats {
+    fn default() -> Self {
+        Self {
+            weight: 1.0,
+            min_difficulty: 0.0,
+            health_multiplier: 1.0,
+            speed_multiplier: 1.0,
+            armor: 0.0,
+            regeneration: 0.0,
+            split: None,
+            aura: None,
+        }
+    }
+}
+
+/// Affix definitions by affix
+#[derive(Resource, Debug, Clone)]
+pub struct EliteAffixes {
+    affixes: HashMap<EliteAffix, AffixStats>,
+}
+
+impl EliteAffixes {
+    #[must_use]
+    pub fn get(&self, affix: EliteAffix) -> Option<&AffixStats> {
+        self.affixes.get(&affix)
+    }
+
+    pub fn get_mut(&mut self, affix: EliteAffix) -> Option<&mut AffixStats> {
+        self.affixes.get_mut(&affix)
+    }
+
+    pub fn set(&mut self, affix: EliteAffix, stats: AffixStats) {
+        self.affixes.insert(affix, stats);
+    }
+}
+
+impl Default for EliteAffixes {
+    fn default() -> Self {
+        let mut affixes = HashMap::new();
+        affixes.insert(
+            EliteAffix::Armored,
+            AffixStats {
+                health_multiplier: 1.2,
+                armor: 4.0,
+                ..default()
+            },
+        );
+        affixes.insert(
+            EliteAffix::Fast,
+            AffixStats {
+                health_multiplier: 0.9,
+                speed_multiplier: 1.6,
+                ..default()
+            },
+        );
+        affixes.insert(
+            EliteAffix::Splitting,
+            AffixStats {
+                weight: 0.8,
+                min_difficulty: 0.5,
+                split: Some(SplitStats {
+                    count: 2,
+                    health_fraction: 0.4,
+                    scale: 0.7,
+                }),
+                ..default()
+            },
+        );
+        affixes.insert(
+            EliteAffix::CorruptingAura,
+            AffixStats {
+                weight: 0.6,
+                min_difficulty: 0.75,
+                aura: Some(AuraStats {
+                    radius: 90.0,
+                    interval: 1.0,
+                    effect: StatusEffect::new(StatusKind::Corruption, 0.05, 3.0, 0.5),
+                }),
+                ..default()
+            },
+        );
+        affixes.insert(
+            EliteAffix::Regenerating,
+            AffixStats {
+                weight: 0.8,
+                min_difficulty: 0.25,
+                health_multiplier: 1.3,
+                regeneration: 4.0,
+                ..default()
+            },
+        );
+        Self { affixes }
+    }
+}
+
+/// Elite roll tuning
+#[derive(Resource, Debug, Clone)]
+pub struct EliteConfig {
+    /// Seconds of run time worth one point of difficulty
+    pub seconds_per_difficulty: f32,
+    /// Difficulty added at full territory coverage
+    pub coverage_difficulty: f32,
+    /// Chance (0.0..=1.0) a spawned enemy is elite, by difficulty
+    pub chance_by_difficulty: Curve,
+    /// Most affixes an elite can roll, by difficulty
+    pub max_affixes_by_difficulty: Curve,
+    /// Nutrient drop multiplier for elites
+    pub drop_multiplier: f32,
+}
+
+impl Default for EliteConfig {
+    fn default() -> Self {
+        Self {
+            seconds_per_difficulty: 600.0,
+            coverage_difficulty: 1.0,
+            chance_by_difficulty: Curve::new(&[(0.0, 0.0), (0.25, 0.02), (1.0, 0.1), (2.0, 0.25)]),
+            max_affixes_by_difficulty: Curve::new(&[(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]),
+            drop_multiplier: 2.5,
+        }
+    }
+}
+
+impl EliteConfig {
+    /// Run difficulty from elapsed time and territory coverage (0.0..=1.0)
+    #[must_use]
+    pub fn difficulty(&self, elapsed: f32, coverage: f32) -> f32 {
+        let time = if self.seconds_per_difficulty > 0.0 {
+            elapsed / self.seconds_per_difficulty
+        } else {
+            0.0
+        };
+        time + coverage.clamp(0.0, 1.0) * self.coverage_difficulty
+    }
+}
+
+/// Combined effect of a set of affixes
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliteModifiers {
+    pub health_multiplier: f32,
+    pub speed_multiplier: f32,
+    pub armor: f32,
+    pub regeneration: f32,
+    pub split: Option<SplitStats>,
+    pub aura: Option<AuraStats>,
+}
+
+/// Compose affixes into one set of modifiers
+#[must_use]
+pub fn combine_affixes(affixes: &[EliteAffix], table: &EliteAffixes) -> EliteModifiers {
+    let mut modifiers = EliteModifiers {
+        health_multiplier: 1.0,
+        speed_multiplier: 1.0,
+        armor: 0.0,
+        regeneration: 0.0,
+        split: None,
+        aura: None,
+    };
+    for stats in affixes.iter().filter_map(|&a| table.get(a)) {
+        modifiers.health_multiplier *= stats.health_multiplier;
+        modifiers.speed_multiplier *= stats.speed_multiplier;
+        modifiers.armor += stats.armor;
+        modifiers.regeneration += stats.regeneration;
+        if let Some(split) = stats.split {
+            if modifiers.split.is_none_or(|s| split.count > s.count) {
+                modifiers.split = Some(split);
+            }
+        }
+        if let Some(aura) = &stats.aura {
+            if modifiers
+                .aura
+                .as_ref()
+                .is_none_or(|a| aura.radius > a.radius)
+            {
+                modifiers.aura = Some(aura.clone());
+            }
+        }
+    }
+    modifiers
+}
+
+/// Roll the affixes of a newly spawned enemy, empty when it is not elite
+pub fn roll_affixes(
+    rng: &mut impl Rng,
+    difficulty: f32,
+    config: &EliteConfig,
+    table: &EliteAffixes,
+) -> Vec<EliteAffix> {
+    let chance = config
+        .chance_by_difficulty
+        .sample(difficulty)
+        .clamp(0.0, 1.0);
+    if !rng.gen_bool(f64::from(chance)) {
+        return Vec::new();
+    }
+    let max = config
+        .max_affixes_by_difficulty
+        .sample(difficulty)
+        .floor()
+        .max(1.0) as usize;
+
+    let mut pool: Vec<(EliteAffix, f32)> = EliteAffix::ALL
+        .iter()
+        .filter_map(|&affix| table.get(affix).map(|stats| (affix, stats)))
+        .filter(|(_, stats)| stats.weight > 0.0 && difficulty >= stats.min_difficulty)
+        .map(|(affix, stats)| (affix, stats.weight))
+        .collect();
+
+    let mut rolled = Vec::new();
+    while rolled.len() < max && !pool.is_empty() {
+        let total: f32 = pool.iter().map(|(_, w)| w).sum();
+        let mut target = rng.gen_range(0.0..1.0) * total;
+        let index = pool
+            .iter()
+            .position(|(_, weight)| {
+                if target < *weight {
+                    return true;
+                }
+                target -= weight;
+                false
+            })
+            .unwrap_or(pool.len() - 1);
+        rolled.push(pool.remove(index).0);
+    }
+    rolled
+}
+
+/// Marks an elite and lists its affixes
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Elite {
+    pub affixes: Vec<EliteAffix>,
+}
+
+/// Health regenerated per second
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Regenerating(pub f32);
+
+/// Periodically corrupts player segments nearby
+#[derive(Component, Debug, Clone)]
+pub struct CorruptingAura {
+    pub stats: AuraStats,
+    /// Seconds until the next pulse
+    pub timer: f32,
+}
+
+/// Leaves smaller copies behind on death
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SplitsOnDeath(pub SplitStats);
+
+/// Roll affixes for enemies spawned this frame and apply them
+pub fn promote_elites(
+    mut commands: Commands,
+    mut spawned: EventReader<EnemySpawned>,
+    config: Res<EliteConfig>,
+    table: Res<EliteAffixes>,
+    run_stats: Res<RunStats>,
+    stats: Res<NetworkStats>,
+    mut enemies: Query<(&mut Enemy, &mut Health)>,
+) {
+    let difficulty = config.difficulty(run_stats.elapsed_time, stats.territory_coverage);
+    let mut rng = rand::thread_rng();
+    for event in spawned.read() {
+        let Ok((mut enemy, mut health)) = enemies.get_mut(event.entity) else {
+            continue;
+        };
+        let affixes = roll_affixes(&mut rng, difficulty, &config, &table);
+        if affixes.is_empty() {
+            continue;
+        }
+        let modifiers = combine_affixes(&affixes, &table);
+
+        enemy.speed *= modifiers.speed_multiplier;
+        enemy.nutrient_drop *= config.drop_multiplier;
+        *health = Health::new(health.max * modifiers.health_multiplier);
+
+        let mut entity = commands.entity(event.entity);
+        entity.insert(Elite { affixes });
+        if modifiers.armor > 0.0 {
+            entity.insert(Armor(modifiers.armor));
+        }
+        if modifiers.regeneration > 0.0 {
+            entity.insert(Regenerating(modifiers.regeneration));
+        }
+        if let Some(split) = modifiers.split {
+            entity.insert(SplitsOnDeath(split));
+        }
+        if let Some(aura) = modifiers.aura {
+            entity.insert(CorruptingAura {
+                timer: aura.interval,
+                stats: aura,
+            });
+        }
+    }
+}
+
+/// Heal regenerating elites that are still alive
+pub fn regenerate_elites(time: Res<Time>, mut elites: Query<(&Regenerating, &mut Health)>) {
+    let delta = time.delta_secs();
+    for (regenerating, mut health) in elites.iter_mut() {
+        if !health.is_dead() {
+            health.heal(regenerating.0 * delta);
+        }
+    }
+}
+
+/// Pulse corruption from aura elites onto nearby player segments
+pub fn pulse_corrupting_auras(
+    time: Res<Time>,
+    mut auras: Query<(Entity, &mut CorruptingAura, &Transform)>,
+    segments: Query<(Entity, &TendrilPosition, Option<&Faction>), With<TendrilSegment>>,
+    mut apply: EventWriter<ApplyStatus>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut aura, transform) in auras.iter_mut() {
+        aura.timer -= delta;
+        if aura.timer > 0.0 {
+            continue;
+        }
+        aura.timer += aura.stats.interval.max(f32::EPSILON);
+
+        let center = transform.translation.truncate();
+        let range_sq = aura.stats.radius * aura.stats.radius;
+        for (target, pos, faction) in segments.iter() {
+            if Faction::is_player(faction) && pos.position.distance_squared(center) <= range_sq {
+                apply.send(ApplyStatus {
+                    target,
+                    effect: aura.stats.effect.clone().with_source(Some(entity)),
+                });
+            }
+        }
+    }
+}
+
+/// Spawn the children of splitting elites that died this frame
+pub fn split_elites_on_death(
+    mut commands: Commands,
+    mut died: EventReader<Died>,
+    archetypes: Res<EnemyArchetypes>,
+    elites: Query<(&Enemy, &Transform, &SplitsOnDeath)>,
+) {
+    for event in died.read() {
+        let Ok((enemy, transform, split)) = elites.get(event.entity) else {
+            continue;
+        };
+        let Some(archetype) = archetypes.get(enemy.kind) else {
+            continue;
+        };
+        let mut child = archetype.clone();
+        child.health *= split.0.health_fraction;
+        child.radius *= split.0.scale;
+        child.nutrient_drop *= split.0.health_fraction;
+
+        let center = transform.translation.truncate();
+        let count = split.0.count.max(1);
+        for i in 0..count {
+            let angle = std::f32::consts::TAU * i as f32 / count as f32;
+            let position = center + Vec2::from_angle(angle) * enemy.radius;
+            spawn_enemy(&mut commands, enemy.kind, &child, position);
+        }
+    }
+}
+
+/// Outline colour of an elite by its strongest affix
+#[must_use]
+pub fn elite_outline_color(affixes: &[EliteAffix]) -> Color {
+    if affixes.len() > 1 {
+        return Color::srgb(1.0, 0.85, 0.2);
+    }
+    match affixes.first() {
+        Some(EliteAffix::Armored) => Color::srgb(0.7, 0.7, 0.8),
+        Some(EliteAffix::Fast) => Color::srgb(0.3, 0.8, 1.0),
+        Some(EliteAffix::Splitting) => Color::srgb(0.9, 0.5, 0.9),
+        Some(EliteAffix::CorruptingAura) => Color::srgb(0.6, 0.2, 0.8),
+        Some(EliteAffix::Regenerating) => Color::srgb(0.3, 0.9, 0.4),
+        None => Color::WHITE,
+    }
+}
+
+/// Draw an outline around elites and their aura range
+pub fn render_elite_outlines(
+    mut gizmos: Gizmos,
+    elites: Query<(
+        &Enemy,
+        &Elite,
+        &Transform,
+        &Visibility,
+        Option<&CorruptingAura>,
+    )>,
+) {
+    for (enemy, elite, transform, visibility, aura) in elites.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        let center = transform.translation.truncate();
+        let color = elite_outline_color(&elite.affixes);
+        gizmos.circle_2d(center, enemy.radius + 3.0, color);
+        if let Some(aura) = aura {
+            gizmos.circle_2d(center, aura.stats.radius, color.with_alpha(0.25));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::StatusEffects;
+    use crate::game::combat::{DamageEvent, DamageType};
+    use crate::game::enemies::test_utils::{create_test_app, enter_state};
+    use crate::game::enemies::{EnemyKind, SpawnDirectorConfig, SpawnEnemy};
+    use crate::GameState;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Config that always rolls exactly one affix
+    fn always_elite() -> EliteConfig {
+        EliteConfig {
+            chance_by_difficulty: Curve::constant(1.0),
+            max_affixes_by_difficulty: Curve::constant(1.0),
+            ..default()
+        }
+    }
+
+    /// Affix table where only `affix` can roll
+    fn only(affix: EliteAffix) -> EliteAffixes {
+        let mut table = EliteAffixes::default();
+        for other in EliteAffix::ALL {
+            let stats = table.get_mut(other).unwrap();
+            stats.min_difficulty = 0.0;
+            if other != affix {
+                stats.weight = 0.0;
+            }
+        }
+        table
+    }
+
+    fn create_elite_app(affix: EliteAffix) -> App {
+        let mut app = create_test_app();
+        app.insert_resource(always_elite())
+            .insert_resource(only(affix));
+        app.world_mut()
+            .resource_mut::<SpawnDirectorConfig>()
+            .max_alive = 0;
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn spawn(app: &mut App, kind: EnemyKind, position: Vec2) -> Entity {
+        app.world_mut().send_event(SpawnEnemy::new(kind, position));
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Elite>>();
+        query.iter(app.world()).last().unwrap()
+    }
+
+    #[test]
+    fn test_affixes_compose() {
+        let table = EliteAffixes::default();
+        let modifiers = combine_affixes(
+            &[
+                EliteAffix::Armored,
+                EliteAffix::Fast,
+                EliteAffix::Regenerating,
+            ],
+            &table,
+        );
+        let armored = table.get(EliteAffix::Armored).unwrap();
+        let fast = table.get(EliteAffix::Fast).unwrap();
+        let regen = table.get(EliteAffix::Regenerating).unwrap();
+        assert_eq!(
+            modifiers.health_multiplier,
+            armored.health_multiplier * fast.health_multiplier * regen.health_multiplier
+        );
+        assert_eq!(modifiers.speed_multiplier, fast.speed_multiplier);
+        assert_eq!(modifiers.armor, armored.armor);
+        assert_eq!(modifiers.regeneration, regen.regeneration);
+        assert!(modifiers.split.is_none());
+        assert!(modifiers.aura.is_none());
+    }
+
+    #[test]
+    fn test_no_elites_at_zero_difficulty() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let config = EliteConfig::default();
+        let table = EliteAffixes::default();
+        for _ in 0..200 {
+            assert!(roll_affixes(&mut rng, 0.0, &config, &table).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_difficulty_grows_with_time_and_territory() {
+        let config = EliteConfig::default();
+        assert_eq!(config.difficulty(0.0, 0.0), 0.0);
+        assert!(config.difficulty(300.0, 0.0) > 0.0);
+        assert!(config.difficulty(300.0, 0.5) > config.difficulty(300.0, 0.0));
+    }
+
+    #[test]
+    fn test_rolls_respect_unlock_difficulty_and_count() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let config = EliteConfig {
+            chance_by_difficulty: Curve::constant(1.0),
+            max_affixes_by_difficulty: Curve::new(&[(0.0, 1.0), (2.0, 3.0)]),
+            ..default()
+        };
+        let table = EliteAffixes::default();
+        for _ in 0..200 {
+            let early = roll_affixes(&mut rng, 0.1, &config, &table);
+            assert_eq!(early.len(), 1);
+            assert!(matches!(early[0], EliteAffix::Armored | EliteAffix::Fast));
+
+            let late = roll_affixes(&mut rng, 2.0, &config, &table);
+            assert_eq!(late.len(), 3);
+            for affix in EliteAffix::ALL {
+                assert!(late.iter().filter(|a| **a == affix).count() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_armored_elite_gets_armor_health_and_drops() {
+        let mut app = create_elite_app(EliteAffix::Armored);
+        let entity = spawn(&mut app, EnemyKind::Insect, Vec2::new(500.0, 0.0));
+        let archetype = EnemyArchetypes::default()
+            .get(EnemyKind::Insect)
+            .unwrap()
+            .clone();
+        let stats = EliteAffixes::default()
+            .get(EliteAffix::Armored)
+            .unwrap()
+            .clone();
+
+        let world = app.world();
+        assert_eq!(world.get::<Armor>(entity).unwrap().0, stats.armor);
+        assert_eq!(
+            world.get::<Health>(entity).unwrap().max,
+            archetype.health * stats.health_multiplier
+        );
+        assert_eq!(
+            world.get::<Enemy>(entity).unwrap().nutrient_drop,
+            archetype.nutrient_drop * EliteConfig::default().drop_multiplier
+        );
+    }
+
+    #[test]
+    fn test_fast_elite_moves_faster() {
+        let mut app = create_elite_app(EliteAffix::Fast);
+        let entity = spawn(&mut app, EnemyKind::Insect, Vec2::new(500.0, 0.0));
+        let base = EnemyArchetypes::default()
+            .get(EnemyKind::Insect)
+            .unwrap()
+            .speed;
+        assert!(app.world().get::<Enemy>(entity).unwrap().speed > base);
+    }
+
+    #[test]
+    fn test_regenerating_elite_heals() {
+        let mut app = create_elite_app(EliteAffix::Regenerating);
+        let entity = spawn(&mut app, EnemyKind::RivalFungus, Vec2::new(500.0, 0.0));
+        app.world_mut().get_mut::<Health>(entity).unwrap().current = 1.0;
+        app.update();
+        assert!(app.world().get::<Health>(entity).unwrap().current > 1.0);
+    }
+
+    #[test]
+    fn test_splitting_elite_leaves_regular_children() {
+        let mut app = create_elite_app(EliteAffix::Splitting);
+        let entity = spawn(&mut app, EnemyKind::Insect, Vec2::new(500.0, 0.0));
+        app.world_mut().send_event(DamageEvent::new(
+            None,
+            entity,
+            10_000.0,
+            DamageType::Physical,
+        ));
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(entity).is_err());
+        let mut query = app.world_mut().query::<(&Enemy, Has<Elite>)>();
+        let children: Vec<_> = query.iter(app.world()).collect();
+        assert_eq!(children.len(), 2);
+        assert!(children.iter().all(|(_, elite)| !elite));
+        let archetype = EnemyArchetypes::default()
+            .get(EnemyKind::Insect)
+            .unwrap()
+            .clone();
+        assert!(children.iter().all(|(e, _)| e.radius < archetype.radius));
+    }
+
+    #[test]
+    fn test_corrupting_aura_corrupts_nearby_segments() {
+        let mut app = create_elite_app(EliteAffix::CorruptingAura);
+        app.world_mut()
+            .resource_mut::<EnemyArchetypes>()
+            .get_mut(EnemyKind::Insect)
+            .unwrap()
+            .speed = 0.0;
+        let near = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(560.0, 0.0), Vec2::X),
+            ))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(800.0, 0.0), Vec2::X),
+            ))
+            .id();
+        spawn(&mut app, EnemyKind::Insect, Vec2::new(500.0, 0.0));
+        for _ in 0..6 {
+            app.update();
+        }
+
+        let world = app.world();
+        assert!(world
+            .get::<StatusEffects>(near)
+            .is_some_and(|e| e.has(StatusKind::Corruption)));
+        assert!(world.get::<StatusEffects>(far).is_none());
+    }
+
+    #[test]
+    fn test_elite_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<Elite>();
+        assert_component::<Regenerating>();
+        assert_component::<CorruptingAura>();
+        assert_component::<SplitsOnDeath>();
+        assert_resource::<EliteAffixes>();
+        assert_resource::<EliteConfig>();
+    }
+}
@@ -0,0 +1,51 @@
+//! Enemy events
+
+use bevy::prelude::*;
+
+use super::components::EnemyKind;
+
+/// Request to spawn an enemy at a world position
+#[derive(Event, Debug, Clone)]
+pub struct SpawnEnemy {
+    pub kind: EnemyKind,
+    pub position: Vec2,
+}
+
+impl SpawnEnemy {
+    #[must_use]
+    pub fn new(kind: EnemyKind, position: Vec2) -> Self {
+        Self { kind, position }
+    }
+}
+
+/// Event fired after an enemy has been spawned
+#[derive(Event, Debug, Clone)]
+pub struct EnemySpawned {
+    pub entity: Entity,
+    pub kind: EnemyKind,
+    pub position: Vec2,
+}
+
+/// Event fired when a hostile colony is destroyed
+#[derive(Event, Debug, Clone)]
+pub struct ColonyDestroyed {
+    pub position: Vec2,
+    /// Nutrients released by the colony
+    pub nutrients: f32,
+    pub major_threat: bool,
+    /// Whether the kill earned an upgrade
+    pub grants_upgrade: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enemy_events_are_events() {
+        fn assert_event<T: Event>() {}
+        assert_event::<SpawnEnemy>();
+        assert_event::<EnemySpawned>();
+        assert_event::<ColonyDestroyed>();
+    }
+}
@@ -0,0 +1,764 @@
+//! Flow-field pathfinding
+//!
+//! A grid of path costs toward the fungal network, shared by every ground
+//! enemy:
+//! - Goal cells are cells occupied by connected tendril segments and the core
+//! - Terrain costs come from the map's `TerrainGrid`
+//! - Recomputed with Dijkstra when the network or terrain changes, spread
+//!   over several frames; the last finished field is used meanwhile
+//! - Updates are incremental: new goals are expanded from, while removed
+//!   goals and changed terrain only invalidate the cells downstream of them
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use bevy::prelude::*;
+
+use super::components::Enemy;
+use super::latch::Latched;
+use crate::game::combat::StatusEffects;
+use crate::game::map::TerrainGrid;
+use crate::game::network::{CoreNode, Faction, Severed, TendrilPosition, TendrilSegment};
+
+/// Tuning for flow-field recomputation
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct FlowFieldConfig {
+    /// Cells settled per frame while a recomputation is in progress
+    pub max_expansions_per_frame: usize,
+}
+
+impl Default for FlowFieldConfig {
+    fn default() -> Self {
+        Self {
+            max_expansions_per_frame: 4096,
+        }
+    }
+}
+
+/// Marker for enemies that walk along the flow field
+#[derive(Component, Debug, Default)]
+pub struct FlowFieldMover;
+
+/// Open-set entry ordered so the cheapest cell pops first
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OpenCell {
+    cost: f32,
+    index: usize,
+}
+
+impl Eq for OpenCell {}
+
+impl Ord for OpenCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for OpenCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A recomputation in progress
+#[derive(Debug, Clone)]
+struct FlowFieldJob {
+    costs: Vec<f32>,
+    parents: Vec<Option<usize>>,
+    open: BinaryHeap<OpenCell>,
+    goals: Vec<usize>,
+    terrain_costs: Vec<f32>,
+    terrain_version: u32,
+    settled: usize,
+}
+
+/// Integrated path cost from every cell to the nearest goal
+#[derive(Resource, Debug, Default)]
+pub struct FlowField {
+    /// Costs of the last finished computation (`INFINITY` = unreachable)
+    costs: Vec<f32>,
+    /// Cell each cost was relaxed from, `None` for goals and unreachable cells
+    parents: Vec<Option<usize>>,
+    /// Sorted goal cell indices the next computation should use
+    goals: Vec<usize>,
+    /// Goals the finished field was built from
+    solved_goals: Vec<usize>,
+    /// Terrain costs the finished field was built from
+    terrain_costs: Vec<f32>,
+    /// Terrain version the finished field was built from
+    terrain_version: u32,
+    /// Goals or terrain changed since the current or last job started
+    dirty: bool,
+    job: Option<FlowFieldJob>,
+    /// Number of finished computations
+    generation: u32,
+    /// Cells settled by the last finished computation
+    settled: usize,
+}
+
+impl FlowField {
+    /// Whether at least one computation has finished
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.generation > 0
+    }
+
+    /// Whether a recomputation is in progress
+    #[must_use]
+    pub fn is_computing(&self) -> bool {
+        self.job.is_some()
+    }
+
+    /// Number of finished computations
+    #[must_use]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Cells settled by the last finished computation
+    #[must_use]
+    pub fn settled(&self) -> usize {
+        self.settled
+    }
+
+    /// Path cost from a cell to the nearest goal
+    #[must_use]
+    pub fn cost(&self, terrain: &TerrainGrid, cell: UVec2) -> f32 {
+        if cell.x >= terrain.width || cell.y >= terrain.height {
+            return f32::INFINITY;
+        }
+        self.costs
+            .get(terrain.index(cell))
+            .copied()
+            .unwrap_or(f32::INFINITY)
+    }
+
+    /// Replace the goal cells, marking the field dirty if they changed
+    pub fn set_goals(&mut self, mut goals: Vec<usize>) {
+        goals.sort_unstable();
+        goals.dedup();
+        if goals != self.goals {
+            self.goals = goals;
+            self.dirty = true;
+        }
+    }
+
+    /// Advance the recomputation by up to `budget` settled cells
+    ///
+    /// Starts a new job when idle and dirty. Returns true when a job finished.
+    pub fn step(&mut self, terrain: &TerrainGrid, budget: usize) -> bool {
+        if self.costs.len() != terrain.len() {
+            self.costs = vec![f32::INFINITY; terrain.len()];
+            self.parents = vec![None; terrain.len()];
+            self.solved_goals.clear();
+            self.terrain_costs = terrain.costs().to_vec();
+            self.terrain_version = terrain.version();
+            self.job = None;
+            self.dirty = true;
+        }
+        let known_version = self
+            .job
+            .as_ref()
+            .map_or(self.terrain_version, |job| job.terrain_version);
+        if terrain.version() != known_version {
+            self.dirty = true;
+        }
+
+        if self.job.is_none() {
+            if !self.dirty {
+                return false;
+            }
+            self.dirty = false;
+            self.job = Some(self.start_job(terrain));
+        }
+
+        let Some(job) = self.job.as_mut() else {
+            return false;
+        };
+        let mut settled = 0;
+        while settled < budget {
+            let Some(OpenCell { cost, index }) = job.open.pop() else {
+                break;
+            };
+            if cost > job.costs[index] {
+                continue;
+            }
+            settled += 1;
+            let cell = terrain.cell(index);
+            for next in terrain.neighbours(cell) {
+                if !can_step(terrain, cell, next) {
+                    continue;
+                }
+                let next_index = terrain.index(next);
+                let candidate = cost + step_cost(terrain, cell, next);
+                if candidate < job.costs[next_index] {
+                    job.costs[next_index] = candidate;
+                    job.parents[next_index] = Some(index);
+                    job.open.push(OpenCell {
+                        cost: candidate,
+                        index: next_index,
+                    });
+                }
+            }
+        }
+        job.settled += settled;
+
+        if !job.open.is_empty() {
+            return false;
+        }
+        let job = self.job.take().expect("job checked above");
+        self.costs = job.costs;
+        self.parents = job.parents;
+        self.solved_goals = job.goals;
+        self.terrain_costs = job.terrain_costs;
+        self.terrain_version = job.terrain_version;
+        self.settled = job.settled;
+        self.generation += 1;
+        true
+    }
+
+    /// Set up a job that repairs the finished field
+    ///
+    /// Added goals are seeded as they are. Removed goals and changed terrain
+    /// invalidate the cells whose path ran through them, which are then
+    /// re-expanded from the valid cells bordering them.
+    fn start_job(&self, terrain: &TerrainGrid) -> FlowFieldJob {
+        let mut costs = self.costs.clone();
+        let mut parents = self.parents.clone();
+        let mut open = BinaryHeap::new();
+
+        let mut roots: Vec<usize> = self
+            .solved_goals
+            .iter()
+            .copied()
+            .filter(|goal| self.goals.binary_search(goal).is_err())
+            .collect();
+        let changed: Vec<usize> = if terrain.version() == self.terrain_version {
+            Vec::new()
+        } else {
+            self.terrain_costs
+                .iter()
+                .zip(terrain.costs())
+                .enumerate()
+                .filter(|(_, (old, new))| old != new)
+                .map(|(index, _)| index)
+                .collect()
+        };
+        for &index in changed.iter() {
+            roots.push(index);
+            // Diagonal moves that cut the changed cell's corner
+            let cell = terrain.cell(index);
+            for next in terrain.neighbours(cell) {
+                let next_index = terrain.index(next);
+                let Some(parent) = parents[next_index] else {
+                    continue;
+                };
+                let parent = terrain.cell(parent);
+                if parent.x != next.x
+                    && parent.y != next.y
+                    && (UVec2::new(next.x, parent.y) == cell
+                        || UVec2::new(parent.x, next.y) == cell)
+                {
+                    roots.push(next_index);
+                }
+            }
+        }
+
+        // Invalidate every cell whose path runs through a root
+        let mut invalid = vec![false; costs.len()];
+        let mut invalidated = Vec::new();
+        while let Some(index) = roots.pop() {
+            if invalid[index] {
+                continue;
+            }
+            invalid[index] = true;
+            invalidated.push(index);
+            for next in terrain.neighbours(terrain.cell(index)) {
+                let next_index = terrain.index(next);
+                if parents[next_index] == Some(index) {
+                    roots.push(next_index);
+                }
+            }
+            costs[index] = f32::INFINITY;
+            parents[index] = None;
+        }
+
+        // Re-expand the invalidated region from its valid boundary
+        for &index in invalidated.iter() {
+            let cell = terrain.cell(index);
+            for from in terrain.neighbours(cell) {
+                let from_index = terrain.index(from);
+                if invalid[from_index]
+                    || !costs[from_index].is_finite()
+                    || !can_step(terrain, from, cell)
+                {
+                    continue;
+                }
+                let candidate = costs[from_index] + step_cost(terrain, from, cell);
+                if candidate < costs[index] {
+                    costs[index] = candidate;
+                    parents[index] = Some(from_index);
+                }
+            }
+            if costs[index].is_finite() {
+                open.push(OpenCell {
+                    cost: costs[index],
+                    index,
+                });
+            }
+        }
+
+        // Cheaper or opened terrain may shorten paths through its neighbours
+        for &index in changed.iter() {
+            let cell = terrain.cell(index);
+            for next in std::iter::once(cell).chain(terrain.neighbours(cell)) {
+                let next_index = terrain.index(next);
+                if costs[next_index].is_finite() {
+                    open.push(OpenCell {
+                        cost: costs[next_index],
+                        index: next_index,
+                    });
+                }
+            }
+        }
+
+        for &index in self.goals.iter() {
+            if index < costs.len() && !terrain.is_blocked(terrain.cell(index)) && costs[index] > 0.0
+            {
+                costs[index] = 0.0;
+                parents[index] = None;
+                open.push(OpenCell { cost: 0.0, index });
+            }
+        }
+
+        FlowFieldJob {
+            costs,
+            parents,
+            open,
+            goals: self.goals.clone(),
+            terrain_costs: terrain.costs().to_vec(),
+            terrain_version: terrain.version(),
+            settled: 0,
+        }
+    }
+
+    /// Direction to move from `position` toward the nearest goal
+    ///
+    /// `None` when the field is not ready, the position is unreachable or
+    /// already on a goal cell.
+    #[must_use]
+    pub fn direction(&self, terrain: &TerrainGrid, position: Vec2) -> Option<Vec2> {
+        let cell = terrain.world_to_cell(position)?;
+        let here = self.cost(terrain, cell);
+        if !here.is_finite() || here <= 0.0 {
+            return None;
+        }
+        let best = terrain
+            .neighbours(cell)
+            .filter(|&next| can_step(terrain, cell, next))
+            .map(|next| (next, self.cost(terrain, next)))
+            .filter(|(_, cost)| cost.is_finite() && *cost < here)
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        (terrain.cell_center(best.0) - position).try_normalize()
+    }
+}
+
+/// Cost of moving between neighbouring cells
+fn step_cost(terrain: &TerrainGrid, from: UVec2, to: UVec2) -> f32 {
+    let distance = if from.x != to.x && from.y != to.y {
+        std::f32::consts::SQRT_2
+    } else {
+        1.0
+    };
+    distance * (terrain.cost(from) + terrain.cost(to)) * 0.5
+}
+
+/// Whether a move between neighbouring cells is allowed
+///
+/// Blocked cells cannot be entered and diagonals cannot cut blocked corners.
+fn can_step(terrain: &TerrainGrid, from: UVec2, to: UVec2) -> bool {
+    if terrain.is_blocked(to) {
+        return false;
+    }
+    if from.x != to.x && from.y != to.y {
+        let side_a = UVec2::new(to.x, from.y);
+        let side_b = UVec2::new(from.x, to.y);
+        if terrain.is_blocked(side_a) || terrain.is_blocked(side_b) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Give ground enemies that latch onto the network a flow-field mover
+pub fn attach_flow_field_movers(
+    mut commands: Commands,
+    enemies: Query<(Entity, &Enemy), Added<Enemy>>,
+) {
+    for (entity, enemy) in enemies.iter() {
+        if enemy.behaviour == super::components::BehaviourKind::Latch {
+            commands.entity(entity).insert(FlowFieldMover);
+        }
+    }
+}
+
+/// Collect goal cells from the player's connected segments and core
+pub fn track_flow_field_goals(
+    terrain: Res<TerrainGrid>,
+    mut field: ResMut<FlowField>,
+    segments: Query<(&TendrilPosition, &TendrilSegment, Option<&Faction>), Without<Severed>>,
+    cores: Query<&Transform, With<CoreNode>>,
+) {
+    let goals = segments
+        .iter()
+        .filter(|(_, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .map(|(pos, _, _)| pos.position)
+        .chain(cores.iter().map(|t| t.translation.truncate()))
+        .filter_map(|position| terrain.world_to_cell(position))
+        .map(|cell| terrain.index(cell))
+        .collect();
+    field.set_goals(goals);
+}
+
+/// Advance the amortised recomputation
+pub fn update_flow_field(
+    config: Res<FlowFieldConfig>,
+    terrain: Res<TerrainGrid>,
+    mut field: ResMut<FlowField>,
+) {
+    field.step(&terrain, config.max_expansions_per_frame);
+}
+
+/// Move flow-field enemies that are not latched along the field
+pub fn move_along_flow_field(
+    time: Res<Time>,
+    terrain: Res<TerrainGrid>,
+    field: Res<FlowField>,
+    mut movers: Query<
+        (&mut Transform, &Enemy, Option<&StatusEffects>),
+        (With<FlowFieldMover>, Without<Latched>),
+    >,
+) {
+    let delta = time.delta_secs();
+    for (mut transform, enemy, effects) in movers.iter_mut() {
+        let position = transform.translation.truncate();
+        let Some(direction) = field.direction(&terrain, position) else {
+            continue;
+        };
+        let speed = enemy.speed * effects.map_or(1.0, StatusEffects::speed_multiplier);
+        transform.translation += (direction * speed * delta).extend(0.0);
+    }
+}
+
+/// Forget the field when a run ends
+pub fn reset_flow_field(mut field: ResMut<FlowField>) {
+    *field = FlowField::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::enemies::test_utils::{create_test_app, enter_state};
+    use crate::game::enemies::{EnemyKind, SpawnDirectorConfig, SpawnEnemy};
+    use crate::game::map::IMPASSABLE;
+    use crate::GameState;
+
+    /// 10x10 cells of 10 units centered on the origin
+    fn small_terrain() -> TerrainGrid {
+        TerrainGrid::new(Vec2::new(-50.0, -50.0), 10.0, 10, 10)
+    }
+
+    /// Run a computation to completion with an unlimited per-frame budget
+    fn solve(field: &mut FlowField, terrain: &TerrainGrid) {
+        let finished = (0..4).any(|_| field.step(terrain, usize::MAX));
+        assert!(finished, "flow field did not finish");
+    }
+
+    fn goal_at(terrain: &TerrainGrid, cell: UVec2) -> Vec<usize> {
+        vec![terrain.index(cell)]
+    }
+
+    #[test]
+    fn test_costs_grow_with_distance_from_goal() {
+        let terrain = small_terrain();
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::new(0, 0)));
+        solve(&mut field, &terrain);
+
+        assert_eq!(field.cost(&terrain, UVec2::new(0, 0)), 0.0);
+        assert_eq!(field.cost(&terrain, UVec2::new(3, 0)), 3.0);
+        let diagonal = field.cost(&terrain, UVec2::new(2, 2));
+        assert!((diagonal - 2.0 * std::f32::consts::SQRT_2).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_direction_points_toward_goal() {
+        let terrain = small_terrain();
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::new(9, 5)));
+        solve(&mut field, &terrain);
+
+        let direction = field
+            .direction(&terrain, terrain.cell_center(UVec2::new(2, 5)))
+            .unwrap();
+        assert!(direction.x > 0.9);
+        // On the goal cell there is nowhere further to go
+        assert!(field
+            .direction(&terrain, terrain.cell_center(UVec2::new(9, 5)))
+            .is_none());
+    }
+
+    #[test]
+    fn test_path_routes_around_wall() {
+        let mut terrain = small_terrain();
+        // Vertical wall at x = 5 with a gap at the top row
+        for y in 0..9 {
+            terrain.set_cost(UVec2::new(5, y), IMPASSABLE);
+        }
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::new(9, 0)));
+        solve(&mut field, &terrain);
+
+        // Straight-line distance is 7, the detour through the gap is longer
+        let cost = field.cost(&terrain, UVec2::new(2, 0));
+        assert!(cost.is_finite());
+        assert!(cost > 12.0);
+        // Next to the wall the field points up toward the gap
+        let direction = field
+            .direction(&terrain, terrain.cell_center(UVec2::new(4, 4)))
+            .unwrap();
+        assert!(direction.y > 0.0);
+    }
+
+    #[test]
+    fn test_enclosed_cells_are_unreachable() {
+        let mut terrain = small_terrain();
+        for cell in terrain.neighbours(UVec2::new(1, 1)).collect::<Vec<_>>() {
+            terrain.set_cost(cell, IMPASSABLE);
+        }
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::new(9, 9)));
+        solve(&mut field, &terrain);
+
+        assert!(!field.cost(&terrain, UVec2::new(1, 1)).is_finite());
+        assert!(field
+            .direction(&terrain, terrain.cell_center(UVec2::new(1, 1)))
+            .is_none());
+    }
+
+    #[test]
+    fn test_slow_terrain_is_avoided_when_cheaper_around() {
+        let mut terrain = small_terrain();
+        for y in 0..9 {
+            terrain.set_cost(UVec2::new(5, y), 20.0);
+        }
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::new(9, 0)));
+        solve(&mut field, &terrain);
+
+        let through_swamp = 2.0 + 20.0 + 2.0;
+        assert!(field.cost(&terrain, UVec2::new(2, 0)) < through_swamp + 5.0);
+    }
+
+    #[test]
+    fn test_recompute_is_amortised_over_frames() {
+        let terrain = small_terrain();
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::ZERO));
+
+        let frames = (1..=1000)
+            .find(|_| field.step(&terrain, 10))
+            .expect("flow field did not finish");
+        assert!(frames >= 10);
+        assert_eq!(field.generation(), 1);
+    }
+
+    #[test]
+    fn test_old_field_served_while_recomputing() {
+        let terrain = small_terrain();
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::ZERO));
+        solve(&mut field, &terrain);
+
+        field.set_goals(goal_at(&terrain, UVec2::new(9, 9)));
+        field.step(&terrain, 5);
+        assert!(field.is_computing());
+        assert_eq!(field.cost(&terrain, UVec2::ZERO), 0.0);
+
+        solve(&mut field, &terrain);
+        assert_eq!(field.cost(&terrain, UVec2::new(9, 9)), 0.0);
+    }
+
+    #[test]
+    fn test_terrain_change_triggers_recompute() {
+        let mut terrain = small_terrain();
+        let mut field = FlowField::default();
+        field.set_goals(goal_at(&terrain, UVec2::ZERO));
+        solve(&mut field, &terrain);
+        assert!(!field.step(&terrain, usize::MAX));
+
+        terrain.set_cost(UVec2::new(1, 0), 5.0);
+        assert!(field.step(&terrain, usize::MAX));
+        assert_eq!(field.generation(), 2);
+    }
+
+    /// 40x40 cells of 10 units
+    fn large_terrain() -> TerrainGrid {
+        TerrainGrid::new(Vec2::splat(-200.0), 10.0, 40, 40)
+    }
+
+    /// Solve `goals` from scratch in a fresh field
+    fn rebuild(terrain: &TerrainGrid, goals: Vec<usize>) -> FlowField {
+        let mut field = FlowField::default();
+        field.set_goals(goals);
+        solve(&mut field, terrain);
+        field
+    }
+
+    fn assert_same_costs(field: &FlowField, expected: &FlowField, terrain: &TerrainGrid) {
+        for index in 0..terrain.len() {
+            let cell = terrain.cell(index);
+            let (got, want) = (field.cost(terrain, cell), expected.cost(terrain, cell));
+            assert!(
+                got == want || (got - want).abs() < 0.001,
+                "cell {cell}: {got} != {want}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_adding_a_goal_settles_fewer_cells_than_a_rebuild() {
+        let terrain = large_terrain();
+        let first = terrain.index(UVec2::new(20, 20));
+        let second = terrain.index(UVec2::new(2, 2));
+        let mut field = rebuild(&terrain, vec![first]);
+
+        field.set_goals(vec![first, second]);
+        solve(&mut field, &terrain);
+
+        let full = rebuild(&terrain, vec![first, second]);
+        assert_same_costs(&field, &full, &terrain);
+        assert!(field.settled() * 4 < full.settled());
+    }
+
+    #[test]
+    fn test_removing_a_goal_only_repairs_its_region() {
+        let terrain = large_terrain();
+        let kept = terrain.index(UVec2::new(20, 20));
+        let removed = terrain.index(UVec2::new(2, 2));
+        let mut field = rebuild(&terrain, vec![kept, removed]);
+
+        field.set_goals(vec![kept]);
+        solve(&mut field, &terrain);
+
+        let full = rebuild(&terrain, vec![kept]);
+        assert_same_costs(&field, &full, &terrain);
+        assert!(field.settled() < full.settled());
+    }
+
+    #[test]
+    fn test_terrain_changes_are_repaired_incrementally() {
+        let mut terrain = large_terrain();
+        let goals = vec![terrain.index(UVec2::new(5, 5))];
+        let mut field = rebuild(&terrain, goals.clone());
+
+        // Wall with a gap, then a cheaper swamp cell and a reopened wall cell
+        for y in 0..30 {
+            terrain.set_cost(UVec2::new(10, y), IMPASSABLE);
+        }
+        solve(&mut field, &terrain);
+        assert_same_costs(&field, &rebuild(&terrain, goals.clone()), &terrain);
+
+        terrain.set_cost(UVec2::new(10, 12), 1.0);
+        terrain.set_cost(UVec2::new(30, 30), 8.0);
+        solve(&mut field, &terrain);
+        let full = rebuild(&terrain, goals);
+        assert_same_costs(&field, &full, &terrain);
+        assert!(field.settled() < full.settled());
+    }
+
+    #[test]
+    fn test_flow_field_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<FlowFieldMover>();
+        assert_resource::<FlowField>();
+        assert_resource::<FlowFieldConfig>();
+    }
+
+    fn create_flow_app() -> App {
+        let mut app = create_test_app();
+        app.insert_resource(TerrainGrid::new(Vec2::splat(-320.0), 32.0, 20, 20));
+        app.world_mut()
+            .resource_mut::<SpawnDirectorConfig>()
+            .max_alive = 0;
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    #[test]
+    fn test_goals_follow_connected_network() {
+        let mut app = create_flow_app();
+        app.world_mut().spawn((CoreNode, Transform::default()));
+        let segment = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(100.0, 0.0), Vec2::X),
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let terrain = app.world().resource::<TerrainGrid>().clone();
+        let cell = terrain.world_to_cell(Vec2::new(100.0, 0.0)).unwrap();
+        assert_eq!(
+            app.world().resource::<FlowField>().cost(&terrain, cell),
+            0.0
+        );
+
+        // Severed segments stop being goals
+        app.world_mut().entity_mut(segment).insert(Severed {
+            time_since_severance: 0.0,
+            decay_rate: 1.0,
+        });
+        app.update();
+        app.update();
+        assert!(app.world().resource::<FlowField>().cost(&terrain, cell) > 0.0);
+    }
+
+    #[test]
+    fn test_bacteria_walk_the_field() {
+        let mut app = create_flow_app();
+        app.world_mut().spawn((CoreNode, Transform::default()));
+        app.world_mut()
+            .send_event(SpawnEnemy::new(EnemyKind::Bacteria, Vec2::new(250.0, 0.0)));
+        app.update();
+        app.update();
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Transform, With<FlowFieldMover>>();
+        let start = query.single(app.world()).translation.x;
+        for _ in 0..4 {
+            app.update();
+        }
+        let end = query.single(app.world()).translation.x;
+        assert!(end < start);
+    }
+
+    #[test]
+    fn test_field_reset_on_run_end() {
+        let mut app = create_flow_app();
+        app.world_mut().spawn((CoreNode, Transform::default()));
+        app.update();
+        assert!(app.world().resource::<FlowField>().is_ready());
+
+        enter_state(&mut app, GameState::GameOver);
+        assert!(!app.world().resource::<FlowField>().is_ready());
+    }
+}
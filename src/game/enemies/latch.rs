@@ -0,0 +1,370 @@
+//! Bacteria latching
+//!
+//! Latch enemies attach to the first player segment they touch:
+//! - While latched they ride the segment, drain its health and seed
+//!   corruption through their `CorruptionOnHit`
+//! - They detach when the segment is gone
+//! - Toxic segments poison their own latchers through contact statuses
+//! - A `CleanseSegment` request spends nutrients to purge corruption and
+//!   every latcher on the segment
+//! - The number of latched bacteria is tracked in `NetworkStats`
+
+use bevy::prelude::*;
+
+use super::components::{BehaviourKind, Enemy};
+use super::resources::EnemyArchetypes;
+use crate::game::collision::CollisionEnter;
+use crate::game::combat::{ContactDamage, DamageEvent, DamageType, StatusEffects, StatusKind};
+use crate::game::network::{Faction, NetworkStats, TendrilPosition, TendrilSegment};
+use crate::game::progression::{
+    try_spend_nutrients, NutrientCosts, NutrientPurpose, NutrientSpendFailed, Nutrients,
+    NutrientsSpent,
+};
+
+/// Tuning for latched bacteria
+#[derive(Resource, Debug, Clone)]
+pub struct LatchConfig {
+    /// Health drained from the host segment per second
+    pub drain_per_second: f32,
+}
+
+impl Default for LatchConfig {
+    fn default() -> Self {
+        Self {
+            drain_per_second: 4.0,
+        }
+    }
+}
+
+/// A bacterium attached to a tendril segment
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Latched {
+    /// The segment being drained
+    pub segment: Entity,
+}
+
+/// Request to cleanse a segment of corruption and latched bacteria
+#[derive(Event, Debug, Clone)]
+pub struct CleanseSegment {
+    pub segment: Entity,
+}
+
+/// Latch free bacteria onto player segments they touch
+pub fn latch_onto_segments(
+    mut commands: Commands,
+    mut enter: EventReader<CollisionEnter>,
+    bacteria: Query<&Enemy, Without<Latched>>,
+    segments: Query<Option<&Faction>, With<TendrilSegment>>,
+) {
+    let mut latched = Vec::new();
+    for contact in enter.read() {
+        for (bacterium, segment) in [(contact.a, contact.b), (contact.b, contact.a)] {
+            let Ok(enemy) = bacteria.get(bacterium) else {
+                continue;
+            };
+            if enemy.behaviour != BehaviourKind::Latch || latched.contains(&bacterium) {
+                continue;
+            }
+            let Ok(faction) = segments.get(segment) else {
+                continue;
+            };
+            if Faction::is_player(faction) {
+                latched.push(bacterium);
+                // The latch drain replaces contact damage while attached
+                commands
+                    .entity(bacterium)
+                    .insert(Latched { segment })
+                    .remove::<ContactDamage>();
+            }
+        }
+    }
+}
+
+/// Keep latched bacteria on their segment and drain it
+///
+/// Draining goes through the damage pipeline so the bacterium's
+/// `CorruptionOnHit` seeds corruption on the segment.
+pub fn drain_latched_segments(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<LatchConfig>,
+    archetypes: Res<EnemyArchetypes>,
+    mut bacteria: Query<(Entity, &Latched, &Enemy, &mut Transform)>,
+    segments: Query<(&TendrilPosition, &TendrilSegment)>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    let delta = time.delta_secs();
+    for (entity, latched, enemy, mut transform) in bacteria.iter_mut() {
+        let Ok((pos, segment)) = segments.get(latched.segment) else {
+            // Host is gone: detach and go back to hunting
+            let mut entity = commands.entity(entity);
+            entity.remove::<Latched>();
+            if let Some(archetype) = archetypes.get(enemy.kind) {
+                entity.insert(ContactDamage::against_network(archetype.contact_damage));
+            }
+            continue;
+        };
+        transform.translation = pos.position.extend(transform.translation.z);
+        if segment.health > 0.0 && delta > 0.0 {
+            damage.send(DamageEvent::new(
+                Some(entity),
+                latched.segment,
+                config.drain_per_second * delta,
+                DamageType::Corrupting,
+            ));
+        }
+    }
+}
+
+/// Spend nutrients to purge corruption and latched bacteria from segments
+pub fn cleanse_segments(
+    mut commands: Commands,
+    mut requests: EventReader<CleanseSegment>,
+    costs: Res<NutrientCosts>,
+    mut nutrients: ResMut<Nutrients>,
+    mut spent: EventWriter<NutrientsSpent>,
+    mut failed: EventWriter<NutrientSpendFailed>,
+    mut segments: Query<(&mut TendrilSegment, Option<&mut StatusEffects>)>,
+    bacteria: Query<(Entity, &Latched)>,
+) {
+    for request in requests.read() {
+        let Ok((mut segment, effects)) = segments.get_mut(request.segment) else {
+            continue;
+        };
+        let cost = costs.cleanse_cost(segment.corruption_level);
+        if !try_spend_nutrients(
+            cost,
+            NutrientPurpose::Cleanse,
+            &mut nutrients,
+            &mut spent,
+            &mut failed,
+        ) {
+            continue;
+        }
+        segment.corrupted = false;
+        segment.corruption_level = 0.0;
+        if let Some(mut effects) = effects {
+            effects.remove(StatusKind::Corruption);
+        }
+        for (entity, latched) in bacteria.iter() {
+            if latched.segment == request.segment {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+/// Count latched bacteria into `NetworkStats`
+pub fn count_latched_bacteria(mut stats: ResMut<NetworkStats>, bacteria: Query<(), With<Latched>>) {
+    let count = bacteria.iter().count() as u32;
+    if stats.latched_bacteria != count {
+        stats.latched_bacteria = count;
+    }
+}
+
+/// Draw a tether from each latched bacterium to its host
+pub fn render_latches(
+    mut gizmos: Gizmos,
+    bacteria: Query<(&Transform, &Enemy, &Latched)>,
+    segments: Query<&TendrilPosition>,
+) {
+    let color = Color::srgb(0.9, 0.85, 0.2);
+    for (transform, enemy, latched) in bacteria.iter() {
+        let position = transform.translation.truncate();
+        gizmos.circle_2d(position, enemy.radius + 3.0, color);
+        if let Ok(host) = segments.get(latched.segment) {
+            gizmos.line_2d(position, host.position, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::combat::StatusEffect;
+    use crate::game::enemies::test_utils::{create_test_app, enter_state};
+    use crate::game::enemies::{EnemyKind, SpawnDirectorConfig, SpawnEnemy};
+    use crate::game::network::TendrilType;
+    use crate::GameState;
+
+    fn create_latch_app() -> App {
+        let mut app = create_test_app();
+        app.world_mut()
+            .resource_mut::<SpawnDirectorConfig>()
+            .max_alive = 0;
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn spawn_segment(app: &mut App, tendril_type: TendrilType, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                TendrilSegment {
+                    tendril_type,
+                    ..default()
+                },
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id()
+    }
+
+    fn spawn_bacterium(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .send_event(SpawnEnemy::new(EnemyKind::Bacteria, position));
+        app.update();
+        let mut query = app.world_mut().query::<(Entity, &Enemy)>();
+        query
+            .iter(app.world())
+            .find(|(_, e)| e.kind == EnemyKind::Bacteria)
+            .map(|(e, _)| e)
+            .unwrap()
+    }
+
+    fn latched_on(app: &mut App, bacterium: Entity) -> Option<Entity> {
+        app.world().get::<Latched>(bacterium).map(|l| l.segment)
+    }
+
+    #[test]
+    fn test_bacteria_latch_on_touch() {
+        let mut app = create_latch_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::new(200.0, 0.0));
+        let bacterium = spawn_bacterium(&mut app, Vec2::new(200.0, 0.0));
+        app.update();
+
+        assert_eq!(latched_on(&mut app, bacterium), Some(segment));
+        assert!(app.world().get::<ContactDamage>(bacterium).is_none());
+        assert_eq!(app.world().resource::<NetworkStats>().latched_bacteria, 1);
+    }
+
+    #[test]
+    fn test_latched_bacteria_drain_and_corrupt() {
+        let mut app = create_latch_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::new(200.0, 0.0));
+        spawn_bacterium(&mut app, Vec2::new(200.0, 0.0));
+        for _ in 0..8 {
+            app.update();
+        }
+
+        let host = app.world().get::<TendrilSegment>(segment).unwrap();
+        assert!(host.health < TendrilSegment::default().health);
+        assert!(host.corrupted);
+        assert!(host.corruption_level > 0.0);
+    }
+
+    #[test]
+    fn test_latched_bacteria_follow_segment() {
+        let mut app = create_latch_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::new(200.0, 0.0));
+        let bacterium = spawn_bacterium(&mut app, Vec2::new(200.0, 0.0));
+        app.update();
+
+        app.world_mut()
+            .get_mut::<TendrilPosition>(segment)
+            .unwrap()
+            .position = Vec2::new(210.0, 5.0);
+        app.update();
+
+        let position = app.world().get::<Transform>(bacterium).unwrap().translation;
+        assert_eq!(position.truncate(), Vec2::new(210.0, 5.0));
+    }
+
+    #[test]
+    fn test_bacteria_detach_when_segment_gone() {
+        let mut app = create_latch_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::new(200.0, 0.0));
+        let bacterium = spawn_bacterium(&mut app, Vec2::new(200.0, 0.0));
+        app.update();
+        assert!(latched_on(&mut app, bacterium).is_some());
+
+        app.world_mut().entity_mut(segment).despawn();
+        app.update();
+        app.update();
+
+        assert!(latched_on(&mut app, bacterium).is_none());
+        assert!(app.world().get::<ContactDamage>(bacterium).is_some());
+        assert_eq!(app.world().resource::<NetworkStats>().latched_bacteria, 0);
+    }
+
+    #[test]
+    fn test_toxic_segments_kill_their_latchers() {
+        let mut app = create_latch_app();
+        spawn_segment(&mut app, TendrilType::Toxic, Vec2::new(200.0, 0.0));
+        let bacterium = spawn_bacterium(&mut app, Vec2::new(200.0, 0.0));
+        for _ in 0..12 {
+            app.update();
+        }
+
+        assert!(app.world().get_entity(bacterium).is_err());
+    }
+
+    #[test]
+    fn test_cleanse_purges_corruption_and_latchers() {
+        let mut app = create_latch_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::new(200.0, 0.0));
+        let bacterium = spawn_bacterium(&mut app, Vec2::new(200.0, 0.0));
+        for _ in 0..4 {
+            app.update();
+        }
+        app.world_mut().resource_mut::<Nutrients>().current = 100.0;
+        let cost = {
+            let level = app.world().get::<TendrilSegment>(segment).unwrap();
+            app.world()
+                .resource::<NutrientCosts>()
+                .cleanse_cost(level.corruption_level)
+        };
+
+        app.world_mut().send_event(CleanseSegment { segment });
+        app.update();
+
+        let host = app.world().get::<TendrilSegment>(segment).unwrap();
+        assert!(!host.corrupted);
+        assert_eq!(host.corruption_level, 0.0);
+        assert!(!app
+            .world()
+            .get::<StatusEffects>(segment)
+            .is_some_and(|e| e.has(StatusKind::Corruption)));
+        assert!(app.world().get_entity(bacterium).is_err());
+        let remaining = app.world().resource::<Nutrients>().current;
+        assert!((remaining - (100.0 - cost)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_cleanse_needs_nutrients() {
+        let mut app = create_latch_app();
+        let segment = spawn_segment(&mut app, TendrilType::Basic, Vec2::new(200.0, 0.0));
+        app.world_mut()
+            .entity_mut(segment)
+            .insert(StatusEffects(vec![StatusEffect::new(
+                StatusKind::Corruption,
+                0.1,
+                10.0,
+                0.5,
+            )]));
+        app.world_mut()
+            .get_mut::<TendrilSegment>(segment)
+            .unwrap()
+            .corrupted = true;
+        app.world_mut().resource_mut::<Nutrients>().current = 0.0;
+
+        app.world_mut().send_event(CleanseSegment { segment });
+        app.update();
+
+        assert!(
+            app.world()
+                .get::<TendrilSegment>(segment)
+                .unwrap()
+                .corrupted
+        );
+    }
+
+    #[test]
+    fn test_latch_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        fn assert_event<T: Event>() {}
+        assert_component::<Latched>();
+        assert_resource::<LatchConfig>();
+        assert_event::<CleanseSegment>();
+    }
+}
@@ -1,18 +1,294 @@
 //! Enemy system
 //!
 //! Handles enemy behavior:
-//! - Enemy spawning
-//! - AI and pathfinding
-//! - Enemy types (insects, fungi, bacteria)
+//! - Data-driven enemy archetypes (insects, rival fungi, bacteria)
+//! - Enemy spawning and cleanup
+//! - Spawn director scaling waves with territory and run time
+//! - Elite affixes layered on top of archetypes as difficulty rises
+//! - Insect swarm flocking
+//! - Rival fungus networks competing with the player's
+//! - Static hostile colonies placed by the map
+//! - Bacteria latching onto segments and seeding corruption
+//! - Flow-field pathfinding toward the network for ground enemies
 //! - Corruption mechanics
 
 use bevy::prelude::*;
 
+use crate::game::combat::CombatSet;
+use crate::game::map::MapGenSet;
+use crate::GameState;
+
+pub mod colony;
+pub mod components;
+pub mod director;
+pub mod elite;
+pub mod events;
+pub mod flow_field;
+pub mod latch;
+pub mod resources;
+pub mod rival;
+pub mod swarm;
+mod systems;
+
+pub use colony::{spawn_colony, Colony, ColonyConfig};
+pub use components::*;
+pub use director::{Curve, SpawnDirector, SpawnDirectorConfig};
+pub use elite::{
+    AffixStats, AuraStats, CorruptingAura, Elite, EliteAffix, EliteAffixes, EliteConfig,
+    Regenerating, SplitStats, SplitsOnDeath,
+};
+pub use events::*;
+pub use flow_field::{FlowField, FlowFieldConfig, FlowFieldMover};
+pub use latch::{CleanseSegment, LatchConfig, Latched};
+pub use resources::*;
+pub use rival::{RivalAttractor, RivalConfig, RivalCore, RivalGoal, RivalSegment};
+pub use swarm::{SwarmAgent, SwarmConfig, SwarmRole};
+pub use systems::{enemy_color, spawn_enemy};
+
 /// Plugin for the enemy system
 pub struct EnemiesPlugin;
 
 impl Plugin for EnemiesPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: Register enemy systems
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<EnemyArchetypes>()
+            .init_resource::<SpawnDirectorConfig>()
+            .init_resource::<SpawnDirector>()
+            .init_resource::<SwarmConfig>()
+            .init_resource::<FlowFieldConfig>()
+            .init_resource::<FlowField>()
+            .init_resource::<RivalConfig>()
+            .init_resource::<ColonyConfig>()
+            .init_resource::<LatchConfig>()
+            .init_resource::<EliteConfig>()
+            .init_resource::<EliteAffixes>()
+            // Events
+            .add_event::<SpawnEnemy>()
+            .add_event::<EnemySpawned>()
+            .add_event::<ColonyDestroyed>()
+            .add_event::<CleanseSegment>()
+            // Fresh director state for every new run
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                (director::reset_spawn_director, colony::spawn_map_colonies).after(MapGenSet),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::GameOver,
+                    entered: GameState::Playing,
+                },
+                (director::reset_spawn_director, colony::spawn_map_colonies).after(MapGenSet),
+            )
+            // Cleanup when the run ends (not when pausing or upgrading)
+            .add_systems(
+                OnEnter(GameState::Menu),
+                (
+                    systems::despawn_all_enemies,
+                    rival::despawn_rival_networks,
+                    colony::despawn_colonies,
+                    flow_field::reset_flow_field,
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (
+                    systems::despawn_all_enemies,
+                    rival::despawn_rival_networks,
+                    colony::despawn_colonies,
+                    flow_field::reset_flow_field,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    (
+                        director::run_spawn_director,
+                        systems::spawn_requested_enemies,
+                        elite::promote_elites,
+                        flow_field::attach_flow_field_movers,
+                        flow_field::track_flow_field_goals,
+                        flow_field::update_flow_field,
+                        latch::latch_onto_segments,
+                        latch::drain_latched_segments,
+                        swarm::assign_swarm_roles,
+                        swarm::steer_swarm,
+                        flow_field::move_along_flow_field,
+                    )
+                        .chain(),
+                    (
+                        rival::attach_rival_cores,
+                        rival::grow_rival_networks,
+                        colony::spread_colony_corruption,
+                        elite::regenerate_elites,
+                        elite::pulse_corrupting_auras,
+                        elite::split_elites_on_death,
+                        systems::handle_enemy_deaths,
+                        rival::handle_rival_segment_deaths,
+                        colony::handle_colony_deaths,
+                        colony::offer_colony_upgrades,
+                        rival::despawn_orphaned_rival_segments,
+                        latch::count_latched_bacteria,
+                    )
+                        .chain(),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Cleansing undoes corruption, so it runs after the combat pipeline
+            .add_systems(
+                Update,
+                latch::cleanse_segments
+                    .after(CombatSet)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Rendering (Playing and Paused so frozen enemies stay visible)
+            .add_systems(
+                Update,
+                (
+                    systems::render_enemies,
+                    elite::render_elite_outlines,
+                    colony::render_colonies,
+                    latch::render_latches,
+                )
+                    .run_if(
+                        resource_exists::<bevy::gizmos::config::GizmoConfigStore>
+                            .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                    ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{create_test_app, enter_state};
+    use super::*;
+    use crate::game::combat::{CorruptionOnHit, DamageEvent, DamageType, Hostile};
+    use crate::game::network::Health;
+    use crate::game::pickups::{Corpse, NutrientOrb};
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn request(app: &mut App, kind: EnemyKind, position: Vec2) {
+        app.world_mut().send_event(SpawnEnemy::new(kind, position));
+        app.update();
+    }
+
+    fn enemies(app: &mut App) -> Vec<(Entity, Enemy)> {
+        let mut query = app.world_mut().query::<(Entity, &Enemy)>();
+        query
+            .iter(app.world())
+            .map(|(e, enemy)| (e, enemy.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_enemies_plugin_builds() {
+        let mut app = create_test_app();
+        app.update();
+        assert!(app.world().get_resource::<EnemyArchetypes>().is_some());
+    }
+
+    #[test]
+    fn test_spawn_request_creates_enemy_from_archetype() {
+        let mut app = create_test_app();
+        enter_state(&mut app, GameState::Playing);
+        request(&mut app, EnemyKind::Insect, Vec2::new(100.0, 50.0));
+
+        let spawned = enemies(&mut app);
+        assert_eq!(spawned.len(), 1);
+        let (entity, enemy) = &spawned[0];
+        let archetype = EnemyArchetypes::default()
+            .get(EnemyKind::Insect)
+            .unwrap()
+            .clone();
+        assert_eq!(enemy.kind, EnemyKind::Insect);
+        assert_eq!(enemy.speed, archetype.speed);
+
+        let world = app.world();
+        assert_eq!(world.get::<Health>(*entity).unwrap().max, archetype.health);
+        assert!(world.get::<Hostile>(*entity).is_some());
+        assert_eq!(
+            world.get::<Transform>(*entity).unwrap().translation,
+            Vec3::new(100.0, 50.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_corrupting_archetypes_get_corruption_on_hit() {
+        let mut app = create_test_app();
+        enter_state(&mut app, GameState::Playing);
+        request(&mut app, EnemyKind::Bacteria, Vec2::ZERO);
+        request(&mut app, EnemyKind::Insect, Vec2::ZERO);
+
+        for (entity, enemy) in enemies(&mut app) {
+            let corrupts = app.world().get::<CorruptionOnHit>(entity).is_some();
+            assert_eq!(corrupts, enemy.kind == EnemyKind::Bacteria);
+        }
+    }
+
+    #[test]
+    fn test_spawn_api_works_from_commands() {
+        let mut app = create_test_app();
+        let archetype = EnemyArchetypes::default()
+            .get(EnemyKind::RivalFungus)
+            .unwrap()
+            .clone();
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_enemy(&mut commands, EnemyKind::RivalFungus, &archetype, Vec2::ONE);
+            })
+            .unwrap();
+
+        assert_eq!(enemies(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn test_killed_enemy_splits_its_drop_between_orbs_and_corpse() {
+        let mut app = create_test_app();
+        enter_state(&mut app, GameState::Playing);
+        request(&mut app, EnemyKind::Insect, Vec2::new(300.0, 0.0));
+        let (entity, enemy) = enemies(&mut app)[0].clone();
+
+        app.world_mut()
+            .send_event(DamageEvent::new(None, entity, 1000.0, DamageType::Physical));
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(entity).is_err());
+        let mut query = app.world_mut().query::<&NutrientOrb>();
+        let dropped: f32 = query.iter(app.world()).map(|orb| orb.value).sum();
+        let mut query = app.world_mut().query::<&Corpse>();
+        let left: f32 = query.iter(app.world()).map(|corpse| corpse.reservoir).sum();
+        assert!(dropped > 0.0 && left > 0.0);
+        assert!((dropped + left - enemy.nutrient_drop).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_enemies_survive_pause_but_not_run_end() {
+        let mut app = create_test_app();
+        enter_state(&mut app, GameState::Playing);
+        request(&mut app, EnemyKind::Insect, Vec2::ZERO);
+        request(&mut app, EnemyKind::Bacteria, Vec2::ZERO);
+
+        enter_state(&mut app, GameState::Paused);
+        assert_eq!(enemies(&mut app).len(), 2);
+
+        enter_state(&mut app, GameState::Playing);
+        enter_state(&mut app, GameState::GameOver);
+        assert!(enemies(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_spawn_requests_ignored_outside_playing() {
+        let mut app = create_test_app();
+        app.update();
+        request(&mut app, EnemyKind::Insect, Vec2::ZERO);
+        assert!(enemies(&mut app).is_empty());
     }
 }
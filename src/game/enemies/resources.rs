@@ -0,0 +1,145 @@
+//! Enemy resources
+//!
+//! Data table of enemy archetypes. Difficulty scaling and elites modify
+//! spawned instances, not this table.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::components::{BehaviourKind, EnemyKind};
+
+/// Stats for one enemy archetype
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnemyArchetype {
+    /// Maximum health
+    pub health: f32,
+    /// Movement speed in world units per second
+    pub speed: f32,
+    /// Damage per second to touching tendrils and the core
+    pub contact_damage: f32,
+    /// Collision radius in world units
+    pub radius: f32,
+    /// Nutrients awarded when killed
+    pub nutrient_drop: f32,
+    /// AI behaviour
+    pub behaviour: BehaviourKind,
+    /// Whether its damage corrupts tendril segments
+    pub corrupts: bool,
+}
+
+/// Archetype stats for every enemy kind
+#[derive(Resource, Debug)]
+pub struct EnemyArchetypes {
+    archetypes: HashMap<EnemyKind, EnemyArchetype>,
+}
+
+impl Default for EnemyArchetypes {
+    fn default() -> Self {
+        let mut archetypes = HashMap::new();
+        archetypes.insert(
+            EnemyKind::Insect,
+            EnemyArchetype {
+                health: 20.0,
+                speed: 90.0,
+                contact_damage: 6.0,
+                radius: 6.0,
+                nutrient_drop: 3.0,
+                behaviour: BehaviourKind::Swarm,
+                corrupts: false,
+            },
+        );
+        archetypes.insert(
+            EnemyKind::RivalFungus,
+            EnemyArchetype {
+                health: 80.0,
+                speed: 25.0,
+                contact_damage: 4.0,
+                radius: 12.0,
+                nutrient_drop: 12.0,
+                behaviour: BehaviourKind::Grow,
+                corrupts: true,
+            },
+        );
+        archetypes.insert(
+            EnemyKind::Bacteria,
+            EnemyArchetype {
+                health: 8.0,
+                speed: 50.0,
+                contact_damage: 2.0,
+                radius: 4.0,
+                nutrient_drop: 1.0,
+                behaviour: BehaviourKind::Latch,
+                corrupts: true,
+            },
+        );
+        Self { archetypes }
+    }
+}
+
+impl EnemyArchetypes {
+    /// Stats for an enemy kind
+    #[must_use]
+    pub fn get(&self, kind: EnemyKind) -> Option<&EnemyArchetype> {
+        self.archetypes.get(&kind)
+    }
+
+    /// Mutable stats for an enemy kind
+    pub fn get_mut(&mut self, kind: EnemyKind) -> Option<&mut EnemyArchetype> {
+        self.archetypes.get_mut(&kind)
+    }
+
+    /// Replace the stats for an enemy kind
+    pub fn set(&mut self, kind: EnemyKind, archetype: EnemyArchetype) {
+        self.archetypes.insert(kind, archetype);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archetypes_cover_every_kind() {
+        let table = EnemyArchetypes::default();
+        for kind in [
+            EnemyKind::Insect,
+            EnemyKind::RivalFungus,
+            EnemyKind::Bacteria,
+        ] {
+            let archetype = table.get(kind).unwrap();
+            assert!(archetype.health > 0.0);
+            assert!(archetype.speed > 0.0);
+            assert!(archetype.radius > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_archetypes_match_spec_roles() {
+        let table = EnemyArchetypes::default();
+        let insect = table.get(EnemyKind::Insect).unwrap();
+        let fungus = table.get(EnemyKind::RivalFungus).unwrap();
+        let bacteria = table.get(EnemyKind::Bacteria).unwrap();
+        assert!(insect.speed > fungus.speed);
+        assert!(fungus.health > insect.health);
+        assert!(bacteria.radius < insect.radius);
+        assert_eq!(bacteria.behaviour, BehaviourKind::Latch);
+    }
+
+    #[test]
+    fn test_archetypes_set_and_get_mut() {
+        let mut table = EnemyArchetypes::default();
+        table.get_mut(EnemyKind::Insect).unwrap().health = 99.0;
+        assert_eq!(table.get(EnemyKind::Insect).unwrap().health, 99.0);
+
+        let mut archetype = table.get(EnemyKind::Bacteria).unwrap().clone();
+        archetype.speed = 1.0;
+        table.set(EnemyKind::Bacteria, archetype.clone());
+        assert_eq!(table.get(EnemyKind::Bacteria), Some(&archetype));
+    }
+
+    #[test]
+    fn test_enemy_archetypes_is_resource() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<EnemyArchetypes>();
+    }
+}
@@ -0,0 +1,627 @@
+//! Rival fungus faction
+//!
+//! Rival fungus enemies are the cores of an AI-controlled network that grows
+//! with the same segments, links and growth tips as the player's:
+//! - Grows toward nutrient sources (`RivalAttractor`) first
+//! - Otherwise spreads toward the player's nearby territory to contest it
+//! - Otherwise wanders outward from its tips
+//! - Its segments corrupt player tendrils they touch
+//! - Killing a segment prunes everything downstream; killing the core
+//!   removes the whole network
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::Rng;
+
+use super::components::{BehaviourKind, Enemy};
+use crate::game::collision::{
+    Collider, CollisionLayers, Layer, LayerMask, TENDRIL_COLLIDER_RADIUS,
+};
+use crate::game::combat::{
+    ContactDamage, CorruptionOnHit, Died, Hostile, StatusEffect, StatusKind,
+};
+use crate::game::map::{ObstacleConfig, Obstacles, TerrainGrid};
+use crate::game::network::graph::find_downstream_segments;
+use crate::game::network::{
+    grow_segment, CoreNode, Faction, GrowthTip, NetworkChildren, NetworkParent, TendrilPosition,
+    TendrilSegment, TendrilStyle, TendrilType,
+};
+
+/// Tuning for rival network growth
+#[derive(Resource, Debug, Clone)]
+pub struct RivalConfig {
+    /// Seconds between growth steps of one rival network
+    pub growth_interval: f32,
+    /// Length of each new segment
+    pub segment_length: f32,
+    /// Segments a single rival network can own
+    pub max_segments: usize,
+    /// Chance the old tip keeps growing alongside the new one
+    pub branch_chance: f64,
+    /// Distance at which nutrient sources pull growth
+    pub attractor_radius: f32,
+    /// Distance at which the player's network is contested
+    pub contest_radius: f32,
+    /// Maximum random turn (radians) applied to each growth step
+    pub wander_jitter: f32,
+    /// Contact damage per second dealt to player tendrils
+    pub contact_damage: f32,
+    /// Corruption applied to player tendrils on contact
+    pub corruption: StatusEffect,
+    /// Segment color
+    pub color: Color,
+}
+
+impl Default for RivalConfig {
+    fn default() -> Self {
+        Self {
+            growth_interval: 1.5,
+            segment_length: 24.0,
+            max_segments: 60,
+            branch_chance: 0.2,
+            attractor_radius: 400.0,
+            contest_radius: 500.0,
+            wander_jitter: 0.6,
+            contact_damage: 3.0,
+            corruption: StatusEffect::new(StatusKind::Corruption, 0.08, 4.0, 0.5),
+            color: Color::srgb(0.75, 0.3, 0.55),
+        }
+    }
+}
+
+/// A rival fungus acting as the core of its own network
+#[derive(Component, Debug, Default)]
+pub struct RivalCore {
+    /// Seconds until the next growth step
+    pub growth_timer: f32,
+}
+
+/// A segment of a rival network
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RivalSegment {
+    /// The rival core this segment grows from
+    pub core: Entity,
+}
+
+/// Something the rival fungus grows toward, such as a nutrient source
+#[derive(Component, Debug, Default)]
+pub struct RivalAttractor;
+
+/// What a rival network is growing toward
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RivalGoal {
+    /// A nutrient source
+    Feed(Vec2),
+    /// The player's territory
+    Contest(Vec2),
+    /// Nothing in sight
+    Wander,
+}
+
+/// Pick the rival's goal as seen from `origin`
+///
+/// Nutrient sources win over the player's network, which wins over wandering.
+#[must_use]
+pub fn choose_rival_goal(
+    origin: Vec2,
+    attractors: &[Vec2],
+    player: &[Vec2],
+    config: &RivalConfig,
+) -> RivalGoal {
+    let nearest_within = |points: &[Vec2], radius: f32| {
+        points
+            .iter()
+            .copied()
+            .filter(|p| p.distance_squared(origin) <= radius * radius)
+            .min_by(|a, b| {
+                a.distance_squared(origin)
+                    .total_cmp(&b.distance_squared(origin))
+            })
+    };
+    if let Some(target) = nearest_within(attractors, config.attractor_radius) {
+        return RivalGoal::Feed(target);
+    }
+    if let Some(target) = nearest_within(player, config.contest_radius) {
+        return RivalGoal::Contest(target);
+    }
+    RivalGoal::Wander
+}
+
+/// A place a rival network can grow from
+#[derive(Debug, Clone, Copy)]
+struct RivalTip {
+    entity: Entity,
+    position: Vec2,
+    direction: Vec2,
+    is_core: bool,
+}
+
+/// Turn rival fungus enemies into network cores
+pub fn attach_rival_cores(
+    mut commands: Commands,
+    config: Res<RivalConfig>,
+    enemies: Query<(Entity, &Enemy, &Transform), Added<Enemy>>,
+) {
+    for (entity, enemy, transform) in enemies.iter() {
+        if enemy.behaviour != BehaviourKind::Grow {
+            continue;
+        }
+        commands.entity(entity).insert((
+            RivalCore {
+                growth_timer: config.growth_interval,
+            },
+            Faction::Rival,
+            NetworkChildren::default(),
+            TendrilPosition::new(transform.translation.truncate(), Vec2::X),
+        ));
+    }
+}
+
+/// Grow every rival network by one segment per growth interval
+pub fn grow_rival_networks(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<RivalConfig>,
+    terrain: Option<Res<TerrainGrid>>,
+    (obstacles, obstacle_config): (Res<Obstacles>, Res<ObstacleConfig>),
+    mut cores: Query<(Entity, &mut RivalCore, &TendrilPosition)>,
+    tips: Query<(Entity, &RivalSegment, &TendrilPosition), With<GrowthTip>>,
+    segments: Query<&RivalSegment>,
+    attractors: Query<&Transform, With<RivalAttractor>>,
+    player_segments: Query<(&TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    player_cores: Query<&Transform, With<CoreNode>>,
+) {
+    let delta = time.delta_secs();
+    let attractors: Vec<Vec2> = attractors
+        .iter()
+        .map(|t| t.translation.truncate())
+        .collect();
+    let player: Vec<Vec2> = player_segments
+        .iter()
+        .filter(|(_, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .map(|(pos, _, _)| pos.position)
+        .chain(player_cores.iter().map(|t| t.translation.truncate()))
+        .collect();
+
+    let mut counts: HashMap<Entity, usize> = HashMap::new();
+    for segment in segments.iter() {
+        *counts.entry(segment.core).or_default() += 1;
+    }
+    let mut tips_by_core: HashMap<Entity, Vec<RivalTip>> = HashMap::new();
+    for (entity, segment, pos) in tips.iter() {
+        tips_by_core
+            .entry(segment.core)
+            .or_default()
+            .push(RivalTip {
+                entity,
+                position: pos.position,
+                direction: pos.direction,
+                is_core: false,
+            });
+    }
+
+    let mut rng = rand::thread_rng();
+    for (core_entity, mut core, core_pos) in cores.iter_mut() {
+        core.growth_timer -= delta;
+        if core.growth_timer > 0.0 {
+            continue;
+        }
+        core.growth_timer += config.growth_interval;
+
+        let count = counts.get(&core_entity).copied().unwrap_or(0);
+        if count >= config.max_segments {
+            continue;
+        }
+        let mut candidates = tips_by_core.remove(&core_entity).unwrap_or_default();
+        if candidates.is_empty() {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            candidates.push(RivalTip {
+                entity: core_entity,
+                position: core_pos.position,
+                direction: Vec2::from_angle(angle),
+                is_core: true,
+            });
+        }
+
+        let goal = choose_rival_goal(core_pos.position, &attractors, &player, &config);
+        let (tip, direction) = match goal {
+            RivalGoal::Feed(target) | RivalGoal::Contest(target) => {
+                let tip = candidates
+                    .iter()
+                    .copied()
+                    .min_by(|a, b| {
+                        a.position
+                            .distance_squared(target)
+                            .total_cmp(&b.position.distance_squared(target))
+                    })
+                    .expect("candidates is never empty");
+                (tip, (target - tip.position).normalize_or(tip.direction))
+            }
+            RivalGoal::Wander => {
+                let tip = candidates[rng.gen_range(0..candidates.len())];
+                (tip, tip.direction)
+            }
+        };
+        let jitter = if config.wander_jitter > 0.0 {
+            rng.gen_range(-config.wander_jitter..=config.wander_jitter)
+        } else {
+            0.0
+        };
+        let direction = Vec2::from_angle(jitter).rotate(direction);
+        let Some(direction) = obstacles.resolve_growth(
+            tip.position,
+            direction,
+            config.segment_length,
+            &obstacle_config,
+        ) else {
+            continue;
+        };
+
+        let next = tip.position + direction * config.segment_length;
+        if terrain
+            .as_ref()
+            .and_then(|t| t.world_to_cell(next).map(|cell| t.is_blocked(cell)))
+            .unwrap_or(false)
+        {
+            continue;
+        }
+
+        let child = grow_segment(
+            &mut commands,
+            tip.entity,
+            tip.position,
+            direction,
+            config.segment_length,
+            TendrilType::Basic,
+            Faction::Rival,
+        );
+        commands.entity(child).insert((
+            RivalSegment { core: core_entity },
+            TendrilStyle {
+                color: config.color,
+                ..TendrilStyle::for_type(TendrilType::Basic)
+            },
+            Hostile,
+            Collider::Capsule {
+                radius: TENDRIL_COLLIDER_RADIUS,
+            },
+            CollisionLayers::new(Layer::Enemy, LayerMask::TENDRIL | LayerMask::CORE),
+            ContactDamage::against_network(config.contact_damage),
+            CorruptionOnHit(config.corruption.clone()),
+            Visibility::default(),
+        ));
+        if !tip.is_core && !rng.gen_bool(config.branch_chance.clamp(0.0, 1.0)) {
+            commands.entity(tip.entity).remove::<GrowthTip>();
+        }
+    }
+}
+
+/// Prune killed rival segments and everything grown from them
+pub fn handle_rival_segment_deaths(
+    mut commands: Commands,
+    mut died: EventReader<Died>,
+    segments: Query<&NetworkParent, With<RivalSegment>>,
+    mut links: ParamSet<(
+        Query<&NetworkChildren>,
+        Query<(&mut NetworkChildren, Has<RivalSegment>)>,
+    )>,
+) {
+    for event in died.read() {
+        let Ok(parent) = segments.get(event.entity) else {
+            continue;
+        };
+        for entity in find_downstream_segments(event.entity, &links.p0()) {
+            if let Some(entity) = commands.get_entity(entity) {
+                entity.despawn_recursive();
+            }
+        }
+        if let Ok((mut siblings, parent_is_segment)) = links.p1().get_mut(parent.0) {
+            siblings.remove_child(event.entity);
+            // The parent resumes growing once its branch is gone
+            if parent_is_segment && siblings.is_empty() {
+                commands.entity(parent.0).insert(GrowthTip::default());
+            }
+        }
+    }
+}
+
+/// Remove rival segments whose core is gone
+pub fn despawn_orphaned_rival_segments(
+    mut commands: Commands,
+    segments: Query<(Entity, &RivalSegment)>,
+    cores: Query<(), With<RivalCore>>,
+) {
+    for (entity, segment) in segments.iter() {
+        if !cores.contains(segment.core) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Remove all rival segments when the run ends
+pub fn despawn_rival_networks(mut commands: Commands, segments: Query<Entity, With<RivalSegment>>) {
+    for entity in segments.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::collision::CollisionStay;
+    use crate::game::combat::{DamageEvent, DamageType, StatusEffects};
+    use crate::game::enemies::test_utils::{create_test_app, enter_state, TEST_FRAME_SECS};
+    use crate::game::enemies::{EnemyKind, SpawnDirectorConfig, SpawnEnemy};
+    use crate::game::map::{ObstacleSite, IMPASSABLE};
+    use crate::GameState;
+
+    fn create_rival_app() -> App {
+        let mut app = create_test_app();
+        app.world_mut()
+            .resource_mut::<SpawnDirectorConfig>()
+            .max_alive = 0;
+        {
+            let mut config = app.world_mut().resource_mut::<RivalConfig>();
+            config.growth_interval = TEST_FRAME_SECS;
+            config.wander_jitter = 0.0;
+            config.branch_chance = 0.0;
+        }
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn spawn_rival(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .send_event(SpawnEnemy::new(EnemyKind::RivalFungus, position));
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<RivalCore>>();
+        query.single(app.world())
+    }
+
+    fn rival_segments(app: &mut App) -> Vec<(Entity, Vec2)> {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(Entity, &TendrilPosition), With<RivalSegment>>();
+        query
+            .iter(app.world())
+            .map(|(e, pos)| (e, pos.position))
+            .collect()
+    }
+
+    #[test]
+    fn test_goal_prefers_food_then_player_then_wander() {
+        let config = RivalConfig::default();
+        let food = [Vec2::new(100.0, 0.0)];
+        let player = [Vec2::new(0.0, 50.0)];
+
+        assert_eq!(
+            choose_rival_goal(Vec2::ZERO, &food, &player, &config),
+            RivalGoal::Feed(food[0])
+        );
+        assert_eq!(
+            choose_rival_goal(Vec2::ZERO, &[], &player, &config),
+            RivalGoal::Contest(player[0])
+        );
+        let far = [Vec2::new(config.contest_radius * 2.0, 0.0)];
+        assert_eq!(
+            choose_rival_goal(Vec2::ZERO, &far, &far, &config),
+            RivalGoal::Wander
+        );
+    }
+
+    #[test]
+    fn test_rival_fungus_becomes_network_core() {
+        let mut app = create_rival_app();
+        let core = spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+
+        assert_eq!(app.world().get::<Faction>(core), Some(&Faction::Rival));
+        assert!(app.world().get::<NetworkChildren>(core).is_some());
+        assert!(app.world().get::<CoreNode>(core).is_none());
+    }
+
+    #[test]
+    fn test_rival_grows_linked_segments() {
+        let mut app = create_rival_app();
+        let core = spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let segments = rival_segments(&mut app);
+        assert!(segments.len() >= 3);
+        let world = app.world();
+        for (entity, _) in &segments {
+            assert_eq!(world.get::<Faction>(*entity), Some(&Faction::Rival));
+            assert_eq!(world.get::<RivalSegment>(*entity).unwrap().core, core);
+            assert!(world.get::<NetworkParent>(*entity).is_some());
+        }
+        // Without branching only the newest segment is a tip
+        let mut tips = app
+            .world_mut()
+            .query_filtered::<(), (With<RivalSegment>, With<GrowthTip>)>();
+        assert_eq!(tips.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_rival_grows_toward_attractor() {
+        let mut app = create_rival_app();
+        app.world_mut()
+            .spawn((RivalAttractor, Transform::from_xyz(300.0, 200.0, 0.0)));
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let segments = rival_segments(&mut app);
+        assert!(!segments.is_empty());
+        for (_, position) in segments {
+            assert!((position.x - 300.0).abs() < 0.01);
+            assert!(position.y > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_rival_contests_player_territory() {
+        let mut app = create_rival_app();
+        app.world_mut().spawn((CoreNode, Transform::default()));
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let segments = rival_segments(&mut app);
+        assert!(!segments.is_empty());
+        assert!(segments.iter().all(|(_, p)| p.x < 300.0));
+    }
+
+    #[test]
+    fn test_rival_respects_segment_cap_and_terrain() {
+        let mut app = create_rival_app();
+        app.world_mut().resource_mut::<RivalConfig>().max_segments = 2;
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..6 {
+            app.update();
+        }
+        assert_eq!(rival_segments(&mut app).len(), 2);
+
+        // A rival walled in by impassable terrain cannot grow at all
+        let mut app = create_rival_app();
+        let position = {
+            let mut terrain = app.world_mut().resource_mut::<TerrainGrid>();
+            let cell = terrain.world_to_cell(Vec2::new(300.0, 0.0)).unwrap();
+            let position = terrain.cell_center(cell);
+            let around: Vec<UVec2> = terrain.neighbours(cell).collect();
+            for neighbour in around {
+                terrain.set_cost(neighbour, IMPASSABLE);
+            }
+            position
+        };
+        // Segments longer than half a cell always leave the core's cell
+        app.world_mut().resource_mut::<RivalConfig>().segment_length = 40.0;
+        spawn_rival(&mut app, position);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(rival_segments(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_rival_cannot_grow_through_obstacles() {
+        let mut app = create_rival_app();
+        // A closed ring of thin walls around the core, narrower than a step
+        let ring: Vec<ObstacleSite> = (0..8)
+            .map(|i| {
+                let a = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 8.0) * 30.0;
+                let b = Vec2::from_angle((i + 1) as f32 * std::f32::consts::TAU / 8.0) * 30.0;
+                let center = Vec2::new(300.0, 0.0);
+                let out = (a + b).normalize() * 2.0;
+                ObstacleSite {
+                    vertices: vec![center + a, center + b, center + b + out, center + a + out],
+                }
+            })
+            .collect();
+        app.insert_resource(Obstacles::new(&ring));
+        app.world_mut().resource_mut::<RivalConfig>().segment_length = 100.0;
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..6 {
+            app.update();
+        }
+        assert!(rival_segments(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_rival_segments_corrupt_player_tendrils() {
+        let mut app = create_rival_app();
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        app.update();
+        let (rival, position) = rival_segments(&mut app)[0];
+        let player = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id();
+        app.world_mut().send_event(CollisionStay {
+            a: rival,
+            b: player,
+        });
+        app.update();
+        app.update();
+
+        let segment = app.world().get::<TendrilSegment>(player).unwrap();
+        assert!(segment.health < TendrilSegment::default().health);
+        let effects = app.world().get::<StatusEffects>(player).unwrap();
+        assert!(effects.has(StatusKind::Corruption));
+    }
+
+    #[test]
+    fn test_killing_segment_prunes_downstream() {
+        let mut app = create_rival_app();
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..3 {
+            app.update();
+        }
+        app.world_mut()
+            .resource_mut::<RivalConfig>()
+            .growth_interval = 1000.0;
+        app.update();
+        let segments = rival_segments(&mut app);
+        assert!(segments.len() >= 3);
+
+        // The first segment hangs off the core; everything else is downstream
+        let first = segments
+            .iter()
+            .map(|(e, _)| *e)
+            .find(|e| {
+                let parent = app.world().get::<NetworkParent>(*e).unwrap().0;
+                app.world().get::<RivalCore>(parent).is_some()
+            })
+            .unwrap();
+        app.world_mut()
+            .send_event(DamageEvent::new(None, first, 1000.0, DamageType::Physical));
+        app.update();
+        app.update();
+
+        assert!(rival_segments(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_killing_core_removes_network() {
+        let mut app = create_rival_app();
+        let core = spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(!rival_segments(&mut app).is_empty());
+
+        app.world_mut()
+            .send_event(DamageEvent::new(None, core, 10_000.0, DamageType::Physical));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(rival_segments(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_rival_networks_removed_on_run_end() {
+        let mut app = create_rival_app();
+        spawn_rival(&mut app, Vec2::new(300.0, 0.0));
+        for _ in 0..3 {
+            app.update();
+        }
+        enter_state(&mut app, GameState::GameOver);
+        assert!(rival_segments(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_rival_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<RivalCore>();
+        assert_component::<RivalSegment>();
+        assert_component::<RivalAttractor>();
+        assert_resource::<RivalConfig>();
+    }
+}
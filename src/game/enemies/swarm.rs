@@ -0,0 +1,553 @@
+//! Insect swarm AI
+//!
+//! Boids-style flocking for enemies with `BehaviourKind::Swarm`:
+//! - Separation, alignment and cohesion between nearby insects
+//! - Leaders seek the nearest live tendril segment or the core node
+//! - Followers cohere to their leader and dive onto its target when close
+//! - Agents slide along obstacles they run into, or stop against them
+//!
+//! Neighbour lookups go through a spatial grid so hundreds of agents stay cheap.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::components::{BehaviourKind, Enemy};
+use crate::game::combat::StatusEffects;
+use crate::game::map::{ObstacleConfig, Obstacles};
+use crate::game::network::{CoreNode, Faction, TendrilPosition, TendrilSegment};
+use crate::game::spatial::SpatialGrid;
+
+/// Tuning for swarm steering
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SwarmConfig {
+    /// Distance within which insects flock together
+    pub neighbour_radius: f32,
+    /// Distance within which insects push each other apart
+    pub separation_radius: f32,
+    pub separation_weight: f32,
+    pub alignment_weight: f32,
+    pub cohesion_weight: f32,
+    /// Weight of seeking the target (leaders) or the leader (followers)
+    pub seek_weight: f32,
+    /// Followers closer than this to their leader's target attack it directly
+    pub engage_radius: f32,
+    /// Distance within which a new insect joins an existing leader
+    pub join_radius: f32,
+    /// Maximum followers per leader
+    pub max_followers: usize,
+    /// How quickly velocity turns toward the desired velocity (per second)
+    pub responsiveness: f32,
+}
+
+impl Default for SwarmConfig {
+    fn default() -> Self {
+        Self {
+            neighbour_radius: 40.0,
+            separation_radius: 14.0,
+            separation_weight: 1.8,
+            alignment_weight: 0.5,
+            cohesion_weight: 0.4,
+            seek_weight: 1.2,
+            engage_radius: 80.0,
+            join_radius: 150.0,
+            max_followers: 12,
+            responsiveness: 6.0,
+        }
+    }
+}
+
+/// Role of an insect within its swarm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwarmRole {
+    /// Chooses targets for the swarm
+    Leader,
+    /// Follows a leader
+    Follower { leader: Entity },
+}
+
+/// Flocking state of a swarm insect
+#[derive(Component, Debug, Clone)]
+pub struct SwarmAgent {
+    pub role: SwarmRole,
+    /// Current velocity in world units per second
+    pub velocity: Vec2,
+    /// Position the agent is currently heading for
+    pub target: Option<Vec2>,
+}
+
+impl SwarmAgent {
+    /// Whether this agent leads a swarm
+    #[must_use]
+    pub fn is_leader(&self) -> bool {
+        self.role == SwarmRole::Leader
+    }
+}
+
+/// A nearby flockmate as seen by an agent
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbour {
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+/// Combined separation, alignment and cohesion steering
+///
+/// Returns a desired direction (not normalised) from flockmates only.
+#[must_use]
+pub fn flock_steering(
+    position: Vec2,
+    velocity: Vec2,
+    neighbours: &[Neighbour],
+    config: &SwarmConfig,
+) -> Vec2 {
+    if neighbours.is_empty() {
+        return Vec2::ZERO;
+    }
+
+    let mut separation = Vec2::ZERO;
+    let mut average_velocity = Vec2::ZERO;
+    let mut center = Vec2::ZERO;
+    for neighbour in neighbours {
+        let offset = position - neighbour.position;
+        let distance = offset.length();
+        if distance < config.separation_radius {
+            // Stronger push the closer they are; coincident agents push along +x
+            let away = offset.normalize_or(Vec2::X);
+            separation += away * (1.0 - distance / config.separation_radius);
+        }
+        average_velocity += neighbour.velocity;
+        center += neighbour.position;
+    }
+    let count = neighbours.len() as f32;
+    average_velocity /= count;
+    center /= count;
+
+    let alignment = (average_velocity - velocity).normalize_or_zero();
+    let cohesion = (center - position).normalize_or_zero();
+
+    separation * config.separation_weight
+        + alignment * config.alignment_weight
+        + cohesion * config.cohesion_weight
+}
+
+/// Join the nearest leader with room in `join_radius`, or become a leader
+fn choose_role(
+    entity: Entity,
+    position: Vec2,
+    leaders: &mut HashMap<Entity, (Vec2, usize)>,
+    config: &SwarmConfig,
+) -> SwarmRole {
+    let join_sq = config.join_radius * config.join_radius;
+    let nearest = leaders
+        .iter()
+        .filter(|(&leader, (leader_pos, followers))| {
+            leader != entity
+                && *followers < config.max_followers
+                && leader_pos.distance_squared(position) <= join_sq
+        })
+        .min_by(|a, b| {
+            let (a_pos, _) = a.1;
+            let (b_pos, _) = b.1;
+            a_pos
+                .distance_squared(position)
+                .total_cmp(&b_pos.distance_squared(position))
+        })
+        .map(|(&leader, _)| leader);
+
+    match nearest {
+        Some(leader) => {
+            if let Some((_, followers)) = leaders.get_mut(&leader) {
+                *followers += 1;
+            }
+            SwarmRole::Follower { leader }
+        }
+        None => {
+            leaders.insert(entity, (position, 0));
+            SwarmRole::Leader
+        }
+    }
+}
+
+/// Give new swarm enemies a role, and re-home followers whose leader is gone
+pub fn assign_swarm_roles(
+    mut commands: Commands,
+    config: Res<SwarmConfig>,
+    new_insects: Query<(Entity, &Enemy, &Transform), Without<SwarmAgent>>,
+    mut agents: Query<(Entity, &mut SwarmAgent, &Transform)>,
+) {
+    // Current leaders with their follower counts
+    let mut leaders: HashMap<Entity, (Vec2, usize)> = agents
+        .iter()
+        .filter(|(_, agent, _)| agent.is_leader())
+        .map(|(entity, _, transform)| (entity, (transform.translation.truncate(), 0)))
+        .collect();
+    for (_, agent, _) in agents.iter() {
+        if let SwarmRole::Follower { leader } = agent.role {
+            if let Some((_, followers)) = leaders.get_mut(&leader) {
+                *followers += 1;
+            }
+        }
+    }
+
+    for (entity, mut agent, transform) in agents.iter_mut() {
+        if let SwarmRole::Follower { leader } = agent.role {
+            if !leaders.contains_key(&leader) {
+                let position = transform.translation.truncate();
+                agent.role = choose_role(entity, position, &mut leaders, &config);
+            }
+        }
+    }
+
+    for (entity, enemy, transform) in new_insects.iter() {
+        if enemy.behaviour != BehaviourKind::Swarm {
+            continue;
+        }
+        let position = transform.translation.truncate();
+        commands.entity(entity).insert(SwarmAgent {
+            role: choose_role(entity, position, &mut leaders, &config),
+            velocity: Vec2::ZERO,
+            target: None,
+        });
+    }
+}
+
+/// Steer and move every swarm agent
+pub fn steer_swarm(
+    time: Res<Time>,
+    config: Res<SwarmConfig>,
+    mut grid: Local<SpatialGrid>,
+    mut agents: Query<(
+        Entity,
+        &mut SwarmAgent,
+        &mut Transform,
+        &Enemy,
+        Option<&StatusEffects>,
+    )>,
+    segments: Query<(&TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    cores: Query<&Transform, (With<CoreNode>, Without<SwarmAgent>)>,
+    obstacles: Res<Obstacles>,
+    obstacle_config: Res<ObstacleConfig>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    // Snapshot positions and velocities so every agent sees the same frame
+    grid.clear();
+    let mut snapshot: HashMap<Entity, Neighbour> = HashMap::new();
+    for (entity, agent, transform, _, _) in agents.iter() {
+        let position = transform.translation.truncate();
+        grid.insert(entity, position);
+        snapshot.insert(
+            entity,
+            Neighbour {
+                position,
+                velocity: agent.velocity,
+            },
+        );
+    }
+
+    let targets: Vec<Vec2> = segments
+        .iter()
+        .filter(|(_, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .map(|(pos, _, _)| pos.position)
+        .chain(cores.iter().map(|t| t.translation.truncate()))
+        .collect();
+    let nearest_target = |position: Vec2| {
+        targets.iter().copied().min_by(|a, b| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        })
+    };
+
+    // Leaders choose targets first so followers can share them
+    let mut leader_state: HashMap<Entity, (Vec2, Option<Vec2>)> = HashMap::new();
+    for (entity, mut agent, transform, _, _) in agents.iter_mut() {
+        if agent.is_leader() {
+            let position = transform.translation.truncate();
+            agent.target = nearest_target(position);
+            leader_state.insert(entity, (position, agent.target));
+        }
+    }
+
+    let mut neighbours = Vec::new();
+    for (entity, mut agent, mut transform, enemy, effects) in agents.iter_mut() {
+        let position = transform.translation.truncate();
+
+        neighbours.clear();
+        neighbours.extend(
+            grid.query_radius(position, config.neighbour_radius)
+                .filter(|(other, _)| *other != entity)
+                .filter_map(|(other, _)| snapshot.get(&other).copied()),
+        );
+        let flock = flock_steering(position, agent.velocity, &neighbours, &config);
+
+        let goal =
+            match agent.role {
+                SwarmRole::Leader => agent.target,
+                SwarmRole::Follower { leader } => leader_state.get(&leader).map(
+                    |&(leader_pos, leader_target)| match leader_target {
+                        Some(target)
+                            if target.distance_squared(position)
+                                <= config.engage_radius * config.engage_radius =>
+                        {
+                            target
+                        }
+                        _ => leader_pos,
+                    },
+                ),
+            };
+        if !agent.is_leader() {
+            agent.target = goal;
+        }
+        let seek = goal.map_or(Vec2::ZERO, |g| (g - position).normalize_or_zero());
+
+        let speed = enemy.speed * effects.map_or(1.0, StatusEffects::speed_multiplier);
+        let desired = (flock + seek * config.seek_weight).normalize_or_zero() * speed;
+        let blend = (config.responsiveness * delta).min(1.0);
+        agent.velocity = agent.velocity.lerp(desired, blend).clamp_length_max(speed);
+
+        // Walls stop or deflect the agent; its velocity follows what it
+        // actually did so flocking does not keep pushing into them
+        let to = position + agent.velocity * delta;
+        let reached = obstacles.slide_move(position, to, obstacle_config.skin);
+        if reached != to {
+            agent.velocity = (reached - position) / delta;
+        }
+        transform.translation = reached.extend(transform.translation.z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::enemies::test_utils::{create_test_app, enter_state};
+    use crate::game::enemies::{EnemyKind, SpawnDirectorConfig, SpawnEnemy};
+    use crate::game::map::ObstacleSite;
+    use crate::GameState;
+
+    fn neighbour(x: f32, y: f32, velocity: Vec2) -> Neighbour {
+        Neighbour {
+            position: Vec2::new(x, y),
+            velocity,
+        }
+    }
+
+    #[test]
+    fn test_flock_steering_without_neighbours_is_zero() {
+        let steering = flock_steering(Vec2::ZERO, Vec2::X, &[], &SwarmConfig::default());
+        assert_eq!(steering, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_separation_pushes_away_from_close_neighbour() {
+        let config = SwarmConfig {
+            alignment_weight: 0.0,
+            cohesion_weight: 0.0,
+            ..default()
+        };
+        let steering = flock_steering(
+            Vec2::ZERO,
+            Vec2::ZERO,
+            &[neighbour(5.0, 0.0, Vec2::ZERO)],
+            &config,
+        );
+        assert!(steering.x < 0.0);
+    }
+
+    #[test]
+    fn test_cohesion_pulls_toward_group_center() {
+        let config = SwarmConfig {
+            separation_weight: 0.0,
+            alignment_weight: 0.0,
+            ..default()
+        };
+        let steering = flock_steering(
+            Vec2::ZERO,
+            Vec2::ZERO,
+            &[
+                neighbour(30.0, 10.0, Vec2::ZERO),
+                neighbour(30.0, -10.0, Vec2::ZERO),
+            ],
+            &config,
+        );
+        assert!(steering.x > 0.0);
+        assert!(steering.y.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_alignment_matches_neighbour_heading() {
+        let config = SwarmConfig {
+            separation_weight: 0.0,
+            cohesion_weight: 0.0,
+            ..default()
+        };
+        let steering = flock_steering(
+            Vec2::ZERO,
+            Vec2::ZERO,
+            &[neighbour(30.0, 0.0, Vec2::new(0.0, 50.0))],
+            &config,
+        );
+        assert!(steering.y > 0.0);
+    }
+
+    #[test]
+    fn test_swarm_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<SwarmAgent>();
+        assert_resource::<SwarmConfig>();
+    }
+
+    fn create_swarm_app() -> App {
+        let mut app = create_test_app();
+        // Keep the director out of these tests
+        app.world_mut()
+            .resource_mut::<SpawnDirectorConfig>()
+            .max_alive = 0;
+        app.update();
+        enter_state(&mut app, GameState::Playing);
+        app
+    }
+
+    fn spawn_insects(app: &mut App, positions: &[Vec2]) {
+        for &position in positions {
+            app.world_mut()
+                .send_event(SpawnEnemy::new(EnemyKind::Insect, position));
+        }
+        app.update();
+        app.update();
+    }
+
+    fn agents(app: &mut App) -> Vec<(Entity, SwarmAgent, Vec2)> {
+        let mut query = app.world_mut().query::<(Entity, &SwarmAgent, &Transform)>();
+        query
+            .iter(app.world())
+            .map(|(e, a, t)| (e, a.clone(), t.translation.truncate()))
+            .collect()
+    }
+
+    #[test]
+    fn test_nearby_insects_form_one_swarm() {
+        let mut app = create_swarm_app();
+        spawn_insects(
+            &mut app,
+            &[Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(0.0, 10.0)],
+        );
+
+        let agents = agents(&mut app);
+        let leaders: Vec<_> = agents.iter().filter(|(_, a, _)| a.is_leader()).collect();
+        assert_eq!(agents.len(), 3);
+        assert_eq!(leaders.len(), 1);
+    }
+
+    #[test]
+    fn test_distant_insects_lead_their_own_swarms() {
+        let mut app = create_swarm_app();
+        spawn_insects(&mut app, &[Vec2::ZERO, Vec2::new(1000.0, 0.0)]);
+
+        assert!(agents(&mut app).iter().all(|(_, a, _)| a.is_leader()));
+    }
+
+    #[test]
+    fn test_follower_promoted_when_leader_dies() {
+        let mut app = create_swarm_app();
+        spawn_insects(&mut app, &[Vec2::ZERO, Vec2::new(10.0, 0.0)]);
+        let leader = agents(&mut app)
+            .into_iter()
+            .find(|(_, a, _)| a.is_leader())
+            .unwrap()
+            .0;
+
+        app.world_mut().entity_mut(leader).despawn();
+        app.update();
+
+        let remaining = agents(&mut app);
+        assert_eq!(remaining.len(), 1);
+        assert!(remaining[0].1.is_leader());
+    }
+
+    #[test]
+    fn test_swarm_moves_toward_nearest_segment() {
+        let mut app = create_swarm_app();
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::new(300.0, 0.0), Vec2::X),
+        ));
+        spawn_insects(&mut app, &[Vec2::ZERO, Vec2::new(0.0, 10.0)]);
+
+        for _ in 0..8 {
+            app.update();
+        }
+
+        for (_, agent, position) in agents(&mut app) {
+            assert!(position.x > 20.0, "insect did not advance: {position}");
+            assert!(agent.target.is_some());
+        }
+    }
+
+    #[test]
+    fn test_insect_blocked_by_wall() {
+        let mut app = create_swarm_app();
+        app.insert_resource(Obstacles::new(&[ObstacleSite {
+            vertices: vec![
+                Vec2::new(100.0, -400.0),
+                Vec2::new(120.0, -400.0),
+                Vec2::new(120.0, 400.0),
+                Vec2::new(100.0, 400.0),
+            ],
+        }]));
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::new(300.0, 0.0), Vec2::X),
+        ));
+        spawn_insects(&mut app, &[Vec2::ZERO]);
+
+        for _ in 0..20 {
+            app.update();
+        }
+
+        let (_, _, position) = agents(&mut app)[0];
+        assert!(position.x > 50.0, "insect did not advance: {position}");
+        assert!(position.x < 100.0, "insect passed the wall: {position}");
+    }
+
+    #[test]
+    fn test_dead_segments_are_ignored() {
+        let mut app = create_swarm_app();
+        app.world_mut().spawn((
+            TendrilSegment {
+                health: 0.0,
+                ..default()
+            },
+            TendrilPosition::new(Vec2::new(300.0, 0.0), Vec2::X),
+        ));
+        spawn_insects(&mut app, &[Vec2::ZERO]);
+        app.update();
+
+        assert!(agents(&mut app)[0].1.target.is_none());
+    }
+
+    #[test]
+    fn test_hundreds_of_agents_keep_apart() {
+        let mut app = create_swarm_app();
+        app.world_mut()
+            .spawn((CoreNode, Transform::from_xyz(2000.0, 0.0, 0.0)));
+        let positions: Vec<Vec2> = (0..300)
+            .map(|i| Vec2::new((i % 20) as f32 * 3.0, (i / 20) as f32 * 3.0))
+            .collect();
+        spawn_insects(&mut app, &positions);
+
+        for _ in 0..12 {
+            app.update();
+        }
+
+        let agents = agents(&mut app);
+        assert_eq!(agents.len(), 300);
+        // Separation spreads the tightly packed block out
+        let ys = agents.iter().map(|(_, _, p)| p.y);
+        let spread = ys.clone().fold(f32::MIN, f32::max) - ys.fold(f32::MAX, f32::min);
+        assert!(spread > 14.0 * 3.0);
+    }
+}
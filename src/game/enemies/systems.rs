@@ -0,0 +1,124 @@
+//! Enemy systems
+//!
+//! Spawning, death handling, cleanup and placeholder rendering.
+
+use bevy::prelude::*;
+
+use super::components::{Enemy, EnemyKind};
+use super::events::{EnemySpawned, SpawnEnemy};
+use super::resources::{EnemyArchetype, EnemyArchetypes};
+use crate::game::collision::{Collider, CollisionLayers, Layer, LayerMask};
+use crate::game::combat::{ContactDamage, CorruptionOnHit, Died, Hostile};
+use crate::game::network::Health;
+use crate::game::pickups::{CorpseConfig, DropNutrients, LeaveCorpse};
+
+/// Spawn an enemy of an archetype and return its entity
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    kind: EnemyKind,
+    archetype: &EnemyArchetype,
+    position: Vec2,
+) -> Entity {
+    let mut entity = commands.spawn((
+        Enemy {
+            kind,
+            behaviour: archetype.behaviour,
+            speed: archetype.speed,
+            radius: archetype.radius,
+            nutrient_drop: archetype.nutrient_drop,
+        },
+        Hostile,
+        Health::new(archetype.health),
+        ContactDamage::against_network(archetype.contact_damage),
+        Collider::Circle {
+            radius: archetype.radius,
+        },
+        CollisionLayers::new(Layer::Enemy, LayerMask::TENDRIL | LayerMask::CORE),
+        Transform::from_translation(position.extend(0.0)),
+        Visibility::default(),
+    ));
+    if archetype.corrupts {
+        entity.insert(CorruptionOnHit::default());
+    }
+    entity.id()
+}
+
+/// Spawn enemies for every `SpawnEnemy` request
+pub fn spawn_requested_enemies(
+    mut commands: Commands,
+    mut requests: EventReader<SpawnEnemy>,
+    archetypes: Res<EnemyArchetypes>,
+    mut spawned: EventWriter<EnemySpawned>,
+) {
+    for request in requests.read() {
+        let Some(archetype) = archetypes.get(request.kind) else {
+            warn!("No archetype for enemy kind {:?}", request.kind);
+            continue;
+        };
+        let entity = spawn_enemy(&mut commands, request.kind, archetype, request.position);
+        spawned.send(EnemySpawned {
+            entity,
+            kind: request.kind,
+            position: request.position,
+        });
+    }
+}
+
+/// Drop nutrient orbs and leave corpses for killed enemies and remove them
+///
+/// The nutrient drop is split between the orbs and the corpse.
+pub fn handle_enemy_deaths(
+    mut commands: Commands,
+    mut died: EventReader<Died>,
+    corpse_config: Res<CorpseConfig>,
+    enemies: Query<(&Enemy, &Transform)>,
+    mut drops: EventWriter<DropNutrients>,
+    mut corpses: EventWriter<LeaveCorpse>,
+) {
+    for event in died.read() {
+        let Ok((enemy, transform)) = enemies.get(event.entity) else {
+            continue;
+        };
+        let (orbs, corpse) = corpse_config.split_drop(enemy.nutrient_drop);
+        if orbs > 0.0 {
+            drops.send(DropNutrients::new(transform.translation.truncate(), orbs));
+        }
+        corpses.send(LeaveCorpse {
+            position: transform.translation.truncate(),
+            radius: enemy.radius,
+            nutrients: corpse,
+        });
+        commands.entity(event.entity).despawn_recursive();
+    }
+}
+
+/// Remove every enemy when the run ends
+pub fn despawn_all_enemies(mut commands: Commands, enemies: Query<Entity, With<Enemy>>) {
+    for entity in enemies.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Placeholder colour per enemy kind
+#[must_use]
+pub fn enemy_color(kind: EnemyKind) -> Color {
+    match kind {
+        EnemyKind::Insect => Color::srgb(0.9, 0.3, 0.2),
+        EnemyKind::RivalFungus => Color::srgb(0.6, 0.2, 0.7),
+        EnemyKind::Bacteria => Color::srgb(0.9, 0.9, 0.3),
+    }
+}
+
+/// Draw enemies as circles until sprites exist
+pub fn render_enemies(mut gizmos: Gizmos, enemies: Query<(&Enemy, &Transform, &Visibility)>) {
+    for (enemy, transform, visibility) in enemies.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            enemy.radius,
+            enemy_color(enemy.kind),
+        );
+    }
+}
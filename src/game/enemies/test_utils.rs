@@ -0,0 +1,63 @@
+//! Shared test utilities for enemy module tests
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use super::EnemiesPlugin;
+use crate::game::collision::CollisionPlugin;
+use crate::game::combat::CombatPlugin;
+use crate::game::map::{
+    BiomeMap, BiomeTable, MapLayout, ObstacleConfig, Obstacles, TerrainGrid, WorldDescription,
+};
+use crate::game::network::NetworkStats;
+use crate::game::pickups::PickupsPlugin;
+use crate::game::progression::ProgressionPlugin;
+use crate::game::state::RunStats;
+use crate::GameState;
+
+/// Fixed frame time used by enemy tests (exact in binary floating point)
+pub const TEST_FRAME_SECS: f32 = 0.25;
+
+/// Helper to create a test app with enemies, combat, collision, progression
+/// and pickup plugins and a fixed time step
+pub fn create_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(StatesPlugin)
+        .init_state::<GameState>()
+        .init_resource::<RunStats>()
+        .init_resource::<NetworkStats>()
+        .init_resource::<TerrainGrid>()
+        .init_resource::<BiomeMap>()
+        .init_resource::<BiomeTable>()
+        .init_resource::<WorldDescription>()
+        .init_resource::<Obstacles>()
+        .init_resource::<ObstacleConfig>()
+        // No colonies or bosses unless a test places them
+        .insert_resource(MapLayout {
+            colonies: Vec::new(),
+            bosses: Vec::new(),
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            TEST_FRAME_SECS,
+        )))
+        .add_plugins((
+            CollisionPlugin,
+            CombatPlugin,
+            ProgressionPlugin,
+            EnemiesPlugin,
+            PickupsPlugin,
+        ));
+    app
+}
+
+/// Transition the test app into `state` and let the transition settle
+pub fn enter_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
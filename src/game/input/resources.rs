@@ -22,6 +22,8 @@ pub struct InputActions {
     pub secondary_just_pressed: bool,
     /// Secondary action currently held
     pub secondary_held: bool,
+    /// Ability key just pressed this frame (launch scout spores)
+    pub ability_just_pressed: bool,
     /// Pause toggle just pressed this frame
     pub pause_just_pressed: bool,
     /// Camera zoom delta (-1.0 to 1.0, negative = zoom out)
@@ -39,6 +41,7 @@ impl InputActions {
         self.move_direction = Vec2::ZERO;
         self.primary_just_pressed = false;
         self.secondary_just_pressed = false;
+        self.ability_just_pressed = false;
         self.pause_just_pressed = false;
         self.zoom_delta = 0.0;
         // Note: held states are not cleared, they persist
@@ -67,6 +70,7 @@ mod tests {
         assert_eq!(actions.move_direction, Vec2::ZERO);
         assert!(!actions.primary_just_pressed);
         assert!(!actions.secondary_just_pressed);
+        assert!(!actions.ability_just_pressed);
         assert!(!actions.pause_just_pressed);
         assert_eq!(actions.zoom_delta, 0.0);
     }
@@ -92,6 +96,7 @@ mod tests {
             move_direction: Vec2::new(1.0, 1.0),
             primary_just_pressed: true,
             secondary_just_pressed: true,
+            ability_just_pressed: true,
             pause_just_pressed: true,
             zoom_delta: 0.5,
             ..default()
@@ -101,6 +106,7 @@ mod tests {
 
         assert_eq!(actions.move_direction, Vec2::ZERO);
         assert!(!actions.primary_just_pressed);
+        assert!(!actions.ability_just_pressed);
         assert_eq!(actions.zoom_delta, 0.0);
     }
 }
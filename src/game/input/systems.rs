@@ -51,6 +51,11 @@ pub fn update_keyboard_input(
     }
     actions.secondary_held =
         keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+
+    // Ability (Q)
+    if keyboard.just_pressed(KeyCode::KeyQ) {
+        actions.ability_just_pressed = true;
+    }
 }
 
 /// Read mouse input and update InputActions
@@ -70,6 +75,11 @@ pub fn update_mouse_input(mouse: Res<ButtonInput<MouseButton>>, mut actions: Res
     if mouse.pressed(MouseButton::Right) {
         actions.secondary_held = true;
     }
+
+    // Ability (Middle click)
+    if mouse.just_pressed(MouseButton::Middle) {
+        actions.ability_just_pressed = true;
+    }
 }
 
 /// Read mouse scroll and update zoom delta
@@ -0,0 +1,355 @@
+//! Biomes
+//!
+//! The map is split into biome regions, each changing how the network fares
+//! on it:
+//! - `BiomeMap` answers which biome covers a world position
+//! - `BiomeTable` holds the modifiers of every biome: growth cost, segment
+//!   health drain, enemy spawn weights and passive absorption
+//! - Hazardous biomes drain segments through the damage pipeline once per
+//!   `DRAIN_TICK_INTERVAL`
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::generation::BiomeRegion;
+use super::resources::Biome;
+use crate::game::combat::{DamageEvent, DamageType};
+use crate::game::enemies::EnemyKind;
+use crate::game::network::{Severed, TendrilPosition, TendrilSegment};
+
+/// Seconds between biome drain ticks
+pub const DRAIN_TICK_INTERVAL: f32 = 1.0;
+
+/// Biome regions of the current map
+///
+/// Every point belongs to the region with the nearest center; an empty map is
+/// all `Biome::default()`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BiomeMap {
+    regions: Vec<BiomeRegion>,
+}
+
+impl BiomeMap {
+    #[must_use]
+    pub fn new(regions: Vec<BiomeRegion>) -> Self {
+        Self { regions }
+    }
+
+    #[must_use]
+    pub fn regions(&self) -> &[BiomeRegion] {
+        &self.regions
+    }
+
+    /// Biome covering a world position
+    #[must_use]
+    pub fn biome_at(&self, position: Vec2) -> Biome {
+        self.regions
+            .iter()
+            .min_by(|a, b| {
+                a.center
+                    .distance_squared(position)
+                    .total_cmp(&b.center.distance_squared(position))
+            })
+            .map_or_else(Biome::default, |region| region.biome)
+    }
+}
+
+/// How a biome changes growth, health, spawns and income
+#[derive(Debug, Clone)]
+pub struct BiomeModifiers {
+    /// Multiplier on the nutrient cost of growing a segment here
+    pub growth_cost_multiplier: f32,
+    /// Toxic damage per second dealt to every segment here
+    pub health_drain: f32,
+    /// Multiplier on the enemy spawn weight of each kind (missing = 1.0)
+    pub spawn_weights: HashMap<EnemyKind, f32>,
+    /// Multiplier on passive absorption for segments here
+    pub absorption_multiplier: f32,
+}
+
+impl Default for BiomeModifiers {
+    fn default() -> Self {
+        Self {
+            growth_cost_multiplier: 1.0,
+            health_drain: 0.0,
+            spawn_weights: HashMap::new(),
+            absorption_multiplier: 1.0,
+        }
+    }
+}
+
+impl BiomeModifiers {
+    /// Spawn weight multiplier for an enemy kind
+    #[must_use]
+    pub fn spawn_weight(&self, kind: EnemyKind) -> f32 {
+        self.spawn_weights.get(&kind).copied().unwrap_or(1.0)
+    }
+}
+
+/// Modifiers by biome
+#[derive(Resource, Debug, Clone)]
+pub struct BiomeTable {
+    biomes: HashMap<Biome, BiomeModifiers>,
+}
+
+impl BiomeTable {
+    #[must_use]
+    pub fn get(&self, biome: Biome) -> Option<&BiomeModifiers> {
+        self.biomes.get(&biome)
+    }
+
+    pub fn get_mut(&mut self, biome: Biome) -> Option<&mut BiomeModifiers> {
+        self.biomes.get_mut(&biome)
+    }
+
+    pub fn set(&mut self, biome: Biome, modifiers: BiomeModifiers) {
+        self.biomes.insert(biome, modifiers);
+    }
+
+    /// Growth cost multiplier at a world position
+    #[must_use]
+    pub fn growth_cost_multiplier(&self, map: &BiomeMap, position: Vec2) -> f32 {
+        self.get(map.biome_at(position))
+            .map_or(1.0, |m| m.growth_cost_multiplier)
+    }
+
+    /// Passive absorption multiplier at a world position
+    #[must_use]
+    pub fn absorption_multiplier(&self, map: &BiomeMap, position: Vec2) -> f32 {
+        self.get(map.biome_at(position))
+            .map_or(1.0, |m| m.absorption_multiplier)
+    }
+
+    /// Spawn weight multiplier for `kind` at a world position
+    #[must_use]
+    pub fn spawn_weight(&self, map: &BiomeMap, position: Vec2, kind: EnemyKind) -> f32 {
+        self.get(map.biome_at(position))
+            .map_or(1.0, |m| m.spawn_weight(kind))
+    }
+}
+
+impl Default for BiomeTable {
+    fn default() -> Self {
+        let mut biomes = HashMap::new();
+        biomes.insert(Biome::ForestFloor, BiomeModifiers::default());
+        biomes.insert(
+            Biome::Rocky,
+            BiomeModifiers {
+                growth_cost_multiplier: 1.75,
+                health_drain: 0.0,
+                spawn_weights: [(EnemyKind::Insect, 1.5), (EnemyKind::Bacteria, 0.5)]
+                    .into_iter()
+                    .collect(),
+                absorption_multiplier: 0.5,
+            },
+        );
+        biomes.insert(
+            Biome::Swamp,
+            BiomeModifiers {
+                growth_cost_multiplier: 0.8,
+                health_drain: 1.5,
+                spawn_weights: [(EnemyKind::Bacteria, 2.0), (EnemyKind::Insect, 0.6)]
+                    .into_iter()
+                    .collect(),
+                absorption_multiplier: 1.6,
+            },
+        );
+        Self { biomes }
+    }
+}
+
+/// Time accumulated toward the next biome drain tick
+#[derive(Resource, Debug, Default)]
+pub struct BiomeDrainTimer(pub f32);
+
+/// Damage segments standing in draining biomes on every drain tick
+///
+/// The hazard belongs to the ground, so rival segments are drained like the
+/// player's. Severed segments are skipped as they already decay on their own.
+pub fn drain_segments_in_biomes(
+    time: Res<Time>,
+    mut timer: ResMut<BiomeDrainTimer>,
+    map: Res<BiomeMap>,
+    table: Res<BiomeTable>,
+    segments: Query<(Entity, &TendrilSegment, &TendrilPosition), Without<Severed>>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    timer.0 += time.delta_secs();
+    let mut ticks = 0;
+    while timer.0 >= DRAIN_TICK_INTERVAL {
+        timer.0 -= DRAIN_TICK_INTERVAL;
+        ticks += 1;
+    }
+    if ticks == 0 {
+        return;
+    }
+    let elapsed = DRAIN_TICK_INTERVAL * ticks as f32;
+    for (entity, segment, position) in segments.iter() {
+        if segment.health <= 0.0 {
+            continue;
+        }
+        let drain = table
+            .get(map.biome_at(position.position))
+            .map_or(0.0, |m| m.health_drain);
+        if drain > 0.0 {
+            damage.send(DamageEvent::new(
+                None,
+                entity,
+                drain * elapsed,
+                DamageType::Toxic,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use crate::game::collision::CollisionPlugin;
+    use crate::game::combat::CombatPlugin;
+    use crate::game::map::MapPlugin;
+    use crate::game::network::Faction;
+    use crate::game::state::{RunSeed, RunStats};
+    use crate::GameState;
+
+    fn two_regions() -> BiomeMap {
+        BiomeMap::new(vec![
+            BiomeRegion {
+                biome: Biome::Rocky,
+                center: Vec2::new(-100.0, 0.0),
+            },
+            BiomeRegion {
+                biome: Biome::Swamp,
+                center: Vec2::new(100.0, 0.0),
+            },
+        ])
+    }
+
+    fn create_test_app(map: BiomeMap) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunStats>()
+            .init_resource::<RunSeed>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                0.25,
+            )))
+            .add_plugins((CollisionPlugin, CombatPlugin, MapPlugin));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.insert_resource(map);
+        app
+    }
+
+    fn spawn_segment(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(position, Vec2::X),
+            ))
+            .id()
+    }
+
+    fn health(app: &App, entity: Entity) -> f32 {
+        app.world().get::<TendrilSegment>(entity).unwrap().health
+    }
+
+    #[test]
+    fn test_biome_at_picks_nearest_region() {
+        let map = two_regions();
+        assert_eq!(map.biome_at(Vec2::new(-30.0, 500.0)), Biome::Rocky);
+        assert_eq!(map.biome_at(Vec2::new(30.0, -500.0)), Biome::Swamp);
+    }
+
+    #[test]
+    fn test_empty_map_is_default_biome() {
+        assert_eq!(BiomeMap::default().biome_at(Vec2::ZERO), Biome::ForestFloor);
+    }
+
+    #[test]
+    fn test_table_lookups_by_position() {
+        let map = two_regions();
+        let table = BiomeTable::default();
+        let rocky = Vec2::new(-100.0, 0.0);
+        let swamp = Vec2::new(100.0, 0.0);
+        assert!(table.growth_cost_multiplier(&map, rocky) > 1.0);
+        assert!(table.absorption_multiplier(&map, swamp) > 1.0);
+        assert!(table.spawn_weight(&map, swamp, EnemyKind::Bacteria) > 1.0);
+        assert_eq!(table.spawn_weight(&map, swamp, EnemyKind::RivalFungus), 1.0);
+    }
+
+    #[test]
+    fn test_every_biome_has_modifiers() {
+        let table = BiomeTable::default();
+        for biome in Biome::ALL {
+            assert!(table.get(biome).is_some());
+        }
+    }
+
+    #[test]
+    fn test_swamp_drains_segments_and_rock_does_not() {
+        let mut app = create_test_app(two_regions());
+        let in_swamp = spawn_segment(&mut app, Vec2::new(100.0, 0.0));
+        let on_rock = spawn_segment(&mut app, Vec2::new(-100.0, 0.0));
+        let full = health(&app, in_swamp);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(health(&app, in_swamp) < full);
+        assert_eq!(health(&app, on_rock), full);
+    }
+
+    #[test]
+    fn test_drain_applies_once_per_tick() {
+        let mut app = create_test_app(two_regions());
+        let in_swamp = spawn_segment(&mut app, Vec2::new(100.0, 0.0));
+        let full = health(&app, in_swamp);
+        let drain = BiomeTable::default()
+            .get(Biome::Swamp)
+            .unwrap()
+            .health_drain;
+
+        // 0.75s of 0.25s frames: no tick yet
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(health(&app, in_swamp), full);
+
+        app.update();
+        assert_eq!(health(&app, in_swamp), full - drain * DRAIN_TICK_INTERVAL);
+    }
+
+    #[test]
+    fn test_drain_hits_rival_segments_but_not_severed_ones() {
+        let mut app = create_test_app(two_regions());
+        let rival = spawn_segment(&mut app, Vec2::new(100.0, 0.0));
+        app.world_mut().entity_mut(rival).insert(Faction::Rival);
+        let severed = spawn_segment(&mut app, Vec2::new(100.0, 0.0));
+        app.world_mut().entity_mut(severed).insert(Severed {
+            time_since_severance: 0.0,
+            decay_rate: 0.0,
+        });
+        let full = health(&app, rival);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(health(&app, rival) < full);
+        assert_eq!(health(&app, severed), full);
+    }
+
+    #[test]
+    fn test_biome_types_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<BiomeMap>();
+        assert_resource::<BiomeTable>();
+        assert_resource::<BiomeDrainTimer>();
+    }
+}
@@ -0,0 +1,690 @@
+//! Fog of war
+//!
+//! A coarse visibility grid over the map:
+//! - Player segments and the core reveal a radius around themselves for the
+//!   rest of the run, once when they appear or move
+//! - Scout spores reveal cells for a limited time only
+//! - Hostiles and pickups standing in fogged cells are hidden, unless a scout
+//!   spore marked them
+//! - A dark overlay texture covers fogged cells; only cells whose visibility
+//!   changed are rewritten each frame
+//!
+//! `FogGrid` is a resource so the minimap and danger sense can ask what the
+//! player is able to see.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::sprite::Anchor;
+
+use super::scout::MinimapMark;
+use crate::game::combat::Hostile;
+use crate::game::network::{CoreNode, Faction, Severed, TendrilPosition, TendrilSegment};
+use crate::game::pickups::{Corpse, NutrientNode, NutrientOrb};
+
+/// Tuning for fog of war
+#[derive(Resource, Debug, Clone)]
+pub struct FogConfig {
+    /// Side length of a fog cell in world units
+    pub cell_size: f32,
+    /// Radius revealed around each live segment
+    pub segment_reveal_radius: f32,
+    /// Radius revealed around the core
+    pub core_reveal_radius: f32,
+    /// Opacity (0.0..=1.0) of the overlay over fogged cells
+    pub opacity: f32,
+    /// Depth of the overlay, above the world and below UI
+    pub overlay_z: f32,
+}
+
+impl Default for FogConfig {
+    fn default() -> Self {
+        Self {
+            cell_size: 48.0,
+            segment_reveal_radius: 180.0,
+            core_reveal_radius: 320.0,
+            opacity: 0.85,
+            overlay_z: 5.0,
+        }
+    }
+}
+
+/// Which cells of the map the player can see
+///
+/// A cell is visible while it is revealed for good or still has temporary
+/// sight left. Positions outside the grid are never visible.
+#[derive(Resource, Debug, Clone)]
+pub struct FogGrid {
+    /// World position of the bottom-left corner of cell (0, 0)
+    pub origin: Vec2,
+    /// Side length of a cell in world units
+    pub cell_size: f32,
+    /// Number of cells along x
+    pub width: u32,
+    /// Number of cells along y
+    pub height: u32,
+    revealed: Vec<bool>,
+    /// Seconds of temporary sight left per cell
+    sight: Vec<f32>,
+    /// Cells whose visibility changed since the overlay last caught up
+    dirty: Vec<usize>,
+    full_refresh: bool,
+}
+
+impl Default for FogGrid {
+    fn default() -> Self {
+        Self::new(Vec2::splat(-3072.0), 48.0, 128, 128)
+    }
+}
+
+impl FogGrid {
+    /// Grid with every cell fogged
+    #[must_use]
+    pub fn new(origin: Vec2, cell_size: f32, width: u32, height: u32) -> Self {
+        let cells = (width * height) as usize;
+        Self {
+            origin,
+            cell_size,
+            width,
+            height,
+            revealed: vec![false; cells],
+            sight: vec![0.0; cells],
+            dirty: Vec::new(),
+            full_refresh: true,
+        }
+    }
+
+    /// Fogged grid covering a world-space rectangle
+    #[must_use]
+    pub fn covering(bounds: Rect, cell_size: f32) -> Self {
+        let cells = (bounds.size() / cell_size).ceil().as_uvec2();
+        Self::new(bounds.min, cell_size, cells.x, cells.y)
+    }
+
+    /// Number of cells
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.revealed.len()
+    }
+
+    /// Whether the grid has no cells
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.revealed.is_empty()
+    }
+
+    /// Cell containing a world position, if inside the grid
+    #[must_use]
+    pub fn world_to_cell(&self, position: Vec2) -> Option<UVec2> {
+        let local = ((position - self.origin) / self.cell_size).floor();
+        if local.x < 0.0
+            || local.y < 0.0
+            || local.x >= self.width as f32
+            || local.y >= self.height as f32
+        {
+            return None;
+        }
+        Some(local.as_uvec2())
+    }
+
+    /// World position of a cell center
+    #[must_use]
+    pub fn cell_center(&self, cell: UVec2) -> Vec2 {
+        self.origin + (cell.as_vec2() + 0.5) * self.cell_size
+    }
+
+    /// Flat index of a cell
+    #[must_use]
+    pub fn index(&self, cell: UVec2) -> usize {
+        (cell.y * self.width + cell.x) as usize
+    }
+
+    /// Cell of a flat index
+    #[must_use]
+    pub fn cell(&self, index: usize) -> UVec2 {
+        UVec2::new(index as u32 % self.width, index as u32 / self.width)
+    }
+
+    /// Whether the cell at a flat index is visible
+    #[must_use]
+    pub fn cell_visible(&self, index: usize) -> bool {
+        self.revealed[index] || self.sight[index] > 0.0
+    }
+
+    /// Whether a position was revealed for good
+    #[must_use]
+    pub fn is_revealed(&self, position: Vec2) -> bool {
+        self.world_to_cell(position)
+            .is_some_and(|cell| self.revealed[self.index(cell)])
+    }
+
+    /// Whether a position is visible now
+    #[must_use]
+    pub fn is_visible(&self, position: Vec2) -> bool {
+        self.world_to_cell(position)
+            .is_some_and(|cell| self.cell_visible(self.index(cell)))
+    }
+
+    /// Fraction (0.0..=1.0) of the map revealed for good
+    #[must_use]
+    pub fn revealed_fraction(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        self.revealed.iter().filter(|r| **r).count() as f32 / self.len() as f32
+    }
+
+    /// Indices of the cells whose centers lie within `radius` of `center`,
+    /// plus the cell holding `center`
+    fn cells_in_circle(&self, center: Vec2, radius: f32) -> Vec<usize> {
+        let mut cells: Vec<usize> = self
+            .world_to_cell(center)
+            .map(|cell| self.index(cell))
+            .into_iter()
+            .collect();
+        if self.is_empty() {
+            return cells;
+        }
+        let max = Vec2::new(self.width as f32 - 1.0, self.height as f32 - 1.0);
+        let lo = ((center - Vec2::splat(radius) - self.origin) / self.cell_size)
+            .floor()
+            .max(Vec2::ZERO);
+        let hi = ((center + Vec2::splat(radius) - self.origin) / self.cell_size)
+            .floor()
+            .min(max);
+        if hi.x < lo.x || hi.y < lo.y {
+            return cells;
+        }
+        let radius_sq = radius * radius;
+        for y in lo.y as u32..=hi.y as u32 {
+            for x in lo.x as u32..=hi.x as u32 {
+                let cell = UVec2::new(x, y);
+                if self.cell_center(cell).distance_squared(center) <= radius_sq {
+                    cells.push(self.index(cell));
+                }
+            }
+        }
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+
+    /// Reveal a circle for the rest of the run, returning how many cells
+    /// were newly revealed
+    pub fn reveal(&mut self, center: Vec2, radius: f32) -> usize {
+        let mut revealed = 0;
+        for index in self.cells_in_circle(center, radius) {
+            if self.revealed[index] {
+                continue;
+            }
+            if self.sight[index] <= 0.0 {
+                self.dirty.push(index);
+            }
+            self.revealed[index] = true;
+            revealed += 1;
+        }
+        revealed
+    }
+
+    /// Make a circle visible for `seconds`
+    pub fn reveal_for(&mut self, center: Vec2, radius: f32, seconds: f32) {
+        for index in self.cells_in_circle(center, radius) {
+            if self.sight[index] >= seconds {
+                continue;
+            }
+            if !self.cell_visible(index) {
+                self.dirty.push(index);
+            }
+            self.sight[index] = seconds;
+        }
+    }
+
+    /// Run down temporary sight
+    pub fn tick(&mut self, delta: f32) {
+        for index in 0..self.sight.len() {
+            if self.sight[index] <= 0.0 {
+                continue;
+            }
+            self.sight[index] -= delta;
+            if self.sight[index] <= 0.0 {
+                self.sight[index] = 0.0;
+                if !self.revealed[index] {
+                    self.dirty.push(index);
+                }
+            }
+        }
+    }
+
+    /// Cells whose visibility changed since the last call
+    pub fn take_dirty(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.dirty)
+    }
+
+    /// Whether the whole grid must be redrawn, clearing the flag
+    pub fn take_full_refresh(&mut self) -> bool {
+        std::mem::take(&mut self.full_refresh)
+    }
+}
+
+/// The sprite drawing fogged cells
+#[derive(Component, Debug)]
+pub struct FogOverlay;
+
+/// Run down temporary sight
+pub fn tick_fog(time: Res<Time>, mut fog: ResMut<FogGrid>) {
+    fog.tick(time.delta_secs());
+}
+
+/// Reveal the area around new or moved player segments and new cores
+///
+/// Reveals are permanent, so segments that stay put are not revisited.
+pub fn reveal_around_network(
+    config: Res<FogConfig>,
+    mut fog: ResMut<FogGrid>,
+    segments: Query<
+        (&TendrilPosition, &TendrilSegment, Option<&Faction>),
+        (
+            Without<Severed>,
+            Or<(Added<TendrilSegment>, Changed<TendrilPosition>)>,
+        ),
+    >,
+    cores: Query<(&Transform, Option<&Faction>), Added<CoreNode>>,
+) {
+    for (transform, faction) in cores.iter() {
+        if Faction::is_player(faction) {
+            fog.reveal(transform.translation.truncate(), config.core_reveal_radius);
+        }
+    }
+    for (position, segment, faction) in segments.iter() {
+        if segment.health > 0.0 && Faction::is_player(faction) {
+            fog.reveal(position.position, config.segment_reveal_radius);
+        }
+    }
+}
+
+/// Hide hostiles and pickups the player cannot see
+///
+/// Entities marked by a scout spore stay visible until the mark runs out.
+/// Network pieces are placed by their `TendrilPosition`, everything else
+/// by its `Transform`.
+pub fn hide_fogged_entities(
+    fog: Res<FogGrid>,
+    mut hideable: Query<
+        (
+            Option<&TendrilPosition>,
+            Option<&Transform>,
+            &mut Visibility,
+            Has<MinimapMark>,
+        ),
+        Or<(
+            With<Hostile>,
+            With<NutrientOrb>,
+            With<Corpse>,
+            With<NutrientNode>,
+        )>,
+    >,
+) {
+    for (tendril, transform, mut visibility, marked) in hideable.iter_mut() {
+        let Some(position) = tendril
+            .map(|t| t.position)
+            .or_else(|| transform.map(|t| t.translation.truncate()))
+        else {
+            continue;
+        };
+        let target = if marked || fog.is_visible(position) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        visibility.set_if_neq(target);
+    }
+}
+
+/// Overlay pixel of a cell; image rows run top to bottom
+fn pixel_offset(fog: &FogGrid, index: usize) -> usize {
+    let cell = fog.cell(index);
+    let row = fog.height - 1 - cell.y;
+    (row * fog.width + cell.x) as usize * 4
+}
+
+fn fog_alpha(fog: &FogGrid, index: usize, config: &FogConfig) -> u8 {
+    if fog.cell_visible(index) {
+        0
+    } else {
+        (config.opacity.clamp(0.0, 1.0) * 255.0) as u8
+    }
+}
+
+/// Keep the overlay texture in step with the grid
+///
+/// A new grid rebuilds the overlay; otherwise only changed cells are written.
+pub fn update_fog_overlay(
+    mut commands: Commands,
+    config: Res<FogConfig>,
+    mut fog: ResMut<FogGrid>,
+    mut images: ResMut<Assets<Image>>,
+    overlays: Query<(Entity, &Sprite), With<FogOverlay>>,
+) {
+    if fog.is_empty() {
+        return;
+    }
+    let existing = overlays.get_single().ok();
+    if fog.take_full_refresh() || existing.is_none() {
+        fog.take_dirty();
+        if let Some((entity, _)) = existing {
+            commands.entity(entity).despawn_recursive();
+        }
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: fog.width,
+                height: fog.height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        for index in 0..fog.len() {
+            image.data[pixel_offset(&fog, index) + 3] = fog_alpha(&fog, index, &config);
+        }
+        commands.spawn((
+            FogOverlay,
+            Sprite {
+                image: images.add(image),
+                custom_size: Some(Vec2::new(fog.width as f32, fog.height as f32) * fog.cell_size),
+                anchor: Anchor::BottomLeft,
+                ..default()
+            },
+            Transform::from_translation(fog.origin.extend(config.overlay_z)),
+        ));
+        return;
+    }
+
+    let dirty = fog.take_dirty();
+    let Some((_, sprite)) = existing else {
+        return;
+    };
+    if dirty.is_empty() {
+        return;
+    }
+    let Some(image) = images.get_mut(&sprite.image) else {
+        return;
+    };
+    for index in dirty {
+        image.data[pixel_offset(&fog, index) + 3] = fog_alpha(&fog, index, &config);
+    }
+}
+
+/// Remove the overlay when the run ends
+pub fn despawn_fog_overlay(mut commands: Commands, overlays: Query<Entity, With<FogOverlay>>) {
+    for entity in overlays.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use crate::game::collision::CollisionPlugin;
+    use crate::game::combat::CombatPlugin;
+    use crate::game::enemies::{spawn_colony, BehaviourKind, ColonyConfig, Enemy, EnemyKind};
+    use crate::game::map::{ColonySite, MapPlugin};
+    use crate::game::state::{RunSeed, RunStats};
+    use crate::GameState;
+
+    fn grid() -> FogGrid {
+        FogGrid::new(Vec2::ZERO, 10.0, 20, 20)
+    }
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunStats>()
+            .init_resource::<RunSeed>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                0.25,
+            )))
+            .add_plugins((CollisionPlugin, CombatPlugin, MapPlugin));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.insert_resource(FogGrid::new(Vec2::splat(-500.0), 50.0, 20, 20));
+        app
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy {
+                    kind: EnemyKind::Insect,
+                    behaviour: BehaviourKind::Swarm,
+                    speed: 0.0,
+                    radius: 8.0,
+                    nutrient_drop: 0.0,
+                },
+                Hostile,
+                Transform::from_translation(position.extend(0.0)),
+                Visibility::default(),
+            ))
+            .id()
+    }
+
+    fn visibility(app: &App, entity: Entity) -> Visibility {
+        *app.world().get::<Visibility>(entity).unwrap()
+    }
+
+    #[test]
+    fn test_new_grid_is_fogged() {
+        let fog = grid();
+        assert!(!fog.is_visible(Vec2::new(55.0, 55.0)));
+        assert_eq!(fog.revealed_fraction(), 0.0);
+        assert!(!fog.is_visible(Vec2::splat(-10.0)));
+    }
+
+    #[test]
+    fn test_reveal_is_permanent_and_reports_new_cells() {
+        let mut fog = grid();
+        let first = fog.reveal(Vec2::splat(100.0), 25.0);
+        assert!(first > 0);
+        assert!(fog.is_revealed(Vec2::splat(100.0)));
+        assert!(fog.is_visible(Vec2::new(115.0, 100.0)));
+        assert!(!fog.is_visible(Vec2::new(160.0, 100.0)));
+        assert_eq!(fog.reveal(Vec2::splat(100.0), 25.0), 0);
+
+        fog.tick(100.0);
+        assert!(fog.is_visible(Vec2::splat(100.0)));
+    }
+
+    #[test]
+    fn test_temporary_sight_expires() {
+        let mut fog = grid();
+        fog.reveal_for(Vec2::splat(50.0), 15.0, 2.0);
+        assert!(fog.is_visible(Vec2::splat(50.0)));
+        assert!(!fog.is_revealed(Vec2::splat(50.0)));
+        fog.tick(1.5);
+        assert!(fog.is_visible(Vec2::splat(50.0)));
+        fog.tick(1.0);
+        assert!(!fog.is_visible(Vec2::splat(50.0)));
+    }
+
+    #[test]
+    fn test_dirty_cells_track_visibility_changes() {
+        let mut fog = grid();
+        assert!(fog.take_full_refresh());
+        assert!(!fog.take_full_refresh());
+
+        fog.reveal_for(Vec2::splat(55.0), 1.0, 1.0);
+        let index = fog.index(UVec2::new(5, 5));
+        assert_eq!(fog.take_dirty(), vec![index]);
+
+        // Revealing a cell that is already visible changes nothing on screen
+        fog.reveal(Vec2::splat(55.0), 1.0);
+        assert!(fog.take_dirty().is_empty());
+        fog.tick(2.0);
+        assert!(fog.take_dirty().is_empty());
+
+        fog.reveal_for(Vec2::splat(155.0), 1.0, 1.0);
+        fog.take_dirty();
+        fog.tick(2.0);
+        assert_eq!(fog.take_dirty(), vec![fog.index(UVec2::new(15, 15))]);
+    }
+
+    #[test]
+    fn test_overlay_pixels_run_top_down() {
+        let fog = grid();
+        assert_eq!(pixel_offset(&fog, fog.index(UVec2::new(0, 19))), 0);
+        assert_eq!(
+            pixel_offset(&fog, fog.index(UVec2::new(1, 0))),
+            (19 * 20 + 1) * 4
+        );
+    }
+
+    #[test]
+    fn test_segments_reveal_and_enemies_in_fog_hide() {
+        let mut app = create_test_app();
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::ZERO, Vec2::X),
+        ));
+        let near = spawn_enemy(&mut app, Vec2::new(60.0, 0.0));
+        let far = spawn_enemy(&mut app, Vec2::new(400.0, 0.0));
+        app.update();
+
+        assert!(app.world().resource::<FogGrid>().is_revealed(Vec2::ZERO));
+        assert_eq!(visibility(&app, near), Visibility::Inherited);
+        assert_eq!(visibility(&app, far), Visibility::Hidden);
+
+        // Temporary sight shows the far enemy until it runs out
+        app.world_mut()
+            .resource_mut::<FogGrid>()
+            .reveal_for(Vec2::new(400.0, 0.0), 20.0, 0.4);
+        app.update();
+        assert_eq!(visibility(&app, far), Visibility::Inherited);
+        app.update();
+        app.update();
+        assert_eq!(visibility(&app, far), Visibility::Hidden);
+    }
+
+    #[test]
+    fn test_rival_and_severed_segments_do_not_reveal() {
+        let mut app = create_test_app();
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::ZERO, Vec2::X),
+            Faction::Rival,
+        ));
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::new(200.0, 0.0), Vec2::X),
+            Severed {
+                time_since_severance: 0.0,
+                decay_rate: 0.0,
+            },
+        ));
+        app.update();
+        assert_eq!(app.world().resource::<FogGrid>().revealed_fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_marked_enemies_stay_visible_in_fog() {
+        let mut app = create_test_app();
+        let enemy = spawn_enemy(&mut app, Vec2::new(400.0, 0.0));
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert(MinimapMark { remaining: 0.4 });
+        app.update();
+        assert_eq!(visibility(&app, enemy), Visibility::Inherited);
+
+        app.update();
+        app.update();
+        assert!(app.world().get::<MinimapMark>(enemy).is_none());
+        assert_eq!(visibility(&app, enemy), Visibility::Hidden);
+    }
+
+    #[test]
+    fn test_only_new_or_moved_segments_reveal() {
+        let mut app = create_test_app();
+        let segment = app
+            .world_mut()
+            .spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::ZERO, Vec2::X),
+            ))
+            .id();
+        app.update();
+        assert!(app.world().resource::<FogGrid>().is_revealed(Vec2::ZERO));
+
+        // A segment that stays put is not revisited
+        app.insert_resource(FogGrid::new(Vec2::splat(-500.0), 50.0, 20, 20));
+        app.update();
+        assert_eq!(app.world().resource::<FogGrid>().revealed_fraction(), 0.0);
+
+        app.world_mut()
+            .get_mut::<TendrilPosition>(segment)
+            .unwrap()
+            .position = Vec2::new(400.0, 0.0);
+        app.update();
+        let fog = app.world().resource::<FogGrid>();
+        assert!(fog.is_revealed(Vec2::new(400.0, 0.0)));
+        assert!(!fog.is_revealed(Vec2::ZERO));
+    }
+
+    #[test]
+    fn test_rival_segments_and_colonies_hide_in_fog() {
+        let mut app = create_test_app();
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::ZERO, Vec2::X),
+        ));
+        let rival_at = |app: &mut App, position: Vec2| {
+            app.world_mut()
+                .spawn((
+                    TendrilSegment::default(),
+                    TendrilPosition::new(position, Vec2::X),
+                    Faction::Rival,
+                    Hostile,
+                    Visibility::default(),
+                ))
+                .id()
+        };
+        let near_rival = rival_at(&mut app, Vec2::new(60.0, 0.0));
+        let far_rival = rival_at(&mut app, Vec2::new(400.0, 0.0));
+        let colony = {
+            let mut commands = app.world_mut().commands();
+            let colony = spawn_colony(
+                &mut commands,
+                &ColonySite {
+                    position: Vec2::new(-400.0, 0.0),
+                    radius: 100.0,
+                    major_threat: false,
+                },
+                &ColonyConfig::default(),
+            );
+            app.world_mut().flush();
+            colony
+        };
+        app.update();
+
+        assert_eq!(visibility(&app, near_rival), Visibility::Inherited);
+        assert_eq!(visibility(&app, far_rival), Visibility::Hidden);
+        assert_eq!(visibility(&app, colony), Visibility::Hidden);
+    }
+
+    #[test]
+    fn test_fog_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<FogOverlay>();
+        assert_resource::<FogGrid>();
+        assert_resource::<FogConfig>();
+    }
+}
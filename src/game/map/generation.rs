@@ -0,0 +1,549 @@
+//! Procedural map generation
+//!
+//! `generate_map` turns a seed into a `WorldDescription` without touching the
+//! ECS, so the same seed always yields the same map:
+//! - Bounds offset around the player spawn at the origin
+//! - Biome regions as Voronoi sites
+//! - Colonies, boss zones and nutrient nodes placed by rejection sampling
+//!   with spacing rules
+//! - Polygon obstacles kept clear of the spawn and every other feature
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::resources::{Biome, BossKind, BossSite, ColonySite};
+
+/// A biome region, owning every point closer to its center than any other
+#[derive(Debug, Clone, PartialEq)]
+pub struct BiomeRegion {
+    pub biome: Biome,
+    pub center: Vec2,
+}
+
+/// A polygon that blocks growth and movement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObstacleSite {
+    /// Vertices in counter-clockwise order
+    pub vertices: Vec<Vec2>,
+}
+
+impl ObstacleSite {
+    /// Center of the vertices
+    #[must_use]
+    pub fn center(&self) -> Vec2 {
+        if self.vertices.is_empty() {
+            return Vec2::ZERO;
+        }
+        self.vertices.iter().copied().sum::<Vec2>() / self.vertices.len() as f32
+    }
+
+    /// Radius of the smallest circle around `center` holding every vertex
+    #[must_use]
+    pub fn bounding_radius(&self) -> f32 {
+        let center = self.center();
+        self.vertices
+            .iter()
+            .map(|v| v.distance(center))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Where an environmental nutrient node sits
+#[derive(Debug, Clone, PartialEq)]
+pub struct NutrientNodeSite {
+    pub position: Vec2,
+    /// Nutrients held at the start of the run
+    pub reservoir: f32,
+    /// Nutrients regained per second, zero for a finite node
+    pub regeneration: f32,
+    /// Rich nodes hold more and draw enemy attention
+    pub rich: bool,
+}
+
+/// Everything a generated map contains
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct WorldDescription {
+    pub seed: u64,
+    /// Playable area
+    pub bounds: Rect,
+    /// Where the core node starts
+    pub player_spawn: Vec2,
+    pub biomes: Vec<BiomeRegion>,
+    pub obstacles: Vec<ObstacleSite>,
+    pub nutrient_nodes: Vec<NutrientNodeSite>,
+    pub colonies: Vec<ColonySite>,
+    pub bosses: Vec<BossSite>,
+}
+
+/// Tuning for map generation
+#[derive(Resource, Debug, Clone)]
+pub struct MapGenConfig {
+    /// Half size of the playable area
+    pub half_extents: Vec2,
+    /// Largest offset of the map center from the player spawn
+    pub max_spawn_offset: f32,
+    /// Features keep at least this far inside the bounds
+    pub edge_margin: f32,
+    /// Obstacles and hostiles keep at least this far from the spawn
+    pub spawn_clearance: f32,
+    /// Minimum distance between any two placed features
+    pub feature_spacing: f32,
+    /// Rejection sampling attempts per feature
+    pub placement_attempts: u32,
+    pub biome_regions: usize,
+    pub colonies: usize,
+    /// How many of the colonies are major threats
+    pub major_colonies: usize,
+    pub colony_radius: f32,
+    pub major_colony_radius: f32,
+    /// Regular colonies keep at least this far from the spawn
+    pub colony_min_distance: f32,
+    /// Bosses, one zone per kind
+    pub bosses: Vec<BossKind>,
+    pub boss_radius: f32,
+    /// Bosses and major colonies keep at least this far from the spawn
+    pub far_min_distance: f32,
+    pub nutrient_nodes: usize,
+    pub node_reservoir: f32,
+    /// Reservoir multiplier for rich nodes
+    pub rich_node_multiplier: f32,
+    /// Chance (0.0..=1.0) a node is rich
+    pub rich_node_chance: f64,
+    /// Chance (0.0..=1.0) a node regenerates
+    pub regenerating_node_chance: f64,
+    pub node_regeneration: f32,
+    pub obstacles: usize,
+    pub obstacle_min_radius: f32,
+    pub obstacle_max_radius: f32,
+    pub obstacle_min_vertices: usize,
+    pub obstacle_max_vertices: usize,
+}
+
+impl Default for MapGenConfig {
+    fn default() -> Self {
+        Self {
+            half_extents: Vec2::splat(3072.0),
+            max_spawn_offset: 800.0,
+            edge_margin: 200.0,
+            spawn_clearance: 450.0,
+            feature_spacing: 260.0,
+            placement_attempts: 64,
+            biome_regions: 14,
+            colonies: 4,
+            major_colonies: 1,
+            colony_radius: 140.0,
+            major_colony_radius: 220.0,
+            colony_min_distance: 800.0,
+            bosses: vec![BossKind::BroodMother, BossKind::Blight],
+            boss_radius: 240.0,
+            far_min_distance: 1600.0,
+            nutrient_nodes: 24,
+            node_reservoir: 80.0,
+            rich_node_multiplier: 3.0,
+            rich_node_chance: 0.2,
+            regenerating_node_chance: 0.4,
+            node_regeneration: 1.0,
+            obstacles: 36,
+            obstacle_min_radius: 50.0,
+            obstacle_max_radius: 170.0,
+            obstacle_min_vertices: 5,
+            obstacle_max_vertices: 8,
+        }
+    }
+}
+
+/// A placed feature's footprint, for spacing checks
+struct Footprint {
+    position: Vec2,
+    radius: f32,
+}
+
+/// Placement rules for one feature
+struct Placement {
+    radius: f32,
+    /// Minimum distance from the spawn
+    min_distance: f32,
+    /// Relax feature spacing, never the spawn distance, when no attempt
+    /// satisfies every rule
+    required: bool,
+}
+
+fn place(
+    rng: &mut StdRng,
+    bounds: Rect,
+    spawn: Vec2,
+    occupied: &[Footprint],
+    rules: &Placement,
+    config: &MapGenConfig,
+) -> Option<Vec2> {
+    let area = bounds.inflate(-(config.edge_margin + rules.radius));
+    if area.is_empty() {
+        return None;
+    }
+    // Slack is how far a candidate clears a rule; negative fails
+    let spawn_slack = |p: Vec2| p.distance(spawn) - rules.min_distance;
+    let spacing_slack = |p: Vec2| {
+        occupied
+            .iter()
+            .map(|f| p.distance(f.position) - f.radius - rules.radius - config.feature_spacing)
+            .fold(f32::INFINITY, f32::min)
+    };
+    let mut best: Option<(Vec2, f32)> = None;
+    for _ in 0..config.placement_attempts.max(1) {
+        let candidate = Vec2::new(
+            rng.gen_range(area.min.x..=area.max.x),
+            rng.gen_range(area.min.y..=area.max.y),
+        );
+        if spawn_slack(candidate) < 0.0 {
+            continue;
+        }
+        let score = spacing_slack(candidate);
+        if score >= 0.0 {
+            return Some(candidate);
+        }
+        if best.is_none_or(|(_, s)| score > s) {
+            best = Some((candidate, score));
+        }
+    }
+    if !rules.required {
+        return None;
+    }
+    // Required features may crowd others but never the spawn: keep the least
+    // crowded candidate, or the corner farthest from the spawn if no
+    // candidate was far enough
+    best.map(|(p, _)| p).or_else(|| {
+        let corner = [
+            area.min,
+            area.max,
+            Vec2::new(area.min.x, area.max.y),
+            Vec2::new(area.max.x, area.min.y),
+        ]
+        .into_iter()
+        .max_by(|a, b| {
+            a.distance_squared(spawn)
+                .total_cmp(&b.distance_squared(spawn))
+        })?;
+        (spawn_slack(corner) >= 0.0).then_some(corner)
+    })
+}
+
+fn obstacle_polygon(
+    rng: &mut StdRng,
+    center: Vec2,
+    radius: f32,
+    config: &MapGenConfig,
+) -> ObstacleSite {
+    let min = config.obstacle_min_vertices.max(3);
+    let count = rng.gen_range(min..=config.obstacle_max_vertices.max(min));
+    let spin = rng.gen_range(0.0..TAU);
+    let vertices = (0..count)
+        .map(|i| {
+            let angle = spin + TAU * i as f32 / count as f32;
+            let distance = radius * rng.gen_range(0.65..=1.0);
+            center + Vec2::from_angle(angle) * distance
+        })
+        .collect();
+    ObstacleSite { vertices }
+}
+
+/// Generate the map for a seed
+///
+/// Pure and deterministic: equal seeds and configs give equal descriptions.
+#[must_use]
+pub fn generate_map(seed: u64, config: &MapGenConfig) -> WorldDescription {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let player_spawn = Vec2::ZERO;
+
+    let offset = Vec2::from_angle(rng.gen_range(0.0..TAU))
+        * rng.gen_range(0.0..=config.max_spawn_offset.max(0.0));
+    let bounds = Rect::from_center_half_size(player_spawn + offset, config.half_extents);
+
+    let biomes = (0..config.biome_regions)
+        .map(|i| BiomeRegion {
+            // Cycle kinds so every biome appears on every map
+            biome: Biome::ALL[i % Biome::ALL.len()],
+            center: Vec2::new(
+                rng.gen_range(bounds.min.x..=bounds.max.x),
+                rng.gen_range(bounds.min.y..=bounds.max.y),
+            ),
+        })
+        .collect();
+
+    let mut occupied = vec![Footprint {
+        position: player_spawn,
+        radius: config.spawn_clearance,
+    }];
+
+    // Far, required features first so they get the pick of the map
+    let mut bosses = Vec::new();
+    for &kind in config.bosses.iter() {
+        let rules = Placement {
+            radius: config.boss_radius,
+            min_distance: config.far_min_distance,
+            required: true,
+        };
+        if let Some(position) = place(&mut rng, bounds, player_spawn, &occupied, &rules, config) {
+            occupied.push(Footprint {
+                position,
+                radius: config.boss_radius,
+            });
+            bosses.push(BossSite {
+                kind,
+                position,
+                radius: config.boss_radius,
+            });
+        }
+    }
+
+    let mut colonies = Vec::new();
+    for i in 0..config.colonies {
+        let major_threat = i < config.major_colonies;
+        let (radius, min_distance) = if major_threat {
+            (config.major_colony_radius, config.far_min_distance)
+        } else {
+            (config.colony_radius, config.colony_min_distance)
+        };
+        let rules = Placement {
+            radius,
+            min_distance,
+            required: major_threat,
+        };
+        if let Some(position) = place(&mut rng, bounds, player_spawn, &occupied, &rules, config) {
+            occupied.push(Footprint { position, radius });
+            colonies.push(ColonySite {
+                position,
+                radius,
+                major_threat,
+            });
+        }
+    }
+
+    // Nodes may sit close to the spawn so the opening has something to tap
+    let mut nutrient_nodes = Vec::new();
+    for _ in 0..config.nutrient_nodes {
+        let rules = Placement {
+            radius: 0.0,
+            min_distance: config.spawn_clearance * 0.5,
+            required: false,
+        };
+        let Some(position) = place(
+            &mut rng,
+            bounds,
+            player_spawn,
+            &occupied[1..],
+            &rules,
+            config,
+        ) else {
+            continue;
+        };
+        let rich = rng.gen_bool(config.rich_node_chance.clamp(0.0, 1.0));
+        let regenerates = rng.gen_bool(config.regenerating_node_chance.clamp(0.0, 1.0));
+        occupied.push(Footprint {
+            position,
+            radius: 0.0,
+        });
+        nutrient_nodes.push(NutrientNodeSite {
+            position,
+            reservoir: if rich {
+                config.node_reservoir * config.rich_node_multiplier
+            } else {
+                config.node_reservoir
+            },
+            regeneration: if regenerates {
+                config.node_regeneration
+            } else {
+                0.0
+            },
+            rich,
+        });
+    }
+
+    let mut obstacles = Vec::new();
+    for _ in 0..config.obstacles {
+        let radius = rng.gen_range(config.obstacle_min_radius..=config.obstacle_max_radius);
+        let rules = Placement {
+            radius,
+            min_distance: config.spawn_clearance + radius,
+            required: false,
+        };
+        if let Some(center) = place(&mut rng, bounds, player_spawn, &occupied, &rules, config) {
+            occupied.push(Footprint {
+                position: center,
+                radius,
+            });
+            obstacles.push(obstacle_polygon(&mut rng, center, radius, config));
+        }
+    }
+
+    WorldDescription {
+        seed,
+        bounds,
+        player_spawn,
+        biomes,
+        obstacles,
+        nutrient_nodes,
+        colonies,
+        bosses,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features(world: &WorldDescription) -> Vec<(Vec2, f32)> {
+        world
+            .colonies
+            .iter()
+            .map(|c| (c.position, c.radius))
+            .chain(world.bosses.iter().map(|b| (b.position, b.radius)))
+            .chain(world.nutrient_nodes.iter().map(|n| (n.position, 0.0)))
+            .chain(
+                world
+                    .obstacles
+                    .iter()
+                    .map(|o| (o.center(), o.bounding_radius())),
+            )
+            .collect()
+    }
+
+    #[test]
+    fn test_same_seed_same_map() {
+        let config = MapGenConfig::default();
+        assert_eq!(generate_map(1234, &config), generate_map(1234, &config));
+    }
+
+    #[test]
+    fn test_different_seeds_differ() {
+        let config = MapGenConfig::default();
+        assert_ne!(generate_map(1, &config), generate_map(2, &config));
+    }
+
+    #[test]
+    fn test_golden_seed_summary() {
+        let world = generate_map(42, &MapGenConfig::default());
+        let summary = format!(
+            "bounds {:.1} {:.1} | biomes {} | colonies {} | bosses {} | nodes {} ({} rich) | obstacles {} | boss0 {:.1}",
+            world.bounds.min,
+            world.bounds.max,
+            world.biomes.len(),
+            world.colonies.len(),
+            world.bosses.len(),
+            world.nutrient_nodes.len(),
+            world.nutrient_nodes.iter().filter(|n| n.rich).count(),
+            world.obstacles.len(),
+            world.bosses[0].position,
+        );
+        assert_eq!(summary, GOLDEN_SEED_42);
+    }
+
+    const GOLDEN_SEED_42: &str = "bounds [-2790.3, -2758.8] [3353.7, 3385.2] | biomes 14 | colonies 4 | bosses 2 | nodes 24 (3 rich) | obstacles 36 | boss0 [1957.6, -1167.6]";
+
+    #[test]
+    fn test_spawn_is_origin_inside_bounds() {
+        for seed in 0..20 {
+            let world = generate_map(seed, &MapGenConfig::default());
+            assert_eq!(world.player_spawn, Vec2::ZERO);
+            assert!(world.bounds.contains(world.player_spawn));
+        }
+    }
+
+    #[test]
+    fn test_every_map_has_bosses_and_a_major_threat() {
+        let config = MapGenConfig::default();
+        for seed in 0..20 {
+            let world = generate_map(seed, &config);
+            assert_eq!(world.bosses.len(), config.bosses.len());
+            assert_eq!(
+                world.colonies.iter().filter(|c| c.major_threat).count(),
+                config.major_colonies
+            );
+        }
+    }
+
+    #[test]
+    fn test_features_stay_inside_bounds() {
+        let config = MapGenConfig::default();
+        for seed in 0..20 {
+            let world = generate_map(seed, &config);
+            for (position, radius) in features(&world) {
+                let inner = world.bounds.inflate(-radius);
+                assert!(inner.contains(position), "seed {seed}: {position}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_hostiles_and_obstacles_keep_clear_of_spawn() {
+        let config = MapGenConfig::default();
+        for seed in 0..20 {
+            let world = generate_map(seed, &config);
+            for site in &world.bosses {
+                assert!(site.position.length() >= config.far_min_distance);
+            }
+            for site in world.colonies.iter().filter(|c| c.major_threat) {
+                assert!(site.position.length() >= config.far_min_distance);
+            }
+            for obstacle in &world.obstacles {
+                let clearance = obstacle.center().length() - obstacle.bounding_radius();
+                assert!(clearance >= config.spawn_clearance, "seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_crowded_bosses_still_keep_clear_of_spawn() {
+        // Far too many huge zones to space out, so placement falls back
+        let config = MapGenConfig {
+            bosses: vec![BossKind::Blight; 12],
+            boss_radius: 900.0,
+            ..default()
+        };
+        for seed in 0..10 {
+            let world = generate_map(seed, &config);
+            assert_eq!(world.bosses.len(), config.bosses.len());
+            for site in &world.bosses {
+                assert!(
+                    site.position.length() >= config.far_min_distance,
+                    "seed {seed}: {}",
+                    site.position
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_obstacles_do_not_cover_features() {
+        let config = MapGenConfig::default();
+        for seed in 0..20 {
+            let world = generate_map(seed, &config);
+            for obstacle in &world.obstacles {
+                let (center, radius) = (obstacle.center(), obstacle.bounding_radius());
+                for site in &world.colonies {
+                    assert!(center.distance(site.position) > radius + site.radius);
+                }
+                for node in &world.nutrient_nodes {
+                    assert!(center.distance(node.position) > radius);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_biome_appears() {
+        let world = generate_map(7, &MapGenConfig::default());
+        for biome in Biome::ALL {
+            assert!(world.biomes.iter().any(|r| r.biome == biome));
+        }
+    }
+
+    #[test]
+    fn test_map_gen_types_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<WorldDescription>();
+        assert_resource::<MapGenConfig>();
+    }
+}
@@ -1,18 +1,151 @@
 //! Map system
 //!
 //! Handles world generation and exploration:
+//! - Terrain cost grid used by pathfinding
+//! - Layout of placed features such as hostile colonies and boss zones
 //! - Procedural map generation
 //! - Biome regions
+//! - Obstacles blocking growth and movement
 //! - Fog of war
 //! - Scout spores
 
 use bevy::prelude::*;
 
+use crate::game::combat::{explosion, CombatSet};
+use crate::GameState;
+
+pub mod biome;
+pub mod fog;
+pub mod generation;
+pub mod obstacle;
+pub mod resources;
+pub mod scout;
+pub mod systems;
+
+pub use biome::{BiomeDrainTimer, BiomeMap, BiomeModifiers, BiomeTable};
+pub use fog::{FogConfig, FogGrid, FogOverlay};
+pub use generation::{
+    generate_map, BiomeRegion, MapGenConfig, NutrientNodeSite, ObstacleSite, WorldDescription,
+};
+pub use obstacle::{ObstacleConfig, ObstacleHit, ObstacleResponse, Obstacles};
+pub use resources::*;
+pub use scout::{MinimapMark, ScoutSpore, ScoutSporeStats};
+
+/// System set generating the map for a new run; systems spawning map
+/// features run after it
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MapGenSet;
+
 /// Plugin for the map system
 pub struct MapPlugin;
 
 impl Plugin for MapPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: Register map systems
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TerrainGrid>()
+            .init_resource::<MapLayout>()
+            .init_resource::<MapGenConfig>()
+            .init_resource::<WorldDescription>()
+            .init_resource::<BiomeMap>()
+            .init_resource::<BiomeTable>()
+            .init_resource::<BiomeDrainTimer>()
+            .init_resource::<Obstacles>()
+            .init_resource::<ObstacleConfig>()
+            .init_resource::<FogConfig>()
+            .init_resource::<FogGrid>()
+            // A fresh map for every new run
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                systems::generate_run_map.in_set(MapGenSet),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::GameOver,
+                    entered: GameState::Playing,
+                },
+                systems::generate_run_map.in_set(MapGenSet),
+            )
+            // Biome hazards feed this frame's damage
+            .add_systems(
+                Update,
+                biome::drain_segments_in_biomes
+                    .before(CombatSet)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Knockback slides along obstacles rather than through them
+            .add_systems(
+                Update,
+                obstacle::deflect_knockback
+                    .after(explosion::resolve_bursts)
+                    .before(explosion::apply_knockback)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Fog follows this frame's growth, movement and scouting
+            .add_systems(
+                Update,
+                (
+                    scout::move_scout_spores,
+                    scout::tick_minimap_marks,
+                    fog::tick_fog,
+                    fog::reveal_around_network,
+                    fog::hide_fogged_entities,
+                )
+                    .chain()
+                    .after(CombatSet)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                fog::update_fog_overlay
+                    .after(fog::hide_fogged_entities)
+                    .run_if(
+                        resource_exists::<Assets<Image>>
+                            .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                    ),
+            )
+            .add_systems(
+                OnEnter(GameState::Menu),
+                (fog::despawn_fog_overlay, scout::despawn_scout_spores),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (fog::despawn_fog_overlay, scout::despawn_scout_spores),
+            )
+            .add_systems(
+                Update,
+                (obstacle::render_obstacles, scout::render_scout_spores).run_if(
+                    resource_exists::<bevy::gizmos::config::GizmoConfigStore>
+                        .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    use crate::game::collision::CollisionPlugin;
+    use crate::game::combat::CombatPlugin;
+    use crate::game::state::{RunSeed, RunStats};
+
+    #[test]
+    fn test_map_plugin_builds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunSeed>()
+            .init_resource::<RunStats>()
+            .add_plugins((CollisionPlugin, CombatPlugin, MapPlugin));
+        app.update();
+        assert!(app.world().get_resource::<TerrainGrid>().is_some());
+        assert!(app.world().get_resource::<MapLayout>().is_some());
+        assert!(app.world().get_resource::<WorldDescription>().is_some());
+        assert!(app.world().get_resource::<BiomeMap>().is_some());
+        assert!(app.world().get_resource::<FogGrid>().is_some());
     }
 }
@@ -0,0 +1,481 @@
+//! Obstacles
+//!
+//! Polygons that nothing can grow or walk through:
+//! - Growth is swept along the whole new segment, so a tip cannot tunnel
+//!   through an obstacle however long its step
+//! - A blocked tip stops or slides along the obstacle edge, per
+//!   `ObstacleConfig`
+//! - Obstacles are rasterized into the `TerrainGrid` as impassable cells so
+//!   enemy pathfinding routes around them
+//! - Steered and knocked-back hostiles slide along edges they walk into,
+//!   and stop where the slide is blocked too
+
+use bevy::prelude::*;
+
+use super::generation::ObstacleSite;
+use super::resources::{TerrainGrid, IMPASSABLE};
+use crate::game::combat::{Hostile, Knockback, Static};
+
+/// What a growth tip does when its next segment would hit an obstacle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObstacleResponse {
+    /// Growth stops at the obstacle
+    Stop,
+    /// Growth turns to run along the obstacle edge
+    #[default]
+    Slide,
+}
+
+/// Tuning for obstacles
+#[derive(Resource, Debug, Clone)]
+pub struct ObstacleConfig {
+    pub response: ObstacleResponse,
+    /// Gap segments and moving hostiles keep from obstacle edges
+    pub skin: f32,
+    /// Smallest alignment (0.0..=1.0) between a tip and an edge that still
+    /// slides; tips hitting closer to head-on stop
+    pub min_slide_alignment: f32,
+}
+
+impl Default for ObstacleConfig {
+    fn default() -> Self {
+        Self {
+            response: ObstacleResponse::Slide,
+            skin: 2.0,
+            min_slide_alignment: 0.1,
+        }
+    }
+}
+
+/// Where a swept segment first touches an obstacle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObstacleHit {
+    /// Fraction (0.0..=1.0) along the segment
+    pub t: f32,
+    pub point: Vec2,
+    /// Unit direction of the edge that was hit
+    pub edge: Vec2,
+}
+
+/// An obstacle polygon with its bounding circle
+#[derive(Debug, Clone, PartialEq)]
+struct Polygon {
+    vertices: Vec<Vec2>,
+    center: Vec2,
+    radius: f32,
+}
+
+impl Polygon {
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    fn contains(&self, point: Vec2) -> bool {
+        if point.distance_squared(self.center) > self.radius * self.radius {
+            return false;
+        }
+        // Even-odd rule
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > point.y) != (b.y > point.y)
+                && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+/// Fraction along `p0..p1` where it crosses `q0..q1`, if it does
+fn segment_intersection(p0: Vec2, p1: Vec2, q0: Vec2, q1: Vec2) -> Option<f32> {
+    let r = p1 - p0;
+    let s = q1 - q0;
+    let denom = r.perp_dot(s);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+    let t = (q0 - p0).perp_dot(s) / denom;
+    let u = (q0 - p0).perp_dot(r) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then_some(t)
+}
+
+/// Distance from `point` to the segment `a..b`
+fn distance_to_segment(point: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((point - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance(a + ab * t)
+}
+
+/// Obstacles of the current map
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Obstacles {
+    polygons: Vec<Polygon>,
+}
+
+impl Obstacles {
+    #[must_use]
+    pub fn new(sites: &[ObstacleSite]) -> Self {
+        let polygons = sites
+            .iter()
+            .filter(|site| site.vertices.len() >= 3)
+            .map(|site| Polygon {
+                vertices: site.vertices.clone(),
+                center: site.center(),
+                radius: site.bounding_radius(),
+            })
+            .collect();
+        Self { polygons }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    /// Vertices of every obstacle
+    pub fn polygons(&self) -> impl Iterator<Item = &[Vec2]> {
+        self.polygons.iter().map(|p| p.vertices.as_slice())
+    }
+
+    /// Whether a point lies inside any obstacle
+    #[must_use]
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.polygons.iter().any(|p| p.contains(point))
+    }
+
+    /// First obstacle edge crossed by the segment `from..to`
+    ///
+    /// The whole segment is tested, so the result does not depend on how
+    /// long it is.
+    #[must_use]
+    pub fn first_hit(&self, from: Vec2, to: Vec2) -> Option<ObstacleHit> {
+        let half = (to - from) * 0.5;
+        let mid = from + half;
+        let reach = half.length();
+        let mut best: Option<ObstacleHit> = None;
+        for polygon in &self.polygons {
+            if mid.distance(polygon.center) > polygon.radius + reach {
+                continue;
+            }
+            if polygon.contains(from) {
+                return Some(ObstacleHit {
+                    t: 0.0,
+                    point: from,
+                    edge: Vec2::ZERO,
+                });
+            }
+            for (a, b) in polygon.edges() {
+                let Some(t) = segment_intersection(from, to, a, b) else {
+                    continue;
+                };
+                if best.is_none_or(|hit| t < hit.t) {
+                    best = Some(ObstacleHit {
+                        t,
+                        point: from.lerp(to, t),
+                        edge: (b - a).normalize_or_zero(),
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    /// Direction a tip at `from` can grow a segment of `length` in
+    ///
+    /// Returns `direction` when the way is clear, the edge direction when the
+    /// tip slides, and `None` when growth is blocked.
+    #[must_use]
+    pub fn resolve_growth(
+        &self,
+        from: Vec2,
+        direction: Vec2,
+        length: f32,
+        config: &ObstacleConfig,
+    ) -> Option<Vec2> {
+        let clear = |dir: Vec2| {
+            let reach = from + dir * (length + config.skin);
+            self.first_hit(from, reach).is_none()
+        };
+        let direction = direction.normalize_or_zero();
+        if direction == Vec2::ZERO {
+            return None;
+        }
+        let reach = from + direction * (length + config.skin);
+        let Some(hit) = self.first_hit(from, reach) else {
+            return Some(direction);
+        };
+        if config.response == ObstacleResponse::Stop {
+            return None;
+        }
+        let along = direction.dot(hit.edge);
+        if along.abs() < config.min_slide_alignment {
+            return None;
+        }
+        let slide = hit.edge * along.signum();
+        clear(slide).then_some(slide)
+    }
+
+    /// Where a body moving from `from` toward `to` ends up
+    ///
+    /// A clear move is kept. Otherwise the body stops `skin` short of the
+    /// first edge and slides along it for the rest of the move, or stays
+    /// put if the slide is blocked as well. Bodies already inside an
+    /// obstacle move freely so they can get out.
+    #[must_use]
+    pub fn slide_move(&self, from: Vec2, to: Vec2, skin: f32) -> Vec2 {
+        let Some(hit) = self.first_hit(from, to) else {
+            return to;
+        };
+        if hit.edge == Vec2::ZERO {
+            return to;
+        }
+        let step = to - from;
+        let length = step.length();
+        let stop = from + step / length * (length * hit.t - skin).max(0.0);
+        let slide_to = stop + hit.edge * (step * (1.0 - hit.t)).dot(hit.edge);
+        if self.first_hit(stop, slide_to).is_none() {
+            slide_to
+        } else {
+            stop
+        }
+    }
+
+    /// Mark every cell an obstacle touches as impassable
+    pub fn rasterize(&self, grid: &mut TerrainGrid) {
+        let half_cell = grid.cell_size * 0.5;
+        // A cell is touched when its center is inside the polygon or an edge
+        // passes within half a cell diagonal of it
+        let touch = half_cell * std::f32::consts::SQRT_2;
+        for polygon in &self.polygons {
+            let min = polygon.center - Vec2::splat(polygon.radius);
+            let max = polygon.center + Vec2::splat(polygon.radius);
+            let lo = ((min - grid.origin) / grid.cell_size)
+                .floor()
+                .max(Vec2::ZERO);
+            let hi = ((max - grid.origin) / grid.cell_size)
+                .floor()
+                .min(Vec2::new(grid.width as f32 - 1.0, grid.height as f32 - 1.0));
+            if hi.x < lo.x || hi.y < lo.y {
+                continue;
+            }
+            for y in lo.y as u32..=hi.y as u32 {
+                for x in lo.x as u32..=hi.x as u32 {
+                    let cell = UVec2::new(x, y);
+                    let center = grid.cell_center(cell);
+                    let touched = polygon.contains(center)
+                        || polygon
+                            .edges()
+                            .any(|(a, b)| distance_to_segment(center, a, b) <= touch);
+                    if touched {
+                        grid.set_cost(cell, IMPASSABLE);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Keep knocked-back hostiles, bosses included, out of obstacles
+///
+/// Knockback that would carry a hostile into an obstacle this frame is
+/// turned into the velocity that slides it along the edge instead.
+pub fn deflect_knockback(
+    time: Res<Time>,
+    obstacles: Res<Obstacles>,
+    config: Res<ObstacleConfig>,
+    mut knocked: Query<(&Transform, &mut Knockback), (With<Hostile>, Without<Static>)>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 || obstacles.is_empty() {
+        return;
+    }
+    for (transform, mut knockback) in knocked.iter_mut() {
+        let from = transform.translation.truncate();
+        let to = from + knockback.velocity * delta;
+        let reached = obstacles.slide_move(from, to, config.skin);
+        if reached != to {
+            knockback.velocity = (reached - from) / delta;
+        }
+    }
+}
+
+/// Draw obstacle outlines
+pub fn render_obstacles(mut gizmos: Gizmos, obstacles: Res<Obstacles>) {
+    for vertices in obstacles.polygons() {
+        let closed = vertices.iter().chain(vertices.first()).copied();
+        gizmos.linestrip_2d(closed, Color::srgb(0.45, 0.45, 0.5));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// A 100 x 20 wall centered on (100, 0), long side along y
+    fn wall() -> Obstacles {
+        Obstacles::new(&[ObstacleSite {
+            vertices: vec![
+                Vec2::new(90.0, -50.0),
+                Vec2::new(110.0, -50.0),
+                Vec2::new(110.0, 50.0),
+                Vec2::new(90.0, 50.0),
+            ],
+        }])
+    }
+
+    fn stop() -> ObstacleConfig {
+        ObstacleConfig {
+            response: ObstacleResponse::Stop,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_contains() {
+        let obstacles = wall();
+        assert!(obstacles.contains(Vec2::new(100.0, 0.0)));
+        assert!(!obstacles.contains(Vec2::new(80.0, 0.0)));
+        assert!(!obstacles.contains(Vec2::new(100.0, 60.0)));
+    }
+
+    #[test]
+    fn test_first_hit_finds_nearest_edge() {
+        let hit = wall().first_hit(Vec2::ZERO, Vec2::new(200.0, 0.0)).unwrap();
+        assert_eq!(hit.point, Vec2::new(90.0, 0.0));
+        assert_eq!(hit.edge.x, 0.0);
+        assert!(wall()
+            .first_hit(Vec2::ZERO, Vec2::new(0.0, 200.0))
+            .is_none());
+    }
+
+    #[test]
+    fn test_fast_tip_cannot_tunnel_through() {
+        // Both endpoints are clear, only the swept segment crosses the wall
+        let obstacles = wall();
+        let from = Vec2::new(50.0, 0.0);
+        assert!(!obstacles.contains(from + Vec2::X * 500.0));
+        assert_eq!(
+            obstacles.resolve_growth(from, Vec2::X, 500.0, &stop()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_clear_growth_keeps_direction() {
+        let direction = wall().resolve_growth(Vec2::ZERO, Vec2::Y, 16.0, &stop());
+        assert_eq!(direction, Some(Vec2::Y));
+    }
+
+    #[test]
+    fn test_stop_response_blocks_growth() {
+        let from = Vec2::new(80.0, 0.0);
+        let direction = Vec2::new(1.0, 0.5).normalize();
+        assert_eq!(wall().resolve_growth(from, direction, 16.0, &stop()), None);
+    }
+
+    #[test]
+    fn test_slide_response_follows_edge() {
+        let from = Vec2::new(80.0, 0.0);
+        let direction = Vec2::new(1.0, 0.5).normalize();
+        let slid = wall().resolve_growth(from, direction, 16.0, &ObstacleConfig::default());
+        assert_eq!(slid, Some(Vec2::Y));
+
+        let down = Vec2::new(1.0, -0.5).normalize();
+        let slid = wall().resolve_growth(from, down, 16.0, &ObstacleConfig::default());
+        assert_eq!(slid, Some(Vec2::NEG_Y));
+    }
+
+    #[test]
+    fn test_head_on_hit_stops_even_when_sliding() {
+        let from = Vec2::new(80.0, 0.0);
+        let slid = wall().resolve_growth(from, Vec2::X, 16.0, &ObstacleConfig::default());
+        assert_eq!(slid, None);
+    }
+
+    #[test]
+    fn test_slide_move_keeps_clear_moves() {
+        let to = Vec2::new(50.0, 10.0);
+        assert_eq!(wall().slide_move(Vec2::ZERO, to, 2.0), to);
+    }
+
+    #[test]
+    fn test_slide_move_slides_along_edge() {
+        let reached = wall().slide_move(Vec2::new(80.0, 0.0), Vec2::new(100.0, 20.0), 2.0);
+        // Stopped short of the wall, then carried on up along it
+        assert!(reached.x < 90.0);
+        assert!(reached.y > 15.0);
+    }
+
+    #[test]
+    fn test_slide_move_stops_head_on() {
+        let reached = wall().slide_move(Vec2::new(80.0, 0.0), Vec2::new(120.0, 0.0), 2.0);
+        assert!((reached - Vec2::new(88.0, 0.0)).length() < 1e-3);
+    }
+
+    #[test]
+    fn test_knockback_stops_at_obstacles() {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(0.25));
+        world.insert_resource(time);
+        world.insert_resource(wall());
+        world.insert_resource(ObstacleConfig::default());
+        // A boss knocked hard enough to cross the wall in one frame
+        let knocked = world
+            .spawn((
+                Hostile,
+                Knockback {
+                    velocity: Vec2::new(400.0, 0.0),
+                },
+                Transform::from_xyz(80.0, 0.0, 0.0),
+            ))
+            .id();
+        let clear = world
+            .spawn((
+                Hostile,
+                Knockback {
+                    velocity: Vec2::new(-400.0, 0.0),
+                },
+                Transform::from_xyz(80.0, 0.0, 0.0),
+            ))
+            .id();
+
+        world.run_system_once(deflect_knockback).unwrap();
+
+        let velocity = world.get::<Knockback>(knocked).unwrap().velocity;
+        assert!((80.0 + velocity.x * 0.25) < 90.0);
+        assert_eq!(
+            world.get::<Knockback>(clear).unwrap().velocity,
+            Vec2::new(-400.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_rasterize_blocks_covered_cells_only() {
+        let mut grid = TerrainGrid::new(Vec2::splat(-100.0), 10.0, 40, 40);
+        wall().rasterize(&mut grid);
+
+        let inside = grid.world_to_cell(Vec2::new(100.0, 0.0)).unwrap();
+        let edge = grid.world_to_cell(Vec2::new(91.0, 45.0)).unwrap();
+        let outside = grid.world_to_cell(Vec2::new(60.0, 0.0)).unwrap();
+        assert!(grid.is_blocked(inside));
+        assert!(grid.is_blocked(edge));
+        assert!(!grid.is_blocked(outside));
+    }
+
+    #[test]
+    fn test_obstacle_types_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<Obstacles>();
+        assert_resource::<ObstacleConfig>();
+    }
+}
@@ -0,0 +1,317 @@
+//! Map resources
+
+use bevy::prelude::*;
+
+/// Cost of a cell that cannot be crossed
+pub const IMPASSABLE: f32 = f32::INFINITY;
+
+/// Uniform grid of movement costs over the playable area
+///
+/// A cost of 1.0 is open ground, higher values are slower terrain and
+/// `IMPASSABLE` blocks movement. `version` changes whenever a cost changes so
+/// systems that cache paths know to refresh.
+#[derive(Resource, Debug, Clone)]
+pub struct TerrainGrid {
+    /// World position of the bottom-left corner of cell (0, 0)
+    pub origin: Vec2,
+    /// Side length of a cell in world units
+    pub cell_size: f32,
+    /// Number of cells along x
+    pub width: u32,
+    /// Number of cells along y
+    pub height: u32,
+    costs: Vec<f32>,
+    version: u32,
+}
+
+impl Default for TerrainGrid {
+    fn default() -> Self {
+        Self::new(Vec2::splat(-3072.0), 32.0, 192, 192)
+    }
+}
+
+impl TerrainGrid {
+    /// Open grid with every cell at cost 1.0
+    #[must_use]
+    pub fn new(origin: Vec2, cell_size: f32, width: u32, height: u32) -> Self {
+        Self {
+            origin,
+            cell_size,
+            width,
+            height,
+            costs: vec![1.0; (width * height) as usize],
+            version: 0,
+        }
+    }
+
+    /// Open grid covering a world-space rectangle
+    #[must_use]
+    pub fn covering(bounds: Rect, cell_size: f32) -> Self {
+        let cells = (bounds.size() / cell_size).ceil().as_uvec2();
+        Self::new(bounds.min, cell_size, cells.x, cells.y)
+    }
+
+    /// Number of cells
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    /// Whether the grid has no cells
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// Changes every time a cost is modified
+    #[must_use]
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Movement costs of every cell, indexed by `index`
+    #[must_use]
+    pub fn costs(&self) -> &[f32] {
+        &self.costs
+    }
+
+    /// Cell containing a world position, if inside the grid
+    #[must_use]
+    pub fn world_to_cell(&self, position: Vec2) -> Option<UVec2> {
+        let local = ((position - self.origin) / self.cell_size).floor();
+        if local.x < 0.0
+            || local.y < 0.0
+            || local.x >= self.width as f32
+            || local.y >= self.height as f32
+        {
+            return None;
+        }
+        Some(local.as_uvec2())
+    }
+
+    /// World position of a cell center
+    #[must_use]
+    pub fn cell_center(&self, cell: UVec2) -> Vec2 {
+        self.origin + (cell.as_vec2() + 0.5) * self.cell_size
+    }
+
+    /// Flat index of a cell
+    #[must_use]
+    pub fn index(&self, cell: UVec2) -> usize {
+        (cell.y * self.width + cell.x) as usize
+    }
+
+    /// Cell of a flat index
+    #[must_use]
+    pub fn cell(&self, index: usize) -> UVec2 {
+        UVec2::new(index as u32 % self.width, index as u32 / self.width)
+    }
+
+    /// Movement cost of a cell (`IMPASSABLE` outside the grid)
+    #[must_use]
+    pub fn cost(&self, cell: UVec2) -> f32 {
+        if cell.x >= self.width || cell.y >= self.height {
+            return IMPASSABLE;
+        }
+        self.costs[self.index(cell)]
+    }
+
+    /// Whether a cell blocks movement
+    #[must_use]
+    pub fn is_blocked(&self, cell: UVec2) -> bool {
+        !self.cost(cell).is_finite()
+    }
+
+    /// Set the movement cost of a cell
+    pub fn set_cost(&mut self, cell: UVec2, cost: f32) {
+        if cell.x >= self.width || cell.y >= self.height {
+            return;
+        }
+        let index = self.index(cell);
+        if self.costs[index] != cost {
+            self.costs[index] = cost;
+            self.version = self.version.wrapping_add(1);
+        }
+    }
+
+    /// Neighbouring cells in 8 directions that lie inside the grid
+    pub fn neighbours(&self, cell: UVec2) -> impl Iterator<Item = UVec2> + '_ {
+        const OFFSETS: [IVec2; 8] = [
+            IVec2::new(1, 0),
+            IVec2::new(-1, 0),
+            IVec2::new(0, 1),
+            IVec2::new(0, -1),
+            IVec2::new(1, 1),
+            IVec2::new(1, -1),
+            IVec2::new(-1, 1),
+            IVec2::new(-1, -1),
+        ];
+        let size = IVec2::new(self.width as i32, self.height as i32);
+        OFFSETS.iter().filter_map(move |offset| {
+            let next = cell.as_ivec2() + *offset;
+            (next.x >= 0 && next.y >= 0 && next.x < size.x && next.y < size.y)
+                .then(|| next.as_uvec2())
+        })
+    }
+}
+
+/// Kind of ground covering a region of the map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Biome {
+    #[default]
+    ForestFloor,
+    Rocky,
+    Swamp,
+}
+
+impl Biome {
+    pub const ALL: [Biome; 3] = [Biome::ForestFloor, Biome::Rocky, Biome::Swamp];
+}
+
+/// Where a hostile fungal colony sits at the start of a run
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColonySite {
+    pub position: Vec2,
+    /// Radius of the region the colony occupies
+    pub radius: f32,
+    /// Whether destroying it counts toward winning the run
+    pub major_threat: bool,
+}
+
+/// Kinds of boss a zone can hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BossKind {
+    /// Insect queen that floods the field with her brood
+    BroodMother,
+    /// Rotting mass that blights everything near it
+    Blight,
+}
+
+/// A fixed zone guarded by a boss
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossSite {
+    pub kind: BossKind,
+    pub position: Vec2,
+    /// Radius of the trigger zone
+    pub radius: f32,
+}
+
+/// Placement of map features for the current run
+///
+/// Map generation fills this in; other modules spawn their entities from it
+/// when a run starts.
+#[derive(Resource, Debug, Clone)]
+pub struct MapLayout {
+    pub colonies: Vec<ColonySite>,
+    pub bosses: Vec<BossSite>,
+}
+
+impl Default for MapLayout {
+    fn default() -> Self {
+        Self {
+            colonies: vec![
+                ColonySite {
+                    position: Vec2::new(900.0, 600.0),
+                    radius: 140.0,
+                    major_threat: false,
+                },
+                ColonySite {
+                    position: Vec2::new(-1100.0, -400.0),
+                    radius: 140.0,
+                    major_threat: false,
+                },
+                ColonySite {
+                    position: Vec2::new(0.0, -1800.0),
+                    radius: 220.0,
+                    major_threat: true,
+                },
+            ],
+            bosses: vec![
+                BossSite {
+                    kind: BossKind::BroodMother,
+                    position: Vec2::new(1800.0, 1800.0),
+                    radius: 260.0,
+                },
+                BossSite {
+                    kind: BossKind::Blight,
+                    position: Vec2::new(-2000.0, 1500.0),
+                    radius: 220.0,
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_grid() -> TerrainGrid {
+        TerrainGrid::new(Vec2::new(-50.0, -50.0), 10.0, 10, 10)
+    }
+
+    #[test]
+    fn test_world_to_cell_round_trip() {
+        let grid = small_grid();
+        let cell = grid.world_to_cell(Vec2::new(3.0, -7.0)).unwrap();
+        assert_eq!(cell, UVec2::new(5, 4));
+        assert_eq!(grid.cell_center(cell), Vec2::new(5.0, -5.0));
+        assert_eq!(grid.cell(grid.index(cell)), cell);
+    }
+
+    #[test]
+    fn test_covering_grid_spans_bounds() {
+        let bounds = Rect::new(-100.0, -40.0, 150.0, 60.0);
+        let grid = TerrainGrid::covering(bounds, 32.0);
+        assert_eq!(grid.origin, bounds.min);
+        assert_eq!((grid.width, grid.height), (8, 4));
+        assert!(grid.world_to_cell(bounds.max - Vec2::splat(0.1)).is_some());
+    }
+
+    #[test]
+    fn test_world_to_cell_outside_is_none() {
+        let grid = small_grid();
+        assert!(grid.world_to_cell(Vec2::new(-51.0, 0.0)).is_none());
+        assert!(grid.world_to_cell(Vec2::new(0.0, 50.0)).is_none());
+    }
+
+    #[test]
+    fn test_set_cost_bumps_version_only_on_change() {
+        let mut grid = small_grid();
+        let cell = UVec2::new(2, 3);
+        grid.set_cost(cell, IMPASSABLE);
+        assert!(grid.is_blocked(cell));
+        assert_eq!(grid.version(), 1);
+
+        grid.set_cost(cell, IMPASSABLE);
+        assert_eq!(grid.version(), 1);
+        assert!(grid.is_blocked(UVec2::new(99, 0)));
+    }
+
+    #[test]
+    fn test_neighbours_clipped_at_edges() {
+        let grid = small_grid();
+        assert_eq!(grid.neighbours(UVec2::ZERO).count(), 3);
+        assert_eq!(grid.neighbours(UVec2::new(5, 5)).count(), 8);
+    }
+
+    #[test]
+    fn test_default_layout_fits_default_terrain() {
+        let grid = TerrainGrid::default();
+        let layout = MapLayout::default();
+        assert!(layout.colonies.iter().any(|site| site.major_threat));
+        for site in &layout.colonies {
+            assert!(grid.world_to_cell(site.position).is_some());
+        }
+        for site in &layout.bosses {
+            assert!(grid.world_to_cell(site.position).is_some());
+        }
+    }
+
+    #[test]
+    fn test_map_resources_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<TerrainGrid>();
+        assert_resource::<MapLayout>();
+    }
+}
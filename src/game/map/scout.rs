@@ -0,0 +1,318 @@
+//! Scout spores
+//!
+//! Spores launched by the scout ability to look ahead of the network:
+//! - A spore flies toward its target until it has covered its range or
+//!   reaches an obstacle
+//! - Fog along its path is revealed for a limited time
+//! - Hostiles and nutrient nodes it passes are marked for a while; marked
+//!   entities stay visible through fog and on the danger sense
+//! - Spores never grow segments or claim territory
+
+use bevy::prelude::*;
+
+use super::fog::FogGrid;
+use super::obstacle::Obstacles;
+use crate::game::combat::Hostile;
+use crate::game::pickups::NutrientNode;
+
+/// How a scout spore flies and what it sees
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutSporeStats {
+    /// Flight speed in world units per second
+    pub speed: f32,
+    /// Distance flown before the spore bursts
+    pub range: f32,
+    /// Radius of fog revealed around the spore
+    pub reveal_radius: f32,
+    /// Seconds revealed cells stay visible
+    pub reveal_seconds: f32,
+    /// Radius within which entities are marked
+    pub mark_radius: f32,
+    /// Seconds a mark stays on the minimap
+    pub mark_seconds: f32,
+}
+
+impl Default for ScoutSporeStats {
+    fn default() -> Self {
+        Self {
+            speed: 420.0,
+            range: 900.0,
+            reveal_radius: 200.0,
+            reveal_seconds: 8.0,
+            mark_radius: 240.0,
+            mark_seconds: 12.0,
+        }
+    }
+}
+
+/// A spore in flight
+#[derive(Component, Debug, Clone)]
+pub struct ScoutSpore {
+    pub stats: ScoutSporeStats,
+    /// Unit direction of flight
+    pub direction: Vec2,
+    /// Distance flown so far
+    pub traveled: f32,
+}
+
+/// Keeps a scouted entity visible through fog until it runs out
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MinimapMark {
+    /// Seconds left
+    pub remaining: f32,
+}
+
+/// Launch a spore from `origin` toward `target`
+///
+/// Returns `None` when the target is on top of the origin.
+pub fn launch_scout_spore(
+    commands: &mut Commands,
+    origin: Vec2,
+    target: Vec2,
+    stats: &ScoutSporeStats,
+) -> Option<Entity> {
+    let direction = (target - origin).try_normalize()?;
+    let entity = commands
+        .spawn((
+            ScoutSpore {
+                stats: stats.clone(),
+                direction,
+                traveled: 0.0,
+            },
+            Transform::from_translation(origin.extend(1.0)),
+            Visibility::default(),
+        ))
+        .id();
+    Some(entity)
+}
+
+/// Fly spores, reveal fog around them and mark what they pass
+pub fn move_scout_spores(
+    mut commands: Commands,
+    time: Res<Time>,
+    obstacles: Res<Obstacles>,
+    mut fog: ResMut<FogGrid>,
+    mut spores: Query<(Entity, &mut ScoutSpore, &mut Transform)>,
+    markable: Query<
+        (Entity, &Transform),
+        (Or<(With<Hostile>, With<NutrientNode>)>, Without<ScoutSpore>),
+    >,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut spore, mut transform) in spores.iter_mut() {
+        let from = transform.translation.truncate();
+        let step = (spore.stats.speed * delta).min(spore.stats.range - spore.traveled);
+        let mut to = from + spore.direction * step;
+        let mut burst = spore.traveled + step >= spore.stats.range;
+        if let Some(hit) = obstacles.first_hit(from, to) {
+            to = hit.point;
+            burst = true;
+        }
+        spore.traveled += from.distance(to);
+        transform.translation = to.extend(transform.translation.z);
+
+        fog.reveal_for(to, spore.stats.reveal_radius, spore.stats.reveal_seconds);
+        let mark_sq = spore.stats.mark_radius * spore.stats.mark_radius;
+        for (seen, seen_transform) in markable.iter() {
+            if seen_transform.translation.truncate().distance_squared(to) <= mark_sq {
+                commands.entity(seen).insert(MinimapMark {
+                    remaining: spore.stats.mark_seconds,
+                });
+            }
+        }
+
+        if burst {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Run down minimap marks and drop expired ones
+pub fn tick_minimap_marks(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut marks: Query<(Entity, &mut MinimapMark)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut mark) in marks.iter_mut() {
+        mark.remaining -= delta;
+        if mark.remaining <= 0.0 {
+            commands.entity(entity).remove::<MinimapMark>();
+        }
+    }
+}
+
+/// Remove spores in flight when the run ends
+pub fn despawn_scout_spores(mut commands: Commands, spores: Query<Entity, With<ScoutSpore>>) {
+    for entity in spores.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Draw spores with a short trail
+pub fn render_scout_spores(mut gizmos: Gizmos, spores: Query<(&ScoutSpore, &Transform)>) {
+    let color = Color::srgb(0.85, 0.95, 0.7);
+    for (spore, transform) in spores.iter() {
+        let position = transform.translation.truncate();
+        gizmos.circle_2d(position, 5.0, color);
+        gizmos.line_2d(position - spore.direction * 18.0, position, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use crate::game::collision::CollisionPlugin;
+    use crate::game::combat::CombatPlugin;
+    use crate::game::enemies::{BehaviourKind, Enemy, EnemyKind};
+    use crate::game::map::{MapPlugin, ObstacleSite};
+    use crate::game::network::TendrilSegment;
+    use crate::game::state::{RunSeed, RunStats};
+    use crate::GameState;
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunStats>()
+            .init_resource::<RunSeed>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                0.25,
+            )))
+            .add_plugins((CollisionPlugin, CombatPlugin, MapPlugin));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.insert_resource(FogGrid::new(Vec2::splat(-2000.0), 50.0, 80, 80));
+        app.insert_resource(Obstacles::default());
+        app
+    }
+
+    fn launch(app: &mut App, target: Vec2, stats: ScoutSporeStats) -> Entity {
+        let mut commands = app.world_mut().commands();
+        let spore = launch_scout_spore(&mut commands, Vec2::ZERO, target, &stats).unwrap();
+        app.world_mut().flush();
+        spore
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy {
+                    kind: EnemyKind::Insect,
+                    behaviour: BehaviourKind::Swarm,
+                    speed: 0.0,
+                    radius: 8.0,
+                    nutrient_drop: 0.0,
+                },
+                Hostile,
+                Transform::from_translation(position.extend(0.0)),
+                Visibility::default(),
+            ))
+            .id()
+    }
+
+    fn stats() -> ScoutSporeStats {
+        ScoutSporeStats {
+            speed: 400.0,
+            range: 600.0,
+            reveal_radius: 60.0,
+            reveal_seconds: 2.0,
+            mark_radius: 80.0,
+            mark_seconds: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_launch_needs_a_direction() {
+        let mut world = World::new();
+        let mut commands = world.commands();
+        assert!(launch_scout_spore(&mut commands, Vec2::ONE, Vec2::ONE, &stats()).is_none());
+    }
+
+    #[test]
+    fn test_spore_flies_its_range_then_bursts() {
+        let mut app = create_test_app();
+        let spore = launch(&mut app, Vec2::new(5000.0, 0.0), stats());
+        app.update();
+        let position = app.world().get::<Transform>(spore).unwrap().translation;
+        assert_eq!(position.x, 100.0);
+
+        for _ in 0..6 {
+            app.update();
+        }
+        assert!(app.world().get_entity(spore).is_err());
+        // The path was revealed, but only for a while and only as far as
+        // the range
+        let fog = app.world().resource::<FogGrid>();
+        assert!(fog.is_visible(Vec2::new(300.0, 0.0)));
+        assert!(fog.is_visible(Vec2::new(600.0, 0.0)));
+        assert!(!fog.is_visible(Vec2::new(800.0, 0.0)));
+        assert!(!fog.is_revealed(Vec2::new(300.0, 0.0)));
+    }
+
+    #[test]
+    fn test_spore_stops_at_obstacles() {
+        let mut app = create_test_app();
+        app.insert_resource(Obstacles::new(&[ObstacleSite {
+            vertices: vec![
+                Vec2::new(150.0, -100.0),
+                Vec2::new(200.0, -100.0),
+                Vec2::new(200.0, 100.0),
+                Vec2::new(150.0, 100.0),
+            ],
+        }]));
+        let spore = launch(&mut app, Vec2::new(5000.0, 0.0), stats());
+        app.update();
+        app.update();
+        assert!(app.world().get_entity(spore).is_err());
+        assert!(!app
+            .world()
+            .resource::<FogGrid>()
+            .is_visible(Vec2::new(400.0, 0.0)));
+    }
+
+    #[test]
+    fn test_spore_marks_what_it_passes_for_a_while() {
+        let mut app = create_test_app();
+        let near = spawn_enemy(&mut app, Vec2::new(200.0, 50.0));
+        let far = spawn_enemy(&mut app, Vec2::new(200.0, 400.0));
+        launch(&mut app, Vec2::new(5000.0, 0.0), stats());
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!(app.world().get::<MinimapMark>(near).is_some());
+        assert!(app.world().get::<MinimapMark>(far).is_none());
+
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(app.world().get::<MinimapMark>(near).is_none());
+    }
+
+    #[test]
+    fn test_spore_claims_no_territory() {
+        let mut app = create_test_app();
+        launch(&mut app, Vec2::new(5000.0, 0.0), stats());
+        for _ in 0..8 {
+            app.update();
+        }
+        let mut segments = app.world_mut().query::<&TendrilSegment>();
+        assert_eq!(segments.iter(app.world()).count(), 0);
+        assert_eq!(app.world().resource::<FogGrid>().revealed_fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_scout_types_are_components() {
+        fn assert_component<T: Component>() {}
+        assert_component::<ScoutSpore>();
+        assert_component::<MinimapMark>();
+    }
+}
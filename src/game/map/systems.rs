@@ -0,0 +1,127 @@
+//! Map systems
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::biome::{BiomeDrainTimer, BiomeMap};
+use super::fog::{FogConfig, FogGrid};
+use super::generation::{generate_map, MapGenConfig};
+use super::obstacle::Obstacles;
+use super::resources::{MapLayout, TerrainGrid};
+use crate::game::state::RunSeed;
+
+/// Fix the run seed and build the map for a new run
+pub fn generate_run_map(
+    mut commands: Commands,
+    mut run_seed: ResMut<RunSeed>,
+    config: Res<MapGenConfig>,
+    terrain: Res<TerrainGrid>,
+    fog_config: Res<FogConfig>,
+) {
+    let seed = run_seed.start_run(rand::thread_rng().gen());
+    let world = generate_map(seed, &config);
+    info!("Generated map for seed {}", seed);
+
+    let obstacles = Obstacles::new(&world.obstacles);
+    let mut grid = TerrainGrid::covering(world.bounds, terrain.cell_size);
+    obstacles.rasterize(&mut grid);
+
+    commands.insert_resource(grid);
+    commands.insert_resource(FogGrid::covering(world.bounds, fog_config.cell_size));
+    commands.insert_resource(obstacles);
+    commands.insert_resource(MapLayout {
+        colonies: world.colonies.clone(),
+        bosses: world.bosses.clone(),
+    });
+    commands.insert_resource(BiomeMap::new(world.biomes.clone()));
+    commands.insert_resource(BiomeDrainTimer::default());
+    commands.insert_resource(world);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    use crate::game::collision::CollisionPlugin;
+    use crate::game::combat::CombatPlugin;
+    use crate::game::map::{MapPlugin, WorldDescription};
+    use crate::game::state::RunStats;
+    use crate::GameState;
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunSeed>()
+            .init_resource::<RunStats>()
+            .add_plugins((CollisionPlugin, CombatPlugin, MapPlugin));
+        app.update();
+        app
+    }
+
+    fn start_run(app: &mut App, from: GameState) {
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(from);
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+    }
+
+    #[test]
+    fn test_new_run_generates_map_from_requested_seed() {
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<RunSeed>().requested = Some(99);
+        start_run(&mut app, GameState::Menu);
+
+        let expected = generate_map(99, &MapGenConfig::default());
+        assert_eq!(app.world().resource::<RunSeed>().seed, 99);
+        assert_eq!(*app.world().resource::<WorldDescription>(), expected);
+
+        let layout = app.world().resource::<MapLayout>();
+        assert_eq!(layout.colonies, expected.colonies);
+        assert_eq!(layout.bosses, expected.bosses);
+        let grid = app.world().resource::<TerrainGrid>();
+        assert_eq!(grid.origin, expected.bounds.min);
+        let biomes = app.world().resource::<BiomeMap>();
+        assert_eq!(biomes.regions(), expected.biomes.as_slice());
+        let fog = app.world().resource::<FogGrid>();
+        assert_eq!(fog.origin, expected.bounds.min);
+        assert!(!fog.is_revealed(expected.player_spawn));
+
+        // Obstacles block growth and the cells enemies walk on
+        let obstacles = app.world().resource::<Obstacles>();
+        let center = expected.obstacles[0].center();
+        assert!(obstacles.contains(center));
+        let cell = grid.world_to_cell(center).unwrap();
+        assert!(grid.is_blocked(cell));
+    }
+
+    #[test]
+    fn test_restart_after_game_over_rolls_a_new_map() {
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<RunSeed>().requested = Some(5);
+        start_run(&mut app, GameState::Menu);
+        let first = app.world().resource::<WorldDescription>().clone();
+
+        app.world_mut().resource_mut::<RunSeed>().requested = Some(6);
+        start_run(&mut app, GameState::GameOver);
+        let second = app.world().resource::<WorldDescription>();
+        assert_eq!(second.seed, 6);
+        assert_ne!(*second, first);
+    }
+
+    #[test]
+    fn test_unpausing_keeps_the_map() {
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<RunSeed>().requested = Some(5);
+        start_run(&mut app, GameState::Menu);
+        start_run(&mut app, GameState::Paused);
+        assert_eq!(app.world().resource::<RunSeed>().seed, 5);
+        assert_eq!(app.world().resource::<WorldDescription>().seed, 5);
+    }
+}
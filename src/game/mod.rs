@@ -1,17 +1,22 @@
 //! Game systems module
 //!
 //! Contains all gameplay-related systems organized by domain.
+//! Shared helpers such as the spatial grid live alongside the domain modules.
 
 use bevy::prelude::*;
 
+pub mod boss;
 pub mod camera;
+pub mod collision;
 pub mod combat;
 pub mod debug;
 pub mod enemies;
 pub mod input;
 pub mod map;
 pub mod network;
+pub mod pickups;
 pub mod progression;
+pub mod spatial;
 pub mod state;
 pub mod ui;
 
@@ -24,9 +29,12 @@ impl Plugin for GameSystemsPlugin {
             state::StatePlugin,
             input::InputPlugin,
             network::NetworkPlugin,
+            collision::CollisionPlugin,
             combat::CombatPlugin,
             enemies::EnemiesPlugin,
+            boss::BossPlugin,
             progression::ProgressionPlugin,
+            pickups::PickupsPlugin,
             map::MapPlugin,
             ui::UiPlugin,
             camera::CameraPlugin,
@@ -0,0 +1,352 @@
+//! Network abilities
+//!
+//! Abilities are data in `AbilityTable`:
+//! - Each definition has a nutrient cost, a cooldown and an effect
+//! - `UseAbility` requests are paid for and put on cooldown, then their
+//!   effect is launched from the part of the network nearest the target
+//! - Scout spores fly toward the cursor to see through fog
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::components::{CoreNode, Faction, Severed, TendrilPosition, TendrilSegment};
+use crate::game::input::{CursorWorldPosition, InputActions};
+use crate::game::map::scout::{launch_scout_spore, ScoutSporeStats};
+use crate::game::progression::{
+    try_spend_nutrients, NutrientPurpose, NutrientSpendFailed, Nutrients, NutrientsSpent,
+};
+
+/// Abilities the network can use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbilityKind {
+    ScoutSpores,
+}
+
+/// What using an ability does
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbilityEffect {
+    /// Launch a scout spore toward the target
+    ScoutSpore(ScoutSporeStats),
+}
+
+/// Cost, cooldown and effect of an ability
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityDefinition {
+    pub name: String,
+    /// Nutrients spent per use
+    pub cost: f32,
+    /// Seconds before the ability can be used again
+    pub cooldown: f32,
+    pub effect: AbilityEffect,
+}
+
+/// Ability definitions by kind
+#[derive(Resource, Debug, Clone)]
+pub struct AbilityTable {
+    definitions: HashMap<AbilityKind, AbilityDefinition>,
+}
+
+impl AbilityTable {
+    #[must_use]
+    pub fn get(&self, kind: AbilityKind) -> Option<&AbilityDefinition> {
+        self.definitions.get(&kind)
+    }
+
+    pub fn get_mut(&mut self, kind: AbilityKind) -> Option<&mut AbilityDefinition> {
+        self.definitions.get_mut(&kind)
+    }
+
+    pub fn set(&mut self, kind: AbilityKind, definition: AbilityDefinition) {
+        self.definitions.insert(kind, definition);
+    }
+}
+
+impl Default for AbilityTable {
+    fn default() -> Self {
+        let mut definitions = HashMap::new();
+        definitions.insert(
+            AbilityKind::ScoutSpores,
+            AbilityDefinition {
+                name: "Scout Spores".to_string(),
+                cost: 15.0,
+                cooldown: 8.0,
+                effect: AbilityEffect::ScoutSpore(ScoutSporeStats::default()),
+            },
+        );
+        Self { definitions }
+    }
+}
+
+/// Seconds left before each ability can be used again
+#[derive(Resource, Debug, Default, Clone)]
+pub struct AbilityCooldowns(HashMap<AbilityKind, f32>);
+
+impl AbilityCooldowns {
+    #[must_use]
+    pub fn remaining(&self, kind: AbilityKind) -> f32 {
+        self.0.get(&kind).copied().unwrap_or(0.0)
+    }
+
+    #[must_use]
+    pub fn is_ready(&self, kind: AbilityKind) -> bool {
+        self.remaining(kind) <= 0.0
+    }
+
+    pub fn start(&mut self, kind: AbilityKind, seconds: f32) {
+        self.0.insert(kind, seconds);
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        for remaining in self.0.values_mut() {
+            *remaining = (*remaining - delta).max(0.0);
+        }
+    }
+}
+
+/// Request to use an ability aimed at a world position
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct UseAbility {
+    pub kind: AbilityKind,
+    pub target: Vec2,
+}
+
+impl UseAbility {
+    #[must_use]
+    pub fn new(kind: AbilityKind, target: Vec2) -> Self {
+        Self { kind, target }
+    }
+}
+
+/// Make every ability ready when a new run starts
+pub fn reset_ability_cooldowns(mut cooldowns: ResMut<AbilityCooldowns>) {
+    *cooldowns = AbilityCooldowns::default();
+}
+
+/// Run down ability cooldowns
+pub fn tick_ability_cooldowns(time: Res<Time>, mut cooldowns: ResMut<AbilityCooldowns>) {
+    cooldowns.tick(time.delta_secs());
+}
+
+/// Turn the ability key into a scout spore aimed at the cursor
+pub fn read_ability_input(
+    input: Res<InputActions>,
+    cursor: Res<CursorWorldPosition>,
+    mut uses: EventWriter<UseAbility>,
+) {
+    if !input.ability_just_pressed {
+        return;
+    }
+    if let Some(target) = cursor.position {
+        uses.send(UseAbility::new(AbilityKind::ScoutSpores, target));
+    }
+}
+
+/// Pay for requested abilities and launch their effects
+pub fn use_abilities(
+    mut commands: Commands,
+    table: Res<AbilityTable>,
+    mut cooldowns: ResMut<AbilityCooldowns>,
+    mut uses: EventReader<UseAbility>,
+    segments: Query<(&TendrilPosition, &TendrilSegment, Option<&Faction>), Without<Severed>>,
+    cores: Query<(&Transform, Option<&Faction>), With<CoreNode>>,
+    mut nutrients: ResMut<Nutrients>,
+    mut spent: EventWriter<NutrientsSpent>,
+    mut failed: EventWriter<NutrientSpendFailed>,
+) {
+    for request in uses.read() {
+        let Some(definition) = table.get(request.kind) else {
+            continue;
+        };
+        if !cooldowns.is_ready(request.kind) {
+            continue;
+        }
+        // Launch from the live part of the network closest to the target
+        let origin = segments
+            .iter()
+            .filter(|(_, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+            .map(|(position, _, _)| position.position)
+            .chain(
+                cores
+                    .iter()
+                    .filter(|(_, faction)| Faction::is_player(*faction))
+                    .map(|(transform, _)| transform.translation.truncate()),
+            )
+            .min_by(|a, b| {
+                a.distance_squared(request.target)
+                    .total_cmp(&b.distance_squared(request.target))
+            });
+        let Some(origin) = origin else {
+            continue;
+        };
+        if origin == request.target {
+            continue;
+        }
+        if !try_spend_nutrients(
+            definition.cost,
+            NutrientPurpose::Ability,
+            &mut nutrients,
+            &mut spent,
+            &mut failed,
+        ) {
+            continue;
+        }
+        cooldowns.start(request.kind, definition.cooldown);
+
+        match &definition.effect {
+            AbilityEffect::ScoutSpore(stats) => {
+                launch_scout_spore(&mut commands, origin, request.target, stats);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::game::map::scout::ScoutSpore;
+    use crate::game::network::test_utils::create_test_app;
+    use crate::GameState;
+    use bevy::time::TimeUpdateStrategy;
+
+    fn create_ability_app() -> App {
+        let mut app = create_test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            0.25,
+        )));
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.world_mut().resource_mut::<Nutrients>().current = 100.0;
+        app
+    }
+
+    fn press_ability(app: &mut App, target: Vec2) {
+        app.world_mut()
+            .resource_mut::<CursorWorldPosition>()
+            .position = Some(target);
+        app.world_mut()
+            .resource_mut::<InputActions>()
+            .ability_just_pressed = true;
+        app.update();
+        app.world_mut()
+            .resource_mut::<InputActions>()
+            .ability_just_pressed = false;
+    }
+
+    fn spores(app: &mut App) -> Vec<ScoutSpore> {
+        let mut query = app.world_mut().query::<&ScoutSpore>();
+        query.iter(app.world()).cloned().collect()
+    }
+
+    fn cost() -> f32 {
+        AbilityTable::default()
+            .get(AbilityKind::ScoutSpores)
+            .unwrap()
+            .cost
+    }
+
+    #[test]
+    fn test_default_table_defines_scout_spores() {
+        let table = AbilityTable::default();
+        let scout = table.get(AbilityKind::ScoutSpores).unwrap();
+        assert!(scout.cost > 0.0);
+        assert!(scout.cooldown > 0.0);
+        assert!(matches!(scout.effect, AbilityEffect::ScoutSpore(_)));
+    }
+
+    #[test]
+    fn test_cooldowns_tick_to_ready() {
+        let mut cooldowns = AbilityCooldowns::default();
+        assert!(cooldowns.is_ready(AbilityKind::ScoutSpores));
+        cooldowns.start(AbilityKind::ScoutSpores, 1.0);
+        assert!(!cooldowns.is_ready(AbilityKind::ScoutSpores));
+        cooldowns.tick(0.75);
+        assert_eq!(cooldowns.remaining(AbilityKind::ScoutSpores), 0.25);
+        cooldowns.tick(0.75);
+        assert!(cooldowns.is_ready(AbilityKind::ScoutSpores));
+    }
+
+    #[test]
+    fn test_ability_key_launches_spore_from_nearest_network_part() {
+        let mut app = create_ability_app();
+        app.world_mut().spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(Vec2::new(300.0, 0.0), Vec2::X),
+        ));
+        press_ability(&mut app, Vec2::new(600.0, 400.0));
+
+        let launched = spores(&mut app);
+        assert_eq!(launched.len(), 1);
+        assert!(launched[0].direction.abs_diff_eq(Vec2::new(0.6, 0.8), 1e-6));
+        assert_eq!(app.world().resource::<Nutrients>().current, 100.0 - cost());
+
+        // Scouting grows nothing
+        let mut segments = app.world_mut().query::<&TendrilSegment>();
+        assert_eq!(segments.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_cooldown_blocks_reuse_until_it_ends() {
+        let mut app = create_ability_app();
+        let cooldown = AbilityTable::default()
+            .get(AbilityKind::ScoutSpores)
+            .unwrap()
+            .cooldown;
+        press_ability(&mut app, Vec2::new(100.0, 0.0));
+        press_ability(&mut app, Vec2::new(100.0, 0.0));
+        assert_eq!(spores(&mut app).len(), 1);
+        assert_eq!(app.world().resource::<Nutrients>().current, 100.0 - cost());
+
+        for _ in 0..(cooldown / 0.25) as usize {
+            app.update();
+        }
+        press_ability(&mut app, Vec2::new(100.0, 0.0));
+        assert_eq!(spores(&mut app).len(), 2);
+    }
+
+    #[test]
+    fn test_unaffordable_ability_does_nothing() {
+        let mut app = create_ability_app();
+        app.world_mut().resource_mut::<Nutrients>().current = cost() - 1.0;
+        press_ability(&mut app, Vec2::new(100.0, 0.0));
+        assert!(spores(&mut app).is_empty());
+        assert!(app
+            .world()
+            .resource::<AbilityCooldowns>()
+            .is_ready(AbilityKind::ScoutSpores));
+    }
+
+    #[test]
+    fn test_cooldowns_reset_when_a_new_run_starts() {
+        let mut app = create_ability_app();
+        press_ability(&mut app, Vec2::new(100.0, 0.0));
+        assert!(!app
+            .world()
+            .resource::<AbilityCooldowns>()
+            .is_ready(AbilityKind::ScoutSpores));
+
+        for state in [GameState::GameOver, GameState::Playing] {
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(state);
+            app.update();
+        }
+        assert!(app
+            .world()
+            .resource::<AbilityCooldowns>()
+            .is_ready(AbilityKind::ScoutSpores));
+    }
+
+    #[test]
+    fn test_ability_types_are_registered() {
+        fn assert_resource<T: Resource>() {}
+        fn assert_event<T: Event>() {}
+        assert_resource::<AbilityTable>();
+        assert_resource::<AbilityCooldowns>();
+        assert_event::<UseAbility>();
+    }
+}
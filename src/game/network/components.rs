@@ -43,6 +43,31 @@ impl Health {
 #[derive(Component, Debug, Default)]
 pub struct NetworkMember;
 
+/// Which network a core or segment belongs to
+///
+/// Entities without a `Faction` belong to the player.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Faction {
+    #[default]
+    Player,
+    /// The AI-controlled rival fungus
+    Rival,
+}
+
+impl Faction {
+    /// Faction of an entity, treating a missing component as the player
+    #[must_use]
+    pub fn of(faction: Option<&Faction>) -> Self {
+        faction.copied().unwrap_or_default()
+    }
+
+    /// Whether an entity belongs to the player's network
+    #[must_use]
+    pub fn is_player(faction: Option<&Faction>) -> bool {
+        Self::of(faction) == Faction::Player
+    }
+}
+
 /// Visual configuration for network entities
 #[derive(Component, Debug)]
 pub struct NetworkVisuals {
@@ -335,6 +360,20 @@ mod tests {
         assert_component::<NetworkChildren>();
     }
 
+    // Faction tests
+    #[test]
+    fn test_missing_faction_is_player() {
+        assert_eq!(Faction::of(None), Faction::Player);
+        assert!(Faction::is_player(None));
+        assert!(!Faction::is_player(Some(&Faction::Rival)));
+    }
+
+    #[test]
+    fn test_faction_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<Faction>();
+    }
+
     // GrowthTip tests
     #[test]
     fn test_growth_tip_default_not_selected() {
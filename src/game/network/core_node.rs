@@ -7,7 +7,7 @@
 use bevy::prelude::*;
 
 use crate::{
-    game::network::{CoreNode, CoreNodeEntity, Health, NetworkMember, NetworkVisuals},
+    game::network::{CoreNode, CoreNodeEntity, Faction, Health, NetworkMember, NetworkVisuals},
     GameState,
 };
 
@@ -22,6 +22,7 @@ pub fn spawn_core_node(mut commands: Commands) {
         .spawn((
             CoreNode,
             NetworkMember,
+            Faction::Player,
             Health::new(CORE_NODE_HEALTH),
             Sprite {
                 color: visuals.base_color,
@@ -79,7 +80,7 @@ mod tests {
         let core_count = app
             .world_mut()
             .query_filtered::<Entity, With<CoreNode>>()
-            .iter(&app.world())
+            .iter(app.world())
             .count();
         assert_eq!(core_count, 1);
     }
@@ -192,7 +193,7 @@ mod tests {
         let core_count = app
             .world_mut()
             .query_filtered::<Entity, With<CoreNode>>()
-            .iter(&app.world())
+            .iter(app.world())
             .count();
         assert_eq!(core_count, 0);
     }
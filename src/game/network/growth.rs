@@ -1,4 +1,9 @@
-//! Growth tip selection and control systems.
+//! Growth tip selection, control and segment growth.
+//!
+//! Holding the primary action grows the active tip toward the cursor, one
+//! segment per `NetworkConfig::growth_interval`. Each segment costs
+//! `NutrientCosts::growth_cost` scaled by the biome it is grown into.
+//! Obstacles stop the tip or turn it along their edge.
 //!
 //! # Configuration
 //! - `TIP_SELECTION_RADIUS`: click detection radius
@@ -7,11 +12,62 @@ use bevy::prelude::*;
 
 use crate::game::{
     input::{CursorWorldPosition, InputActions},
-    network::{ActiveGrowthTip, GrowthTip, TendrilPosition},
+    map::{BiomeMap, BiomeTable, ObstacleConfig, Obstacles},
+    network::{
+        ActiveGrowthTip, Faction, GrowthCooldown, GrowthTip, NetworkChildren, NetworkConfig,
+        NetworkMember, NetworkParent, TendrilPosition, TendrilSegment, TendrilStyle, TendrilType,
+    },
+    progression::{
+        try_spend_nutrients, NutrientCosts, NutrientPurpose, NutrientSpendFailed, Nutrients,
+        NutrientsSpent,
+    },
 };
 
 const TIP_SELECTION_RADIUS: f32 = 12.0;
 
+/// Spawn a new segment `length` units from `from` along `direction`
+///
+/// The segment becomes a growth tip attached to `parent` and is registered
+/// in the parent's `NetworkChildren`. Callers decide whether the parent
+/// stays a tip.
+pub fn grow_segment(
+    commands: &mut Commands,
+    parent: Entity,
+    from: Vec2,
+    direction: Vec2,
+    length: f32,
+    tendril_type: TendrilType,
+    faction: Faction,
+) -> Entity {
+    let direction = direction.normalize_or_zero();
+    let child = commands
+        .spawn((
+            TendrilSegment {
+                tendril_type,
+                ..default()
+            },
+            TendrilPosition::new(from + direction * length, direction),
+            TendrilStyle::for_type(tendril_type),
+            NetworkParent(parent),
+            NetworkChildren::default(),
+            NetworkMember,
+            GrowthTip::default(),
+            faction,
+        ))
+        .id();
+
+    commands
+        .entity(parent)
+        .queue(move |mut entity: EntityWorldMut| {
+            if let Some(mut children) = entity.get_mut::<NetworkChildren>() {
+                children.add_child(child);
+            } else {
+                entity.insert(NetworkChildren(vec![child]));
+            }
+        });
+    child
+}
+
 pub fn is_cursor_near_tip(cursor_pos: Vec2, tip_pos: Vec2, radius: f32) -> bool {
     tip_pos.distance_squared(cursor_pos) <= radius * radius
 }
@@ -22,7 +78,7 @@ pub fn select_growth_tip(
     input: Res<InputActions>,
     cursor_position: Res<CursorWorldPosition>,
     mut active_tip: ResMut<ActiveGrowthTip>,
-    mut tips_query: Query<(Entity, &TendrilPosition, &mut GrowthTip)>,
+    mut tips_query: Query<(Entity, &TendrilPosition, &mut GrowthTip, Option<&Faction>)>,
 ) {
     if !input.primary_just_pressed {
         return;
@@ -34,24 +90,25 @@ pub fn select_growth_tip(
 
     // Deselect old tip first (if it exists and hasn't been despawned)
     if let Some(old_entity) = active_tip.0 {
-        if let Ok((_entity, _pos, mut old_tip)) = tips_query.get_mut(old_entity) {
+        if let Ok((_entity, _pos, mut old_tip, _faction)) = tips_query.get_mut(old_entity) {
             old_tip.selected = false;
         }
     }
 
     let closest_tip = tips_query
         .iter_mut()
-        .filter(|(_entity, pos, _tip)| {
-            is_cursor_near_tip(cursor, pos.position, TIP_SELECTION_RADIUS)
+        .filter(|(_entity, pos, _tip, faction)| {
+            Faction::is_player(*faction)
+                && is_cursor_near_tip(cursor, pos.position, TIP_SELECTION_RADIUS)
         })
-        .min_by(|(_, pos_a, _), (_, pos_b, _)| {
+        .min_by(|(_, pos_a, _, _), (_, pos_b, _, _)| {
             let dist_a = cursor.distance_squared(pos_a.position);
             let dist_b = cursor.distance_squared(pos_b.position);
             dist_a.total_cmp(&dist_b)
         });
 
     match closest_tip {
-        Some((entity, _pos, mut tip)) => {
+        Some((entity, _pos, mut tip, _faction)) => {
             tip.selected = true;
             active_tip.0 = Some(entity);
         }
@@ -77,16 +134,89 @@ pub fn update_selected_tip_direction(
     tip_pos.direction = (cursor - tip_pos.position).normalize_or_zero();
 }
 
+/// Grow the active tip one segment toward the cursor while primary is held
+///
+/// The new segment becomes the active tip. Growth pauses once the tip
+/// reaches the cursor, runs into an obstacle it cannot slide along, or when
+/// nutrients run out.
+pub fn grow_active_tip(
+    mut commands: Commands,
+    time: Res<Time>,
+    input: Res<InputActions>,
+    cursor_position: Res<CursorWorldPosition>,
+    config: Res<NetworkConfig>,
+    costs: Res<NutrientCosts>,
+    (biome_map, biomes): (Res<BiomeMap>, Res<BiomeTable>),
+    (obstacles, obstacle_config): (Res<Obstacles>, Res<ObstacleConfig>),
+    mut cooldown: ResMut<GrowthCooldown>,
+    mut active_tip: ResMut<ActiveGrowthTip>,
+    tips: Query<(&TendrilPosition, Option<&TendrilSegment>), With<GrowthTip>>,
+    mut nutrients: ResMut<Nutrients>,
+    mut spent: EventWriter<NutrientsSpent>,
+    mut failed: EventWriter<NutrientSpendFailed>,
+) {
+    cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
+    if !input.primary_held || cooldown.0 > 0.0 {
+        return;
+    }
+    let (Some(cursor), Some(tip)) = (cursor_position.position, active_tip.0) else {
+        return;
+    };
+    let Ok((tip_pos, segment)) = tips.get(tip) else {
+        return;
+    };
+    if tip_pos.position.distance(cursor) < config.segment_length {
+        return;
+    }
+
+    let Some(direction) = obstacles.resolve_growth(
+        tip_pos.position,
+        cursor - tip_pos.position,
+        config.segment_length,
+        &obstacle_config,
+    ) else {
+        return;
+    };
+    let target = tip_pos.position + direction * config.segment_length;
+    let cost = costs.growth_cost * biomes.growth_cost_multiplier(&biome_map, target);
+    cooldown.0 = config.growth_interval;
+    if !try_spend_nutrients(
+        cost,
+        NutrientPurpose::Growth,
+        &mut nutrients,
+        &mut spent,
+        &mut failed,
+    ) {
+        return;
+    }
+
+    let tendril_type = segment.map_or_else(TendrilType::default, |s| s.tendril_type);
+    let child = grow_segment(
+        &mut commands,
+        tip,
+        tip_pos.position,
+        direction,
+        config.segment_length,
+        tendril_type,
+        Faction::Player,
+    );
+    commands.entity(tip).remove::<GrowthTip>();
+    commands.entity(child).insert(GrowthTip { selected: true });
+    active_tip.0 = Some(child);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         game::{
             input::{CursorWorldPosition, InputActions},
+            map::{Biome, BiomeRegion, ObstacleResponse, ObstacleSite},
             network::test_utils::create_test_app,
         },
         GameState,
     };
+    use bevy::ecs::system::RunSystemOnce;
 
     #[test]
     /// Spawn tip, select it: tip selected.
@@ -609,4 +739,206 @@ mod tests {
             10.0
         ));
     }
+
+    #[test]
+    /// Grow from a parent: child is a tip registered with the parent.
+    fn test_grow_segment_links_child_to_parent() {
+        let mut app = create_test_app();
+        let parent = app
+            .world_mut()
+            .spawn(TendrilPosition::new(Vec2::ZERO, Vec2::X))
+            .id();
+
+        let child = app
+            .world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                grow_segment(
+                    &mut commands,
+                    parent,
+                    Vec2::ZERO,
+                    Vec2::new(0.0, 2.0),
+                    10.0,
+                    TendrilType::Toxic,
+                    Faction::Rival,
+                )
+            })
+            .unwrap();
+
+        let world = app.world();
+        let pos = world.get::<TendrilPosition>(child).unwrap();
+        assert_eq!(pos.position, Vec2::new(0.0, 10.0));
+        assert_eq!(pos.direction, Vec2::Y);
+        assert_eq!(world.get::<NetworkParent>(child).unwrap().0, parent);
+        assert!(world.get::<GrowthTip>(child).is_some());
+        assert_eq!(
+            world.get::<TendrilSegment>(child).unwrap().tendril_type,
+            TendrilType::Toxic
+        );
+        assert_eq!(world.get::<Faction>(child), Some(&Faction::Rival));
+        assert!(world
+            .get::<NetworkChildren>(parent)
+            .unwrap()
+            .0
+            .contains(&child));
+    }
+
+    #[test]
+    /// Click a rival tip: it cannot be selected.
+    fn test_rival_tips_cannot_be_selected() {
+        let mut app = create_test_app();
+        app.update();
+
+        let tip_entity = app
+            .world_mut()
+            .spawn((
+                GrowthTip::default(),
+                TendrilPosition::new(Vec2::ZERO, Vec2::X),
+                Faction::Rival,
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<CursorWorldPosition>()
+            .position = Some(Vec2::ZERO);
+        app.world_mut()
+            .resource_mut::<InputActions>()
+            .primary_just_pressed = true;
+
+        app.world_mut().run_system_once(select_growth_tip).unwrap();
+
+        assert!(!app.world().get::<GrowthTip>(tip_entity).unwrap().selected);
+        assert!(app.world().resource::<ActiveGrowthTip>().0.is_none());
+    }
+
+    fn setup_growth(app: &mut App, tip_at: Vec2, cursor: Vec2) -> Entity {
+        let tip = app
+            .world_mut()
+            .spawn((
+                GrowthTip { selected: true },
+                TendrilSegment::default(),
+                TendrilPosition::new(tip_at, Vec2::X),
+                Faction::Player,
+            ))
+            .id();
+        app.world_mut().resource_mut::<ActiveGrowthTip>().0 = Some(tip);
+        app.world_mut()
+            .resource_mut::<CursorWorldPosition>()
+            .position = Some(cursor);
+        app.world_mut().resource_mut::<InputActions>().primary_held = true;
+        app.world_mut().resource_mut::<Nutrients>().current = 50.0;
+        tip
+    }
+
+    fn nutrients(app: &App) -> f32 {
+        app.world().resource::<Nutrients>().current
+    }
+
+    #[test]
+    /// Hold primary with the cursor away from the tip: a paid segment grows.
+    fn test_holding_primary_grows_active_tip_toward_cursor() {
+        let mut app = create_test_app();
+        let tip = setup_growth(&mut app, Vec2::ZERO, Vec2::new(0.0, 100.0));
+
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+
+        let child = app.world().resource::<ActiveGrowthTip>().0.unwrap();
+        assert_ne!(child, tip);
+        let world = app.world();
+        let length = NetworkConfig::default().segment_length;
+        assert_eq!(
+            world.get::<TendrilPosition>(child).unwrap().position,
+            Vec2::new(0.0, length)
+        );
+        assert!(world.get::<GrowthTip>(child).unwrap().selected);
+        assert!(world.get::<GrowthTip>(tip).is_none());
+        assert_eq!(world.get::<NetworkParent>(child).unwrap().0, tip);
+        assert_eq!(nutrients(&app), 50.0 - NutrientCosts::default().growth_cost);
+    }
+
+    #[test]
+    /// Growth cost follows the biome the segment is grown into.
+    fn test_growth_cost_scales_with_biome() {
+        let mut app = create_test_app();
+        app.insert_resource(BiomeMap::new(vec![BiomeRegion {
+            biome: Biome::Rocky,
+            center: Vec2::ZERO,
+        }]));
+        setup_growth(&mut app, Vec2::ZERO, Vec2::new(100.0, 0.0));
+
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+
+        let multiplier = BiomeTable::default()
+            .get(Biome::Rocky)
+            .unwrap()
+            .growth_cost_multiplier;
+        assert_eq!(
+            nutrients(&app),
+            50.0 - NutrientCosts::default().growth_cost * multiplier
+        );
+    }
+
+    #[test]
+    /// Without enough nutrients the tip does not grow.
+    fn test_no_growth_without_nutrients() {
+        let mut app = create_test_app();
+        let tip = setup_growth(&mut app, Vec2::ZERO, Vec2::new(100.0, 0.0));
+        app.world_mut().resource_mut::<Nutrients>().current = 1.0;
+
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+
+        assert_eq!(app.world().resource::<ActiveGrowthTip>().0, Some(tip));
+        assert_eq!(nutrients(&app), 1.0);
+    }
+
+    #[test]
+    /// Tip already at the cursor, or primary released: nothing grows.
+    fn test_no_growth_at_cursor_or_when_released() {
+        let mut app = create_test_app();
+        let tip = setup_growth(&mut app, Vec2::ZERO, Vec2::new(2.0, 0.0));
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+        assert_eq!(app.world().resource::<ActiveGrowthTip>().0, Some(tip));
+
+        app.world_mut()
+            .resource_mut::<CursorWorldPosition>()
+            .position = Some(Vec2::new(100.0, 0.0));
+        app.world_mut().resource_mut::<InputActions>().primary_held = false;
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+        assert_eq!(app.world().resource::<ActiveGrowthTip>().0, Some(tip));
+        assert_eq!(nutrients(&app), 50.0);
+    }
+
+    #[test]
+    /// A wall between tip and cursor: the tip stops or slides along it.
+    fn test_obstacle_stops_or_deflects_growth() {
+        let wall = Obstacles::new(&[ObstacleSite {
+            vertices: vec![
+                Vec2::new(10.0, -50.0),
+                Vec2::new(30.0, -50.0),
+                Vec2::new(30.0, 50.0),
+                Vec2::new(10.0, 50.0),
+            ],
+        }]);
+        let cursor = Vec2::new(100.0, 40.0);
+
+        let mut app = create_test_app();
+        app.insert_resource(wall.clone());
+        app.insert_resource(ObstacleConfig {
+            response: ObstacleResponse::Stop,
+            ..default()
+        });
+        let tip = setup_growth(&mut app, Vec2::ZERO, cursor);
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+        assert_eq!(app.world().resource::<ActiveGrowthTip>().0, Some(tip));
+        assert_eq!(nutrients(&app), 50.0);
+
+        let mut app = create_test_app();
+        app.insert_resource(wall);
+        setup_growth(&mut app, Vec2::ZERO, cursor);
+        app.world_mut().run_system_once(grow_active_tip).unwrap();
+        let child = app.world().resource::<ActiveGrowthTip>().0.unwrap();
+        let position = app.world().get::<TendrilPosition>(child).unwrap().position;
+        assert_eq!(
+            position,
+            Vec2::new(0.0, NetworkConfig::default().segment_length)
+        );
+    }
 }
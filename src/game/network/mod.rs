@@ -1,16 +1,18 @@
 //! Fungal network system
 //!
 //! Handles the core network mechanics:
-//! - Tendril growth and pathfinding
+//! - Tendril growth and pathfinding (shared by the player and rival networks)
 //! - Network connectivity and severance
 //! - Specialized tendril types
 //! - Core node management
+//! - Data-defined abilities such as scout spores
 //! - Visual rendering of the network
 
 use bevy::prelude::*;
 
 use crate::GameState;
 
+mod ability;
 mod components;
 mod core_node;
 pub mod graph;
@@ -19,7 +21,11 @@ mod rendering;
 mod resources;
 
 // Re-exports
+pub use ability::{
+    AbilityCooldowns, AbilityDefinition, AbilityEffect, AbilityKind, AbilityTable, UseAbility,
+};
 pub use components::*;
+pub use growth::grow_segment;
 pub use rendering::{lerp_color, segment_color, TendrilAnimationState, TendrilStyle};
 pub use resources::*;
 
@@ -30,9 +36,29 @@ impl Plugin for NetworkPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<NetworkStats>()
             .init_resource::<ActiveGrowthTip>()
+            .init_resource::<NetworkConfig>()
+            .init_resource::<GrowthCooldown>()
             .init_resource::<TendrilAnimationState>()
+            .init_resource::<AbilityTable>()
+            .init_resource::<AbilityCooldowns>()
+            .add_event::<UseAbility>()
             .add_systems(OnEnter(GameState::Playing), core_node::spawn_core_node)
             .add_systems(OnEnter(GameState::Menu), core_node::despawn_core_node)
+            // Abilities start every run ready
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                ability::reset_ability_cooldowns,
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::GameOver,
+                    entered: GameState::Playing,
+                },
+                ability::reset_ability_cooldowns,
+            )
             .add_systems(
                 Update,
                 (
@@ -40,6 +66,13 @@ impl Plugin for NetworkPlugin {
                     (
                         growth::select_growth_tip,
                         growth::update_selected_tip_direction,
+                        growth::grow_active_tip,
+                    )
+                        .chain(),
+                    (
+                        ability::tick_ability_cooldowns,
+                        ability::read_ability_input,
+                        ability::use_abilities,
                     )
                         .chain(),
                     rendering::update_tendril_animation,
@@ -160,15 +160,26 @@ pub fn brighten_color(color: Color) -> Color {
 }
 
 /// Render all tendril segments as lines connecting to their parents
+///
+/// Segments hidden by fog (rival ones) are skipped.
 pub fn render_tendrils(
     mut gizmos: Gizmos,
     segments: Query<
-        (&TendrilPosition, &TendrilSegment, &TendrilStyle, &NetworkParent),
+        (
+            &TendrilPosition,
+            &TendrilSegment,
+            &TendrilStyle,
+            &NetworkParent,
+            Option<&Visibility>,
+        ),
         Without<CoreNode>,
     >,
     positions: Query<&TendrilPosition>,
 ) {
-    for (pos, segment, style, parent) in segments.iter() {
+    for (pos, segment, style, parent, visibility) in segments.iter() {
+        if visibility == Some(&Visibility::Hidden) {
+            continue;
+        }
         if let Ok(parent_pos) = positions.get(parent.0) {
             let color = segment_color(segment, style);
             gizmos.line_2d(parent_pos.position, pos.position, color);
@@ -176,13 +187,21 @@ pub fn render_tendrils(
     }
 }
 
-/// Render growth tips with pulsing highlight
+/// Render growth tips with pulsing highlight, skipping those hidden by fog
 pub fn render_growth_tips(
     mut gizmos: Gizmos,
     anim_state: Res<TendrilAnimationState>,
-    tips: Query<(&TendrilPosition, &GrowthTip, Option<&TendrilStyle>)>,
+    tips: Query<(
+        &TendrilPosition,
+        &GrowthTip,
+        Option<&TendrilStyle>,
+        Option<&Visibility>,
+    )>,
 ) {
-    for (pos, tip, style) in tips.iter() {
+    for (pos, tip, style, visibility) in tips.iter() {
+        if visibility == Some(&Visibility::Hidden) {
+            continue;
+        }
         // Pulsing size based on animation
         let pulse = calculate_pulse(anim_state.time, anim_state.pulse_speed);
         let base_radius = 6.0;
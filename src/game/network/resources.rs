@@ -10,6 +10,10 @@ pub struct CoreNodeEntity(pub Entity);
 #[derive(Resource, Debug, Default)]
 pub struct ActiveGrowthTip(pub Option<Entity>);
 
+/// Seconds until the active growth tip may grow again
+#[derive(Resource, Debug, Default)]
+pub struct GrowthCooldown(pub f32);
+
 /// Tracks overall network statistics
 #[derive(Resource, Debug, Default)]
 pub struct NetworkStats {
@@ -27,6 +31,8 @@ pub struct NetworkStats {
     pub connected_segments: u32,
     /// Number of severed segments
     pub severed_segments: u32,
+    /// Number of bacteria latched onto segments
+    pub latched_bacteria: u32,
 }
 
 /// Configuration for network behavior
@@ -40,6 +46,8 @@ pub struct NetworkConfig {
     pub decay_rate: f32,
     /// Delay before severed segments start decaying (seconds)
     pub decay_start_delay: f32,
+    /// Seconds between segments while the active tip is grown
+    pub growth_interval: f32,
 }
 
 impl Default for NetworkConfig {
@@ -49,6 +57,7 @@ impl Default for NetworkConfig {
             segment_health: 50.0,
             decay_rate: 10.0,
             decay_start_delay: 2.0,
+            growth_interval: 0.15,
         }
     }
 }
@@ -67,6 +76,7 @@ mod tests {
     fn test_active_growth_tip_is_resource() {
         fn assert_resource<T: Resource>() {}
         assert_resource::<ActiveGrowthTip>();
+        assert_resource::<GrowthCooldown>();
     }
 
     #[test]
@@ -77,6 +87,7 @@ mod tests {
         assert_eq!(stats.tip_count, 0);
         assert_eq!(stats.connected_segments, 0);
         assert_eq!(stats.severed_segments, 0);
+        assert_eq!(stats.latched_bacteria, 0);
     }
 
     #[test]
@@ -92,6 +103,7 @@ mod tests {
         assert!(config.segment_health > 0.0);
         assert!(config.decay_rate > 0.0);
         assert!(config.decay_start_delay >= 0.0);
+        assert!(config.growth_interval > 0.0);
     }
 
     #[test]
@@ -2,7 +2,11 @@
 
 use super::NetworkPlugin;
 use crate::{
-    game::input::{CursorWorldPosition, InputActions},
+    game::{
+        input::{CursorWorldPosition, InputActions},
+        map::{BiomeMap, BiomeTable, ObstacleConfig, Obstacles},
+        progression::ProgressionPlugin,
+    },
     GameState,
 };
 use bevy::prelude::*;
@@ -14,8 +18,12 @@ pub fn create_test_app() -> App {
     app.add_plugins(MinimalPlugins)
         .add_plugins(StatesPlugin)
         .init_state::<GameState>()
-        .add_plugins(NetworkPlugin)
+        .add_plugins((NetworkPlugin, ProgressionPlugin))
         .init_resource::<InputActions>()
-        .init_resource::<CursorWorldPosition>();
+        .init_resource::<CursorWorldPosition>()
+        .init_resource::<BiomeMap>()
+        .init_resource::<BiomeTable>()
+        .init_resource::<Obstacles>()
+        .init_resource::<ObstacleConfig>();
     app
 }
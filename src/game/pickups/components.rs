@@ -0,0 +1,40 @@
+//! Pickup components
+
+use bevy::prelude::*;
+
+/// A nutrient orb waiting to be collected by the network
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct NutrientOrb {
+    /// Nutrients added when collected
+    pub value: f32,
+    /// Current drift velocity in world units per second
+    pub velocity: Vec2,
+}
+
+impl NutrientOrb {
+    #[must_use]
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_orb_is_at_rest() {
+        let orb = NutrientOrb::new(5.0);
+        assert_eq!(orb.value, 5.0);
+        assert_eq!(orb.velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_orb_is_component() {
+        fn assert_component<T: Component>() {}
+        assert_component::<NutrientOrb>();
+    }
+}
@@ -0,0 +1,414 @@
+//! Decomposing corpses
+//!
+//! Killed enemies leave a corpse holding a nutrient reservoir:
+//! - The reservoir is `corpse_share` of the enemy's nutrient drop, the rest
+//!   drops as orbs
+//! - Live player segments within `absorb_range` draw nutrients out slowly
+//! - A segment grown onto the corpse draws them out faster
+//! - Corpses shrink as the reservoir empties and rot away if left alone
+//! - Past `max_corpses` the smallest fold into their nearest neighbour
+
+use bevy::prelude::*;
+
+use crate::game::network::{Faction, TendrilPosition, TendrilSegment};
+use crate::game::progression::{NutrientSource, Nutrients, NutrientsGained};
+use crate::game::spatial::SpatialGrid;
+
+/// Tuning for corpses
+#[derive(Resource, Debug, Clone)]
+pub struct CorpseConfig {
+    /// Share of an enemy's nutrient drop kept in its corpse
+    pub corpse_share: f32,
+    /// Distance from the corpse edge within which segments absorb it
+    pub absorb_range: f32,
+    /// Nutrients per second released to nearby segments
+    pub release_rate: f32,
+    /// Release rate multiplier while a segment touches the corpse
+    pub contact_multiplier: f32,
+    /// Seconds an unabsorbed corpse lasts before rotting away
+    pub rot_time: f32,
+    /// Hard cap on live corpses; the smallest merge into their nearest neighbour
+    pub max_corpses: usize,
+}
+
+impl Default for CorpseConfig {
+    fn default() -> Self {
+        Self {
+            corpse_share: 0.4,
+            absorb_range: 80.0,
+            release_rate: 1.0,
+            contact_multiplier: 4.0,
+            rot_time: 60.0,
+            max_corpses: 40,
+        }
+    }
+}
+
+impl CorpseConfig {
+    /// Split a nutrient drop into the orb part and the corpse part
+    #[must_use]
+    pub fn split_drop(&self, amount: f32) -> (f32, f32) {
+        let corpse = amount * self.corpse_share.clamp(0.0, 1.0);
+        (amount - corpse, corpse)
+    }
+}
+
+/// A body slowly releasing nutrients where it fell
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Corpse {
+    /// Nutrients left to release
+    pub reservoir: f32,
+    /// Reservoir when the corpse was left
+    pub initial: f32,
+    /// Radius of the body at full size
+    pub radius: f32,
+    /// Seconds without any absorbing segment
+    pub idle_time: f32,
+}
+
+impl Corpse {
+    #[must_use]
+    pub fn new(reservoir: f32, radius: f32) -> Self {
+        Self {
+            reservoir,
+            initial: reservoir,
+            radius,
+            idle_time: 0.0,
+        }
+    }
+
+    /// Fraction of the reservoir left, used for the body's size
+    #[must_use]
+    pub fn remaining(&self) -> f32 {
+        if self.initial > 0.0 {
+            (self.reservoir / self.initial).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Current radius of the shrinking body
+    #[must_use]
+    pub fn current_radius(&self) -> f32 {
+        self.radius * self.remaining().sqrt()
+    }
+}
+
+/// Request to leave a corpse at a world position
+#[derive(Event, Debug, Clone)]
+pub struct LeaveCorpse {
+    pub position: Vec2,
+    pub radius: f32,
+    /// Nutrients held in the corpse's reservoir
+    pub nutrients: f32,
+}
+
+/// Spawn a corpse for every request
+pub fn spawn_corpses(mut commands: Commands, mut requests: EventReader<LeaveCorpse>) {
+    for request in requests.read() {
+        if request.nutrients <= 0.0 {
+            continue;
+        }
+        commands.spawn((
+            Corpse::new(request.nutrients, request.radius),
+            Transform::from_translation(request.position.extend(-0.5)),
+            Visibility::default(),
+        ));
+    }
+}
+
+/// Fold the smallest corpses into their nearest neighbour past `max_corpses`
+pub fn cap_corpses(
+    mut commands: Commands,
+    config: Res<CorpseConfig>,
+    mut corpses: Query<(Entity, &mut Corpse, &Transform)>,
+) {
+    let max = config.max_corpses.max(1);
+    if corpses.iter().count() <= max {
+        return;
+    }
+
+    let mut live: Vec<(Entity, f32, Vec2)> = corpses
+        .iter()
+        .map(|(e, corpse, t)| (e, corpse.reservoir, t.translation.truncate()))
+        .collect();
+    live.sort_by_key(|(e, _, _)| *e);
+    let mut merged = vec![false; live.len()];
+    let mut added = vec![0.0; live.len()];
+
+    let mut remaining = live.len();
+    while remaining > max {
+        let alive = || (0..live.len()).filter(|&i| !merged[i]);
+        let Some(smallest) = alive().min_by(|&a, &b| live[a].1.total_cmp(&live[b].1)) else {
+            break;
+        };
+        let origin = live[smallest].2;
+        let Some(nearest) = alive().filter(|&i| i != smallest).min_by(|&a, &b| {
+            live[a]
+                .2
+                .distance_squared(origin)
+                .total_cmp(&live[b].2.distance_squared(origin))
+        }) else {
+            break;
+        };
+        live[nearest].1 += live[smallest].1;
+        added[nearest] += live[smallest].1;
+        merged[smallest] = true;
+        remaining -= 1;
+    }
+
+    for (i, (entity, _, _)) in live.into_iter().enumerate() {
+        if merged[i] {
+            commands.entity(entity).despawn_recursive();
+        } else if added[i] > 0.0 {
+            if let Ok((_, mut corpse, _)) = corpses.get_mut(entity) {
+                corpse.reservoir += added[i];
+                corpse.initial += added[i];
+            }
+        }
+    }
+}
+
+/// Release corpse nutrients to nearby segments and remove spent corpses
+pub fn decompose_corpses(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<CorpseConfig>,
+    mut grid: Local<SpatialGrid>,
+    mut corpses: Query<(Entity, &mut Corpse, &mut Transform)>,
+    segments: Query<(Entity, &TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    mut nutrients: ResMut<Nutrients>,
+    mut gained: EventWriter<NutrientsGained>,
+) {
+    if corpses.is_empty() {
+        return;
+    }
+    let delta = time.delta_secs();
+    grid.clear();
+    for (entity, pos, segment, faction) in segments.iter() {
+        if segment.health > 0.0 && Faction::is_player(faction) {
+            grid.insert(entity, pos.position);
+        }
+    }
+
+    for (entity, mut corpse, mut transform) in corpses.iter_mut() {
+        let center = transform.translation.truncate();
+        let radius = corpse.current_radius();
+        let closest = grid
+            .nearest(center, radius + config.absorb_range)
+            .map(|(_, position)| position.distance(center));
+        let rate = match closest {
+            Some(distance) if distance <= radius => config.release_rate * config.contact_multiplier,
+            Some(distance) if distance <= radius + config.absorb_range => config.release_rate,
+            _ => 0.0,
+        };
+
+        if rate > 0.0 {
+            corpse.idle_time = 0.0;
+            let amount = (rate * delta).min(corpse.reservoir);
+            if amount > 0.0 {
+                corpse.reservoir -= amount;
+                nutrients.add(amount);
+                gained.send(NutrientsGained::new(amount, NutrientSource::Decomposition));
+            }
+        } else {
+            corpse.idle_time += delta;
+        }
+
+        if corpse.reservoir <= f32::EPSILON || corpse.idle_time >= config.rot_time {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        transform.scale = Vec3::splat(corpse.remaining().sqrt());
+    }
+}
+
+/// Remove every corpse when the run ends
+pub fn despawn_corpses(mut commands: Commands, corpses: Query<Entity, With<Corpse>>) {
+    for entity in corpses.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Draw corpses as shrinking circles
+pub fn render_corpses(mut gizmos: Gizmos, corpses: Query<(&Corpse, &Transform, &Visibility)>) {
+    for (corpse, transform, visibility) in corpses.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            corpse.current_radius(),
+            Color::srgb(0.45, 0.35, 0.25),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::pickups::test_utils::{
+        create_test_app, enter_state, grow_segment_at, Gained, TEST_FRAME_SECS,
+    };
+    use crate::GameState;
+
+    fn leave_corpse(app: &mut App, position: Vec2, nutrients: f32) -> Entity {
+        app.world_mut().send_event(LeaveCorpse {
+            position,
+            radius: 10.0,
+            nutrients,
+        });
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Corpse>>();
+        query.single(app.world())
+    }
+
+    fn reservoir(app: &App, corpse: Entity) -> f32 {
+        app.world().get::<Corpse>(corpse).unwrap().reservoir
+    }
+
+    #[test]
+    fn test_corpse_holds_requested_reservoir() {
+        let mut app = create_test_app();
+        let corpse = leave_corpse(&mut app, Vec2::ZERO, 4.0);
+        assert_eq!(reservoir(&app, corpse), 4.0);
+    }
+
+    #[test]
+    fn test_split_drop_keeps_the_total() {
+        let config = CorpseConfig::default();
+        let (orbs, corpse) = config.split_drop(10.0);
+        assert_eq!(orbs + corpse, 10.0);
+        assert_eq!(corpse, 10.0 * config.corpse_share);
+    }
+
+    #[test]
+    fn test_corpses_past_the_cap_fold_into_their_neighbour() {
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<CorpseConfig>().max_corpses = 2;
+        app.update();
+        for (x, nutrients) in [(0.0, 5.0), (20.0, 1.0), (500.0, 3.0)] {
+            app.world_mut().send_event(LeaveCorpse {
+                position: Vec2::new(x, 0.0),
+                radius: 10.0,
+                nutrients,
+            });
+        }
+        app.update();
+        app.update();
+
+        let mut query = app.world_mut().query::<(&Corpse, &Transform)>();
+        let mut corpses: Vec<(f32, f32)> = query
+            .iter(app.world())
+            .map(|(c, t)| (t.translation.x, c.reservoir))
+            .collect();
+        corpses.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(corpses, vec![(0.0, 6.0), (500.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_corpse_without_segments_keeps_its_nutrients() {
+        let mut app = create_test_app();
+        let corpse = leave_corpse(&mut app, Vec2::ZERO, 4.0);
+        let full = reservoir(&app, corpse);
+        app.update();
+        assert_eq!(reservoir(&app, corpse), full);
+        assert!(app
+            .world()
+            .resource::<Gained>()
+            .from(NutrientSource::Decomposition)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_nearby_segment_absorbs_slowly_and_contact_faster() {
+        let mut near_app = create_test_app();
+        let near = leave_corpse(&mut near_app, Vec2::ZERO, 40.0);
+        grow_segment_at(&mut near_app, Vec2::new(50.0, 0.0));
+
+        let mut touch_app = create_test_app();
+        let touch = leave_corpse(&mut touch_app, Vec2::ZERO, 40.0);
+        grow_segment_at(&mut touch_app, Vec2::new(5.0, 0.0));
+
+        let full = reservoir(&near_app, near);
+        near_app.update();
+        touch_app.update();
+
+        let config = CorpseConfig::default();
+        let slow = full - reservoir(&near_app, near);
+        let fast = full - reservoir(&touch_app, touch);
+        assert_eq!(slow, config.release_rate * TEST_FRAME_SECS);
+        assert_eq!(fast, slow * config.contact_multiplier);
+    }
+
+    #[test]
+    fn test_release_emits_decomposition_gains() {
+        let mut app = create_test_app();
+        let corpse = leave_corpse(&mut app, Vec2::ZERO, 40.0);
+        grow_segment_at(&mut app, Vec2::ZERO);
+        let full = reservoir(&app, corpse);
+        let before = app.world().resource::<Nutrients>().current;
+        app.update();
+
+        let released = full - reservoir(&app, corpse);
+        assert!(released > 0.0);
+        assert_eq!(
+            app.world().resource::<Nutrients>().current,
+            before + released
+        );
+
+        // The collector may read this frame's event on the next one
+        app.world_mut().resource_mut::<CorpseConfig>().release_rate = 0.0;
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<Gained>()
+                .from(NutrientSource::Decomposition),
+            vec![released]
+        );
+    }
+
+    #[test]
+    fn test_corpse_shrinks_and_disappears_when_consumed() {
+        let mut app = create_test_app();
+        let corpse = leave_corpse(&mut app, Vec2::ZERO, 2.0);
+        grow_segment_at(&mut app, Vec2::ZERO);
+        app.update();
+        let scale = app.world().get::<Transform>(corpse).unwrap().scale.x;
+        assert!(scale < 1.0);
+
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(app.world().get_entity(corpse).is_err());
+    }
+
+    #[test]
+    fn test_ignored_corpse_rots_away() {
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<CorpseConfig>().rot_time = 0.5;
+        let corpse = leave_corpse(&mut app, Vec2::ZERO, 4.0);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(corpse).is_err());
+    }
+
+    #[test]
+    fn test_corpses_cleared_when_run_ends() {
+        let mut app = create_test_app();
+        let corpse = leave_corpse(&mut app, Vec2::ZERO, 4.0);
+        enter_state(&mut app, GameState::GameOver);
+        assert!(app.world().get_entity(corpse).is_err());
+    }
+
+    #[test]
+    fn test_corpse_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        fn assert_event<T: Event>() {}
+        assert_component::<Corpse>();
+        assert_resource::<CorpseConfig>();
+        assert_event::<LeaveCorpse>();
+    }
+}
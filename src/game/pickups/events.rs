@@ -0,0 +1,31 @@
+//! Pickup events
+
+use bevy::prelude::*;
+
+/// Request to drop nutrient orbs worth `amount` at a world position
+///
+/// The amount is split across several orbs and scaled by
+/// `PickupStats::value_multiplier`.
+#[derive(Event, Debug, Clone)]
+pub struct DropNutrients {
+    pub position: Vec2,
+    pub amount: f32,
+}
+
+impl DropNutrients {
+    #[must_use]
+    pub fn new(position: Vec2, amount: f32) -> Self {
+        Self { position, amount }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_nutrients_is_event() {
+        fn assert_event<T: Event>() {}
+        assert_event::<DropNutrients>();
+    }
+}
@@ -0,0 +1,250 @@
+//! Pickup system
+//!
+//! Handles nutrient orbs dropped by enemies:
+//! - Drops split into a few scattered orbs
+//! - Magnetic pull toward the nearest live tendril in range
+//! - Collection on contact with the network
+//! - Merging when many pile up, to cap the entity count
+//! - Corpses that release nutrients to nearby segments as they decompose
+//! - Environmental nutrient nodes placed by map generation
+
+use bevy::prelude::*;
+
+use crate::game::collision::CollisionSet;
+use crate::game::map::MapGenSet;
+use crate::GameState;
+
+pub mod components;
+pub mod corpse;
+pub mod events;
+pub mod node;
+pub mod resources;
+mod systems;
+
+pub use components::*;
+pub use corpse::{Corpse, CorpseConfig, LeaveCorpse};
+pub use events::*;
+pub use node::{node_color, NodeState, NutrientNode, NutrientNodeConfig};
+pub use resources::*;
+pub use systems::spawn_nutrient_orb;
+
+/// Plugin for the pickup system
+pub struct PickupsPlugin;
+
+impl Plugin for PickupsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<PickupStats>()
+            .init_resource::<OrbConfig>()
+            .init_resource::<CorpseConfig>()
+            .init_resource::<NutrientNodeConfig>()
+            // Events
+            .add_event::<DropNutrients>()
+            .add_event::<LeaveCorpse>()
+            // Nodes of the freshly generated map
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                node::spawn_nutrient_nodes.after(MapGenSet),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::GameOver,
+                    entered: GameState::Playing,
+                },
+                node::spawn_nutrient_nodes.after(MapGenSet),
+            )
+            // Cleanup when the run ends
+            .add_systems(
+                OnEnter(GameState::Menu),
+                (
+                    systems::despawn_orbs,
+                    corpse::despawn_corpses,
+                    node::despawn_nutrient_nodes,
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (
+                    systems::despawn_orbs,
+                    corpse::despawn_corpses,
+                    node::despawn_nutrient_nodes,
+                ),
+            )
+            // Collection reads this frame's contacts, so run after detection
+            .add_systems(
+                Update,
+                (
+                    systems::drop_nutrient_orbs,
+                    corpse::spawn_corpses,
+                    corpse::cap_corpses,
+                    systems::collect_orbs,
+                    systems::merge_orbs,
+                    systems::attract_orbs,
+                    corpse::decompose_corpses,
+                    node::tap_nutrient_nodes,
+                )
+                    .chain()
+                    .after(CollisionSet)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Rendering
+            .add_systems(
+                Update,
+                (
+                    node::render_nutrient_nodes,
+                    corpse::render_corpses,
+                    systems::render_orbs,
+                )
+                    .run_if(
+                        resource_exists::<bevy::gizmos::config::GizmoConfigStore>
+                            .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                    ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{create_test_app, enter_state, grow_segment_at, Gained};
+    use super::*;
+    use crate::game::collision::{Collider, CollisionLayers, Layer, LayerMask};
+    use crate::game::progression::{NutrientSource, Nutrients};
+
+    fn orbs(app: &mut App) -> Vec<(Entity, NutrientOrb, Vec2)> {
+        let mut query = app
+            .world_mut()
+            .query::<(Entity, &NutrientOrb, &Transform)>();
+        query
+            .iter(app.world())
+            .map(|(e, orb, t)| (e, orb.clone(), t.translation.truncate()))
+            .collect()
+    }
+
+    fn place_orb(app: &mut App, value: f32, position: Vec2) -> Entity {
+        let config = OrbConfig::default();
+        app.world_mut()
+            .spawn((
+                NutrientOrb::new(value),
+                Collider::Circle {
+                    radius: config.radius,
+                },
+                CollisionLayers::new(Layer::Pickup, LayerMask::TENDRIL | LayerMask::CORE),
+                Transform::from_translation(position.extend(0.0)),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_drop_splits_into_scaled_orbs() {
+        let mut app = create_test_app();
+        app.world_mut()
+            .resource_mut::<PickupStats>()
+            .value_multiplier = 2.0;
+        app.world_mut()
+            .send_event(DropNutrients::new(Vec2::new(500.0, 0.0), 6.0));
+        app.update();
+
+        let dropped = orbs(&mut app);
+        assert_eq!(dropped.len(), 3);
+        let total: f32 = dropped.iter().map(|(_, orb, _)| orb.value).sum();
+        assert_eq!(total, 12.0);
+    }
+
+    #[test]
+    fn test_magnet_pulls_orbs_in_range_only() {
+        let mut app = create_test_app();
+        grow_segment_at(&mut app, Vec2::ZERO);
+        let near = place_orb(&mut app, 1.0, Vec2::new(100.0, 0.0));
+        let far = place_orb(&mut app, 1.0, Vec2::new(400.0, 0.0));
+        app.update();
+
+        let world = app.world();
+        assert!(world.get::<Transform>(near).unwrap().translation.x < 100.0);
+        assert_eq!(world.get::<Transform>(far).unwrap().translation.x, 400.0);
+    }
+
+    #[test]
+    fn test_larger_magnet_radius_reaches_further() {
+        let mut app = create_test_app();
+        app.world_mut().resource_mut::<PickupStats>().magnet_radius = 500.0;
+        grow_segment_at(&mut app, Vec2::ZERO);
+        let orb = place_orb(&mut app, 1.0, Vec2::new(400.0, 0.0));
+        app.update();
+
+        assert!(app.world().get::<Transform>(orb).unwrap().translation.x < 400.0);
+    }
+
+    #[test]
+    fn test_orbs_are_collected_on_contact() {
+        let mut app = create_test_app();
+        grow_segment_at(&mut app, Vec2::ZERO);
+        let orb = place_orb(&mut app, 7.0, Vec2::ZERO);
+        let before = app.world().resource::<Nutrients>().current;
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(orb).is_err());
+        assert_eq!(app.world().resource::<Nutrients>().current, before + 7.0);
+        assert_eq!(
+            app.world()
+                .resource::<Gained>()
+                .from(NutrientSource::EnemyDrop),
+            vec![7.0]
+        );
+    }
+
+    #[test]
+    fn test_orbs_merge_past_threshold() {
+        let mut app = create_test_app();
+        {
+            let mut config = app.world_mut().resource_mut::<OrbConfig>();
+            config.merge_threshold = 4;
+            config.max_orbs = 3;
+        }
+        // Two tight piles of three, plus two stragglers far apart
+        for position in [
+            Vec2::new(1000.0, 0.0),
+            Vec2::new(1005.0, 0.0),
+            Vec2::new(1010.0, 0.0),
+            Vec2::new(-1000.0, 0.0),
+            Vec2::new(-1005.0, 0.0),
+            Vec2::new(-1010.0, 0.0),
+            Vec2::new(0.0, 1000.0),
+            Vec2::new(0.0, -1000.0),
+        ] {
+            place_orb(&mut app, 1.0, position);
+        }
+        app.update();
+
+        let merged = orbs(&mut app);
+        assert_eq!(merged.len(), 3);
+        let total: f32 = merged.iter().map(|(_, orb, _)| orb.value).sum();
+        assert_eq!(total, 8.0);
+    }
+
+    #[test]
+    fn test_few_orbs_never_merge() {
+        let mut app = create_test_app();
+        place_orb(&mut app, 1.0, Vec2::new(1000.0, 0.0));
+        place_orb(&mut app, 1.0, Vec2::new(1001.0, 0.0));
+        app.update();
+
+        assert_eq!(orbs(&mut app).len(), 2);
+    }
+
+    #[test]
+    fn test_orbs_cleared_when_run_ends() {
+        let mut app = create_test_app();
+        place_orb(&mut app, 1.0, Vec2::new(1000.0, 0.0));
+        enter_state(&mut app, GameState::GameOver);
+
+        assert!(orbs(&mut app).is_empty());
+    }
+}
@@ -0,0 +1,356 @@
+//! Environmental nutrient nodes
+//!
+//! Map generation places nodes holding a nutrient reservoir:
+//! - Connected player segments within `tap_range` draw nutrients out
+//! - Finite nodes run dry, regenerating ones slowly refill
+//! - Each node shows whether it is full, tapped or depleted
+//! - Rich nodes pull rival growth toward them, and the spawn director sends
+//!   enemies at rich nodes the player is tapping
+
+use bevy::prelude::*;
+
+use crate::game::enemies::RivalAttractor;
+use crate::game::map::WorldDescription;
+use crate::game::network::{Faction, Severed, TendrilPosition, TendrilSegment};
+use crate::game::progression::{NutrientSource, Nutrients, NutrientsGained};
+
+/// Tuning for nutrient nodes
+#[derive(Resource, Debug, Clone)]
+pub struct NutrientNodeConfig {
+    /// Distance from the node center within which segments tap it
+    pub tap_range: f32,
+    /// Nutrients per second paid out while tapped
+    pub tap_rate: f32,
+    /// Tap rate multiplier for rich nodes
+    pub rich_tap_multiplier: f32,
+    /// Radius of the drawn node
+    pub radius: f32,
+}
+
+impl Default for NutrientNodeConfig {
+    fn default() -> Self {
+        Self {
+            tap_range: 60.0,
+            tap_rate: 2.0,
+            rich_tap_multiplier: 2.0,
+            radius: 14.0,
+        }
+    }
+}
+
+/// What a node looks like to the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeState {
+    /// Untouched reservoir
+    Full,
+    /// Being drained or partly drained
+    Tapped,
+    /// Nothing left to draw
+    Depleted,
+}
+
+/// A nutrient reservoir in the environment
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct NutrientNode {
+    /// Nutrients left
+    pub reservoir: f32,
+    /// Reservoir when full
+    pub capacity: f32,
+    /// Nutrients regained per second, zero for a finite node
+    pub regeneration: f32,
+    pub rich: bool,
+    /// Whether a segment drew from the node on the last frame
+    pub tapped: bool,
+}
+
+impl NutrientNode {
+    #[must_use]
+    pub fn new(capacity: f32, regeneration: f32, rich: bool) -> Self {
+        Self {
+            reservoir: capacity,
+            capacity,
+            regeneration,
+            rich,
+            tapped: false,
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> NodeState {
+        if self.reservoir <= f32::EPSILON {
+            NodeState::Depleted
+        } else if self.tapped || self.reservoir < self.capacity {
+            NodeState::Tapped
+        } else {
+            NodeState::Full
+        }
+    }
+
+    /// Fraction of the reservoir left
+    #[must_use]
+    pub fn fill(&self) -> f32 {
+        if self.capacity > 0.0 {
+            (self.reservoir / self.capacity).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Spawn the nodes of the generated map
+pub fn spawn_nutrient_nodes(mut commands: Commands, world: Res<WorldDescription>) {
+    for site in &world.nutrient_nodes {
+        let mut node = commands.spawn((
+            NutrientNode::new(site.reservoir, site.regeneration, site.rich),
+            Transform::from_translation(site.position.extend(-0.6)),
+            Visibility::default(),
+        ));
+        if site.rich {
+            node.insert(RivalAttractor);
+        }
+    }
+}
+
+/// Pay out nodes to nearby connected segments and refill regenerating ones
+pub fn tap_nutrient_nodes(
+    time: Res<Time>,
+    config: Res<NutrientNodeConfig>,
+    mut nodes: Query<(&mut NutrientNode, &Transform)>,
+    segments: Query<(&TendrilPosition, &TendrilSegment, Option<&Faction>), Without<Severed>>,
+    mut nutrients: ResMut<Nutrients>,
+    mut gained: EventWriter<NutrientsGained>,
+) {
+    let delta = time.delta_secs();
+    let tappers: Vec<Vec2> = segments
+        .iter()
+        .filter(|(_, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .map(|(pos, _, _)| pos.position)
+        .collect();
+    let range_sq = config.tap_range * config.tap_range;
+
+    for (mut node, transform) in nodes.iter_mut() {
+        let center = transform.translation.truncate();
+        let in_range = tappers
+            .iter()
+            .any(|p| p.distance_squared(center) <= range_sq);
+
+        let mut amount = 0.0;
+        if in_range {
+            let rate = if node.rich {
+                config.tap_rate * config.rich_tap_multiplier
+            } else {
+                config.tap_rate
+            };
+            amount = (rate * delta).min(node.reservoir);
+            if amount > 0.0 {
+                node.reservoir -= amount;
+                nutrients.add(amount);
+                gained.send(NutrientsGained::new(
+                    amount,
+                    NutrientSource::EnvironmentNode,
+                ));
+            }
+        } else if node.regeneration > 0.0 {
+            node.reservoir = (node.reservoir + node.regeneration * delta).min(node.capacity);
+        }
+        node.tapped = amount > 0.0;
+    }
+}
+
+/// Remove every node when the run ends
+pub fn despawn_nutrient_nodes(mut commands: Commands, nodes: Query<Entity, With<NutrientNode>>) {
+    for entity in nodes.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Color a node by its state
+#[must_use]
+pub fn node_color(state: NodeState, rich: bool) -> Color {
+    match (state, rich) {
+        (NodeState::Depleted, _) => Color::srgb(0.3, 0.3, 0.3),
+        (NodeState::Tapped, false) => Color::srgb(0.3, 0.6, 0.9),
+        (NodeState::Tapped, true) => Color::srgb(0.9, 0.7, 0.2),
+        (NodeState::Full, false) => Color::srgb(0.4, 0.8, 1.0),
+        (NodeState::Full, true) => Color::srgb(1.0, 0.85, 0.3),
+    }
+}
+
+/// Draw nodes as rings filled by their remaining reservoir
+pub fn render_nutrient_nodes(
+    mut gizmos: Gizmos,
+    config: Res<NutrientNodeConfig>,
+    nodes: Query<(&NutrientNode, &Transform, &Visibility)>,
+) {
+    for (node, transform, visibility) in nodes.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        let center = transform.translation.truncate();
+        let color = node_color(node.state(), node.rich);
+        let radius = if node.rich {
+            config.radius * 1.5
+        } else {
+            config.radius
+        };
+        gizmos.circle_2d(center, radius, color);
+        if node.fill() > 0.0 {
+            gizmos.circle_2d(center, radius * node.fill(), color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::map::NutrientNodeSite;
+    use crate::game::pickups::test_utils::{
+        create_test_app, enter_state, grow_segment_at, Gained, TEST_FRAME_SECS,
+    };
+    use crate::GameState;
+
+    fn place_node(app: &mut App, position: Vec2, node: NutrientNode) -> Entity {
+        app.world_mut()
+            .spawn((node, Transform::from_translation(position.extend(0.0))))
+            .id()
+    }
+
+    fn node(app: &App, entity: Entity) -> NutrientNode {
+        app.world().get::<NutrientNode>(entity).unwrap().clone()
+    }
+
+    #[test]
+    fn test_node_state_follows_reservoir() {
+        let mut node = NutrientNode::new(10.0, 0.0, false);
+        assert_eq!(node.state(), NodeState::Full);
+        node.reservoir = 4.0;
+        assert_eq!(node.state(), NodeState::Tapped);
+        node.reservoir = 0.0;
+        assert_eq!(node.state(), NodeState::Depleted);
+    }
+
+    #[test]
+    fn test_segment_in_range_taps_node() {
+        let mut app = create_test_app();
+        let entity = place_node(&mut app, Vec2::ZERO, NutrientNode::new(50.0, 0.0, false));
+        grow_segment_at(&mut app, Vec2::new(40.0, 0.0));
+        let before = app.world().resource::<Nutrients>().current;
+        app.update();
+
+        let config = NutrientNodeConfig::default();
+        let paid = config.tap_rate * TEST_FRAME_SECS;
+        let tapped = node(&app, entity);
+        assert_eq!(tapped.reservoir, 50.0 - paid);
+        assert!(tapped.tapped);
+        assert_eq!(tapped.state(), NodeState::Tapped);
+        assert_eq!(app.world().resource::<Nutrients>().current, before + paid);
+
+        app.update();
+        assert!(!app
+            .world()
+            .resource::<Gained>()
+            .from(NutrientSource::EnvironmentNode)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_distant_and_severed_segments_do_not_tap() {
+        let mut app = create_test_app();
+        let entity = place_node(&mut app, Vec2::ZERO, NutrientNode::new(50.0, 0.0, false));
+        grow_segment_at(&mut app, Vec2::new(200.0, 0.0));
+        let severed = grow_segment_at(&mut app, Vec2::new(10.0, 0.0));
+        app.world_mut().entity_mut(severed).insert(Severed {
+            time_since_severance: 0.0,
+            decay_rate: 0.0,
+        });
+        app.update();
+        assert_eq!(node(&app, entity).state(), NodeState::Full);
+    }
+
+    #[test]
+    fn test_rich_nodes_pay_faster() {
+        let mut app = create_test_app();
+        let plain = place_node(&mut app, Vec2::ZERO, NutrientNode::new(50.0, 0.0, false));
+        let rich = place_node(
+            &mut app,
+            Vec2::new(500.0, 0.0),
+            NutrientNode::new(50.0, 0.0, true),
+        );
+        grow_segment_at(&mut app, Vec2::ZERO);
+        grow_segment_at(&mut app, Vec2::new(500.0, 0.0));
+        app.update();
+
+        let config = NutrientNodeConfig::default();
+        let plain_paid = 50.0 - node(&app, plain).reservoir;
+        let rich_paid = 50.0 - node(&app, rich).reservoir;
+        assert_eq!(rich_paid, plain_paid * config.rich_tap_multiplier);
+    }
+
+    #[test]
+    fn test_finite_node_depletes_and_regenerating_node_refills() {
+        let mut app = create_test_app();
+        let finite = place_node(&mut app, Vec2::ZERO, NutrientNode::new(1.0, 0.0, false));
+        let segment = grow_segment_at(&mut app, Vec2::ZERO);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(node(&app, finite).state(), NodeState::Depleted);
+        app.world_mut().entity_mut(segment).despawn();
+        app.update();
+        assert_eq!(node(&app, finite).state(), NodeState::Depleted);
+
+        let mut drained = NutrientNode::new(10.0, 4.0, false);
+        drained.reservoir = 0.0;
+        let regenerating = place_node(&mut app, Vec2::ZERO, drained);
+        for _ in 0..12 {
+            app.update();
+        }
+        assert_eq!(node(&app, regenerating).state(), NodeState::Full);
+    }
+
+    #[test]
+    fn test_nodes_spawn_from_map_and_clear_when_run_ends() {
+        let mut app = create_test_app();
+        enter_state(&mut app, GameState::Menu);
+        app.insert_resource(WorldDescription {
+            nutrient_nodes: vec![
+                NutrientNodeSite {
+                    position: Vec2::new(100.0, 0.0),
+                    reservoir: 80.0,
+                    regeneration: 0.0,
+                    rich: false,
+                },
+                NutrientNodeSite {
+                    position: Vec2::new(-100.0, 0.0),
+                    reservoir: 240.0,
+                    regeneration: 1.0,
+                    rich: true,
+                },
+            ],
+            ..default()
+        });
+        enter_state(&mut app, GameState::Playing);
+
+        let mut query = app
+            .world_mut()
+            .query::<(&NutrientNode, Has<RivalAttractor>)>();
+        let mut spawned: Vec<_> = query
+            .iter(app.world())
+            .map(|(node, attracts)| (node.capacity, node.rich, attracts))
+            .collect();
+        spawned.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(spawned, vec![(80.0, false, false), (240.0, true, true)]);
+
+        enter_state(&mut app, GameState::GameOver);
+        let mut query = app.world_mut().query::<&NutrientNode>();
+        assert_eq!(query.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_node_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        fn assert_resource<T: Resource>() {}
+        assert_component::<NutrientNode>();
+        assert_resource::<NutrientNodeConfig>();
+    }
+}
@@ -0,0 +1,113 @@
+//! Pickup resources
+
+use bevy::prelude::*;
+
+/// Pickup stats, modified by upgrades
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PickupStats {
+    /// Distance within which orbs are pulled toward the network
+    pub magnet_radius: f32,
+    /// Multiplier on the value of every dropped orb
+    pub value_multiplier: f32,
+}
+
+impl Default for PickupStats {
+    fn default() -> Self {
+        Self {
+            magnet_radius: 140.0,
+            value_multiplier: 1.0,
+        }
+    }
+}
+
+/// Tuning for nutrient orbs
+#[derive(Resource, Debug, Clone)]
+pub struct OrbConfig {
+    /// Largest value a freshly dropped orb carries
+    pub max_orb_value: f32,
+    /// Most orbs a single drop splits into
+    pub max_orbs_per_drop: usize,
+    /// Speed orbs scatter at when dropped
+    pub scatter_speed: f32,
+    /// Speed orbs reach when pulled by the magnet
+    pub magnet_speed: f32,
+    /// How quickly orbs turn toward the magnet target, per second
+    pub magnet_acceleration: f32,
+    /// Fraction of velocity lost per second while drifting freely
+    pub drag: f32,
+    /// Collision radius of an orb
+    pub radius: f32,
+    /// Orbs merge with neighbours once more than this many exist
+    pub merge_threshold: usize,
+    /// Distance within which orbs merge past the threshold
+    pub merge_radius: f32,
+    /// Hard cap on live orbs; the smallest merge into their nearest neighbour
+    pub max_orbs: usize,
+}
+
+impl Default for OrbConfig {
+    fn default() -> Self {
+        Self {
+            max_orb_value: 5.0,
+            max_orbs_per_drop: 4,
+            scatter_speed: 60.0,
+            magnet_speed: 280.0,
+            magnet_acceleration: 6.0,
+            drag: 3.0,
+            radius: 6.0,
+            merge_threshold: 60,
+            merge_radius: 32.0,
+            max_orbs: 150,
+        }
+    }
+}
+
+impl OrbConfig {
+    /// Values of the orbs a drop of `amount` splits into
+    #[must_use]
+    pub fn split(&self, amount: f32) -> Vec<f32> {
+        if amount <= 0.0 {
+            return Vec::new();
+        }
+        let count = if self.max_orb_value > 0.0 {
+            (amount / self.max_orb_value).ceil() as usize
+        } else {
+            1
+        };
+        let count = count.clamp(1, self.max_orbs_per_drop.max(1));
+        vec![amount / count as f32; count]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_keeps_total_value() {
+        let config = OrbConfig::default();
+        let orbs = config.split(12.0);
+        assert_eq!(orbs.len(), 3);
+        assert_eq!(orbs.iter().sum::<f32>(), 12.0);
+    }
+
+    #[test]
+    fn test_split_caps_orbs_per_drop() {
+        let config = OrbConfig::default();
+        let orbs = config.split(1000.0);
+        assert_eq!(orbs.len(), config.max_orbs_per_drop);
+        assert_eq!(orbs.iter().sum::<f32>(), 1000.0);
+    }
+
+    #[test]
+    fn test_split_nothing_drops_nothing() {
+        assert!(OrbConfig::default().split(0.0).is_empty());
+    }
+
+    #[test]
+    fn test_pickup_resources_are_resources() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<PickupStats>();
+        assert_resource::<OrbConfig>();
+    }
+}
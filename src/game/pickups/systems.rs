@@ -0,0 +1,210 @@
+//! Pickup systems
+//!
+//! Dropping, magnetic pull, collection, merging and rendering of orbs.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::NutrientOrb;
+use super::events::DropNutrients;
+use super::resources::{OrbConfig, PickupStats};
+use crate::game::collision::{Collider, CollisionLayers, CollisionStay, Layer, LayerMask};
+use crate::game::network::{CoreNode, Faction, TendrilPosition, TendrilSegment};
+use crate::game::progression::{NutrientSource, Nutrients, NutrientsGained};
+
+/// Spawn a nutrient orb and return its entity
+pub fn spawn_nutrient_orb(
+    commands: &mut Commands,
+    orb: NutrientOrb,
+    position: Vec2,
+    config: &OrbConfig,
+) -> Entity {
+    commands
+        .spawn((
+            orb,
+            Collider::Circle {
+                radius: config.radius,
+            },
+            CollisionLayers::new(Layer::Pickup, LayerMask::TENDRIL | LayerMask::CORE),
+            Transform::from_translation(position.extend(0.0)),
+            Visibility::default(),
+        ))
+        .id()
+}
+
+/// Split every drop request into scattered orbs
+pub fn drop_nutrient_orbs(
+    mut commands: Commands,
+    mut drops: EventReader<DropNutrients>,
+    config: Res<OrbConfig>,
+    stats: Res<PickupStats>,
+) {
+    let mut rng = rand::thread_rng();
+    for drop in drops.read() {
+        for value in config.split(drop.amount * stats.value_multiplier) {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let orb = NutrientOrb {
+                value,
+                velocity: Vec2::from_angle(angle) * config.scatter_speed,
+            };
+            spawn_nutrient_orb(&mut commands, orb, drop.position, &config);
+        }
+    }
+}
+
+/// Pull orbs toward the nearest live player segment in magnet range
+pub fn attract_orbs(
+    time: Res<Time>,
+    config: Res<OrbConfig>,
+    stats: Res<PickupStats>,
+    mut orbs: Query<(&mut NutrientOrb, &mut Transform)>,
+    segments: Query<(&TendrilPosition, &TendrilSegment, Option<&Faction>)>,
+    cores: Query<&Transform, (With<CoreNode>, Without<NutrientOrb>)>,
+) {
+    let delta = time.delta_secs();
+    let mut magnets: Vec<Vec2> = segments
+        .iter()
+        .filter(|(_, segment, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .map(|(pos, _, _)| pos.position)
+        .collect();
+    magnets.extend(cores.iter().map(|t| t.translation.truncate()));
+    let range_sq = stats.magnet_radius * stats.magnet_radius;
+
+    for (mut orb, mut transform) in orbs.iter_mut() {
+        let position = transform.translation.truncate();
+        let target = magnets
+            .iter()
+            .map(|m| (*m, m.distance_squared(position)))
+            .filter(|(_, d)| *d <= range_sq)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        match target {
+            Some((magnet, _)) => {
+                let desired = (magnet - position).normalize_or_zero() * config.magnet_speed;
+                let blend = (config.magnet_acceleration * delta).min(1.0);
+                orb.velocity = orb.velocity.lerp(desired, blend);
+            }
+            None => {
+                orb.velocity *= (1.0 - config.drag * delta).max(0.0);
+            }
+        }
+        transform.translation += (orb.velocity * delta).extend(0.0);
+    }
+}
+
+/// Add the value of orbs touching the network to `Nutrients`
+pub fn collect_orbs(
+    mut commands: Commands,
+    mut stay: EventReader<CollisionStay>,
+    orbs: Query<&NutrientOrb>,
+    mut nutrients: ResMut<Nutrients>,
+    mut gained: EventWriter<NutrientsGained>,
+) {
+    let mut collected = HashSet::new();
+    for event in stay.read() {
+        // Orbs only collide with the tendril and core layers
+        for entity in [event.a, event.b] {
+            let Ok(orb) = orbs.get(entity) else {
+                continue;
+            };
+            if !collected.insert(entity) {
+                continue;
+            }
+            nutrients.add(orb.value);
+            gained.send(NutrientsGained::new(orb.value, NutrientSource::EnemyDrop));
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Merge orbs when too many pile up
+///
+/// Past `merge_threshold` orbs fold into neighbours within `merge_radius`;
+/// past `max_orbs` the smallest fold into their nearest neighbour regardless
+/// of distance.
+pub fn merge_orbs(
+    mut commands: Commands,
+    config: Res<OrbConfig>,
+    mut orbs: Query<(Entity, &mut NutrientOrb, &Transform)>,
+) {
+    if orbs.iter().count() <= config.merge_threshold {
+        return;
+    }
+
+    let mut live: Vec<(Entity, f32, Vec2)> = orbs
+        .iter()
+        .map(|(e, orb, t)| (e, orb.value, t.translation.truncate()))
+        .collect();
+    live.sort_by_key(|(e, _, _)| *e);
+    let mut merged = vec![false; live.len()];
+
+    let radius_sq = config.merge_radius * config.merge_radius;
+    for i in 0..live.len() {
+        if merged[i] {
+            continue;
+        }
+        for j in (i + 1)..live.len() {
+            if !merged[j] && live[i].2.distance_squared(live[j].2) <= radius_sq {
+                live[i].1 += live[j].1;
+                merged[j] = true;
+            }
+        }
+    }
+
+    let mut remaining = merged.iter().filter(|m| !**m).count();
+    while remaining > config.max_orbs.max(1) {
+        let alive = || (0..live.len()).filter(|&i| !merged[i]);
+        let Some(smallest) = alive().min_by(|&a, &b| live[a].1.total_cmp(&live[b].1)) else {
+            break;
+        };
+        let origin = live[smallest].2;
+        let Some(nearest) = alive().filter(|&i| i != smallest).min_by(|&a, &b| {
+            live[a]
+                .2
+                .distance_squared(origin)
+                .total_cmp(&live[b].2.distance_squared(origin))
+        }) else {
+            break;
+        };
+        live[nearest].1 += live[smallest].1;
+        merged[smallest] = true;
+        remaining -= 1;
+    }
+
+    for (i, (entity, value, _)) in live.into_iter().enumerate() {
+        if merged[i] {
+            commands.entity(entity).despawn_recursive();
+        } else if let Ok((_, mut orb, _)) = orbs.get_mut(entity) {
+            if orb.value != value {
+                orb.value = value;
+            }
+        }
+    }
+}
+
+/// Remove every orb when the run ends
+pub fn despawn_orbs(mut commands: Commands, orbs: Query<Entity, With<NutrientOrb>>) {
+    for entity in orbs.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Draw orbs as small circles sized by value
+pub fn render_orbs(
+    mut gizmos: Gizmos,
+    config: Res<OrbConfig>,
+    orbs: Query<(&NutrientOrb, &Transform, &Visibility)>,
+) {
+    for (orb, transform, visibility) in orbs.iter() {
+        if visibility == Visibility::Hidden {
+            continue;
+        }
+        let radius = config.radius * (1.0 + orb.value / config.max_orb_value.max(1.0)).sqrt();
+        gizmos.circle_2d(
+            transform.translation.truncate(),
+            radius,
+            Color::srgb(0.4, 0.9, 0.5),
+        );
+    }
+}
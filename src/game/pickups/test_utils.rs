@@ -0,0 +1,77 @@
+//! Shared test utilities for pickup module tests
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use super::PickupsPlugin;
+use crate::game::collision::CollisionPlugin;
+use crate::game::map::{BiomeMap, BiomeTable, WorldDescription};
+use crate::game::network::{NetworkStats, TendrilPosition, TendrilSegment};
+use crate::game::progression::{NutrientSource, NutrientsGained, ProgressionPlugin};
+use crate::GameState;
+
+/// Fixed frame time used by pickup tests (exact in binary floating point)
+pub const TEST_FRAME_SECS: f32 = 0.25;
+
+/// `NutrientsGained` events collected across frames
+#[derive(Resource, Default)]
+pub struct Gained(pub Vec<(NutrientSource, f32)>);
+
+impl Gained {
+    /// Amounts gained from one source, in order
+    pub fn from(&self, source: NutrientSource) -> Vec<f32> {
+        self.0
+            .iter()
+            .filter(|(s, _)| *s == source)
+            .map(|(_, amount)| *amount)
+            .collect()
+    }
+}
+
+fn collect_gained(mut events: EventReader<NutrientsGained>, mut out: ResMut<Gained>) {
+    out.0
+        .extend(events.read().map(|event| (event.source, event.amount)));
+}
+
+/// Helper to create a test app with pickup, collision and progression
+/// plugins and a fixed time step, already in `Playing`
+pub fn create_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(StatesPlugin)
+        .init_state::<GameState>()
+        .init_resource::<NetworkStats>()
+        .init_resource::<BiomeMap>()
+        .init_resource::<BiomeTable>()
+        .init_resource::<WorldDescription>()
+        .init_resource::<Gained>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            TEST_FRAME_SECS,
+        )))
+        .add_plugins((CollisionPlugin, ProgressionPlugin, PickupsPlugin))
+        .add_systems(Update, collect_gained);
+    app.update();
+    enter_state(&mut app, GameState::Playing);
+    app
+}
+
+/// Transition the test app into `state` and let the transition settle
+pub fn enter_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+    app.update();
+}
+
+/// Spawn a player tendril segment at a world position
+pub fn grow_segment_at(app: &mut App, position: Vec2) -> Entity {
+    app.world_mut()
+        .spawn((
+            TendrilSegment::default(),
+            TendrilPosition::new(position, Vec2::X),
+        ))
+        .id()
+}
@@ -13,7 +13,7 @@ pub enum NutrientSource {
     EnvironmentNode,
     /// Passive generation from network
     PassiveAbsorption,
-    /// From decomposing severed segments
+    /// From decomposing corpses and severed segments
     Decomposition,
     /// For testing/debugging
     Debug,
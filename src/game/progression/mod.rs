@@ -18,6 +18,7 @@ mod systems;
 
 pub use events::*;
 pub use resources::*;
+pub use systems::try_spend_nutrients;
 
 /// Plugin for the progression system
 pub struct ProgressionPlugin;
@@ -44,8 +45,11 @@ impl Plugin for ProgressionPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::network::NetworkStats;
+    use crate::game::map::{Biome, BiomeMap, BiomeRegion, BiomeTable};
+    use crate::game::network::{NetworkStats, TendrilPosition, TendrilSegment};
     use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
 
     fn create_test_app() -> App {
         let mut app = App::new();
@@ -53,6 +57,8 @@ mod tests {
             .add_plugins(StatesPlugin)
             .init_state::<GameState>()
             .init_resource::<NetworkStats>()
+            .init_resource::<BiomeMap>()
+            .init_resource::<BiomeTable>()
             .add_plugins(ProgressionPlugin);
         app
     }
@@ -179,4 +185,43 @@ mod tests {
             with_10
         );
     }
+
+    fn passive_income_on(biome: Biome) -> f32 {
+        let mut app = create_test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.insert_resource(BiomeMap::new(vec![BiomeRegion {
+            biome,
+            center: Vec2::ZERO,
+        }]));
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.world_mut()
+            .resource_mut::<NetworkStats>()
+            .connected_segments = 10;
+        for i in 0..10 {
+            app.world_mut().spawn((
+                TendrilSegment::default(),
+                TendrilPosition::new(Vec2::new(i as f32 * 16.0, 0.0), Vec2::X),
+            ));
+        }
+        app.world_mut().resource_mut::<Nutrients>().current = 0.0;
+        for _ in 0..100 {
+            app.update();
+        }
+        app.world().resource::<Nutrients>().current
+    }
+
+    #[test]
+    fn test_passive_generation_scales_with_biome_absorption() {
+        let rocky = passive_income_on(Biome::Rocky);
+        let forest = passive_income_on(Biome::ForestFloor);
+        let swamp = passive_income_on(Biome::Swamp);
+        assert!(rocky < forest, "rocky {rocky} vs forest {forest}");
+        assert!(forest < swamp, "forest {forest} vs swamp {swamp}");
+    }
 }
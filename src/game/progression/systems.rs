@@ -4,20 +4,45 @@
 
 use bevy::prelude::*;
 
-use super::events::{NutrientPurpose, NutrientSource, NutrientSpendFailed, NutrientsGained, NutrientsSpent};
+use super::events::{
+    NutrientPurpose, NutrientSource, NutrientSpendFailed, NutrientsGained, NutrientsSpent,
+};
 use super::resources::{Nutrients, PassiveNutrientConfig};
-use crate::game::network::NetworkStats;
+use crate::game::map::{BiomeMap, BiomeTable};
+use crate::game::network::{Faction, NetworkStats, TendrilPosition, TendrilSegment};
 
 /// Process passive nutrient generation based on network size
+///
+/// Segment income is scaled by the average absorption multiplier of the
+/// biomes under the player's segments.
 pub fn passive_nutrient_generation(
     time: Res<Time>,
     network_stats: Res<NetworkStats>,
     config: Res<PassiveNutrientConfig>,
+    biome_map: Res<BiomeMap>,
+    biomes: Res<BiomeTable>,
+    segments: Query<(&TendrilSegment, &TendrilPosition, Option<&Faction>)>,
     mut nutrients: ResMut<Nutrients>,
     mut events: EventWriter<NutrientsGained>,
 ) {
+    let (count, absorption) = segments
+        .iter()
+        .filter(|(segment, _, faction)| segment.health > 0.0 && Faction::is_player(*faction))
+        .fold((0u32, 0.0), |(count, sum), (_, pos, _)| {
+            (
+                count + 1,
+                sum + biomes.absorption_multiplier(&biome_map, pos.position),
+            )
+        });
+    let absorption = if count > 0 {
+        absorption / count as f32
+    } else {
+        1.0
+    };
+
     // Calculate income from connected segments
-    let segment_income = network_stats.connected_segments as f32 * config.per_segment_rate;
+    let segment_income =
+        network_stats.connected_segments as f32 * config.per_segment_rate * absorption;
 
     // Calculate income from territory coverage
     let territory_income = network_stats.territory_coverage * config.territory_bonus_rate;
@@ -27,7 +52,10 @@ pub fn passive_nutrient_generation(
 
     if total > 0.0 {
         nutrients.add(total);
-        events.send(NutrientsGained::new(total, NutrientSource::PassiveAbsorption));
+        events.send(NutrientsGained::new(
+            total,
+            NutrientSource::PassiveAbsorption,
+        ));
     }
 }
 
@@ -35,7 +63,6 @@ pub fn passive_nutrient_generation(
 ///
 /// Returns true if the spend was successful, false otherwise.
 /// Fires appropriate events based on the result.
-#[allow(dead_code)] // Will be used by growth/ability systems in future tickets
 pub fn try_spend_nutrients(
     amount: f32,
     purpose: NutrientPurpose,
@@ -0,0 +1,167 @@
+//! Spatial hashing utilities
+//!
+//! A uniform grid that buckets entities by position for fast radius queries.
+//! Systems rebuild a grid each frame from their own queries instead of
+//! scanning every entity for every lookup.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Uniform grid of entity positions
+#[derive(Debug, Clone)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<(Entity, Vec2)>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(64.0)
+    }
+}
+
+impl SpatialGrid {
+    /// Create an empty grid with square cells of `cell_size` world units
+    #[must_use]
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Side length of a cell in world units
+    #[must_use]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Cell coordinate containing a world position
+    #[must_use]
+    pub fn cell_of(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// Remove all entries, keeping allocated cells
+    pub fn clear(&mut self) {
+        for entries in self.cells.values_mut() {
+            entries.clear();
+        }
+    }
+
+    /// Add an entity at a position
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        let cell = self.cell_of(position);
+        self.cells.entry(cell).or_default().push((entity, position));
+    }
+
+    /// Number of entries in the grid
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.values().map(Vec::len).sum()
+    }
+
+    /// Whether the grid has no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.values().all(Vec::is_empty)
+    }
+
+    /// Entries in the cells overlapping a square around `center`
+    ///
+    /// Candidates may lie outside `radius`; use `query_radius` for an exact
+    /// circle test.
+    pub fn query_cells(
+        &self,
+        center: Vec2,
+        radius: f32,
+    ) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let min = self.cell_of(center - Vec2::splat(radius));
+        let max = self.cell_of(center + Vec2::splat(radius));
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+
+    /// Entries within `radius` of `center`
+    pub fn query_radius(
+        &self,
+        center: Vec2,
+        radius: f32,
+    ) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let radius_sq = radius * radius;
+        self.query_cells(center, radius)
+            .filter(move |(_, position)| position.distance_squared(center) <= radius_sq)
+    }
+
+    /// Closest entry within `radius` of `center`
+    #[must_use]
+    pub fn nearest(&self, center: Vec2, radius: f32) -> Option<(Entity, Vec2)> {
+        self.query_radius(center, radius).min_by(|a, b| {
+            a.1.distance_squared(center)
+                .total_cmp(&b.1.distance_squared(center))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_with(points: &[Vec2]) -> SpatialGrid {
+        let mut grid = SpatialGrid::new(10.0);
+        for (i, point) in points.iter().enumerate() {
+            grid.insert(Entity::from_raw(i as u32), *point);
+        }
+        grid
+    }
+
+    #[test]
+    fn test_cell_of_handles_negative_positions() {
+        let grid = SpatialGrid::new(10.0);
+        assert_eq!(grid.cell_of(Vec2::new(5.0, 5.0)), IVec2::new(0, 0));
+        assert_eq!(grid.cell_of(Vec2::new(-0.1, 15.0)), IVec2::new(-1, 1));
+    }
+
+    #[test]
+    fn test_query_radius_is_exact() {
+        let grid = grid_with(&[Vec2::ZERO, Vec2::new(9.0, 0.0), Vec2::new(11.0, 0.0)]);
+        let found: Vec<_> = grid
+            .query_radius(Vec2::ZERO, 10.0)
+            .map(|(e, _)| e)
+            .collect();
+        assert_eq!(found.len(), 2);
+        assert!(!found.contains(&Entity::from_raw(2)));
+    }
+
+    #[test]
+    fn test_query_radius_spans_cells() {
+        let grid = grid_with(&[
+            Vec2::new(-25.0, 0.0),
+            Vec2::new(25.0, 0.0),
+            Vec2::new(0.0, 29.0),
+        ]);
+        assert_eq!(grid.query_radius(Vec2::ZERO, 30.0).count(), 3);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let grid = grid_with(&[Vec2::new(20.0, 0.0), Vec2::new(5.0, 5.0)]);
+        assert_eq!(
+            grid.nearest(Vec2::ZERO, 50.0).unwrap().0,
+            Entity::from_raw(1)
+        );
+        assert!(grid.nearest(Vec2::new(500.0, 0.0), 10.0).is_none());
+    }
+
+    #[test]
+    fn test_clear_empties_grid() {
+        let mut grid = grid_with(&[Vec2::ZERO, Vec2::ONE]);
+        assert_eq!(grid.len(), 2);
+        grid.clear();
+        assert!(grid.is_empty());
+        assert_eq!(grid.query_radius(Vec2::ZERO, 10.0).count(), 0);
+    }
+}
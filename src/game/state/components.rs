@@ -22,6 +22,39 @@ impl RunStats {
     }
 }
 
+/// How the current run ended
+///
+/// `GameOver` covers both endings; this tells them apart. Victory is set by
+/// whatever decides the run was won, anything else entering `GameOver` is a
+/// defeat.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// The run is still going
+    #[default]
+    Undecided,
+    Defeat,
+    Victory,
+}
+
+/// Seed the current run's map was generated from
+///
+/// Set `requested` before a run starts to replay a map; otherwise every run
+/// rolls a fresh seed.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RunSeed {
+    pub seed: u64,
+    /// Seed to use for the next run instead of a random one
+    pub requested: Option<u64>,
+}
+
+impl RunSeed {
+    /// Fix the seed of a new run, preferring a requested seed over `roll`
+    pub fn start_run(&mut self, roll: u64) -> u64 {
+        self.seed = self.requested.take().unwrap_or(roll);
+        self.seed
+    }
+}
+
 /// Resource tracking pause state details
 ///
 /// Note: `was_paused_by_upgrade` is reserved for future use when the
@@ -52,6 +85,18 @@ mod tests {
         assert_resource::<RunStats>();
     }
 
+    #[test]
+    fn test_run_seed_prefers_requested_seed_once() {
+        let mut seed = RunSeed {
+            requested: Some(42),
+            ..default()
+        };
+        assert_eq!(seed.start_run(7), 42);
+        assert_eq!(seed.seed, 42);
+        assert_eq!(seed.start_run(7), 7);
+        assert_eq!(seed.seed, 7);
+    }
+
     #[test]
     fn test_run_stats_reset() {
         let mut stats = RunStats {
@@ -65,6 +110,14 @@ mod tests {
         assert_eq!(stats.enemies_killed, 0);
     }
 
+    #[test]
+    fn test_run_outcome_default_undecided() {
+        assert_eq!(RunOutcome::default(), RunOutcome::Undecided);
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<RunOutcome>();
+        assert_resource::<RunSeed>();
+    }
+
     #[test]
     fn test_pause_state_default() {
         let pause = PauseState::default();
@@ -1,6 +1,7 @@
 //! Game state management
 //!
-//! Handles state transitions, pause functionality, and run statistics.
+//! Handles state transitions, pause functionality, run statistics, the run
+//! seed and the run outcome (victory or defeat).
 
 use bevy::prelude::*;
 
@@ -18,11 +19,16 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<RunStats>()
             .init_resource::<PauseState>()
-            // Pause input works in Playing and Paused states
+            .init_resource::<RunOutcome>()
+            .init_resource::<RunSeed>()
+            // Pause input works in Playing, Paused and Upgrading states
             .add_systems(
                 Update,
-                systems::handle_pause_input
-                    .run_if(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                systems::handle_pause_input.run_if(
+                    in_state(GameState::Playing)
+                        .or(in_state(GameState::Paused))
+                        .or(in_state(GameState::Upgrading)),
+                ),
             )
             // Update run time only in Playing state
             .add_systems(
@@ -35,15 +41,16 @@ impl Plugin for StatePlugin {
                     exited: GameState::Menu,
                     entered: GameState::Playing,
                 },
-                systems::reset_run_stats,
+                (systems::reset_run_stats, systems::reset_run_outcome),
             )
             .add_systems(
                 OnTransition {
                     exited: GameState::GameOver,
                     entered: GameState::Playing,
                 },
-                systems::reset_run_stats,
-            );
+                (systems::reset_run_stats, systems::reset_run_outcome),
+            )
+            .add_systems(OnEnter(GameState::GameOver), systems::record_defeat);
     }
 }
 
@@ -193,6 +200,38 @@ mod tests {
         assert!(stats.elapsed_time >= 100.0); // Should still be >= 100.0
     }
 
+    #[test]
+    fn test_escape_leaves_upgrade_selection_without_reset() {
+        let mut app = create_test_app();
+        app.update(); // Start in Menu
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.world_mut().resource_mut::<RunStats>().enemies_killed = 7;
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Upgrading);
+        app.update();
+        app.world_mut()
+            .send_event(bevy::input::keyboard::KeyboardInput {
+                key_code: KeyCode::Escape,
+                logical_key: bevy::input::keyboard::Key::Escape,
+                state: bevy::input::ButtonState::Pressed,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Playing
+        );
+        assert_eq!(app.world().resource::<RunStats>().enemies_killed, 7);
+    }
+
     #[test]
     fn test_run_stats_reset_on_restart_from_game_over() {
         let mut app = create_test_app();
@@ -223,4 +262,36 @@ mod tests {
         assert_eq!(stats.enemies_killed, 0);
         assert!(stats.elapsed_time < 0.1);
     }
+
+    #[test]
+    fn test_game_over_without_victory_is_defeat() {
+        let mut app = create_test_app();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+        assert_eq!(*app.world().resource::<RunOutcome>(), RunOutcome::Defeat);
+
+        // A new run clears the outcome
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        assert_eq!(*app.world().resource::<RunOutcome>(), RunOutcome::Undecided);
+    }
+
+    #[test]
+    fn test_victory_survives_game_over() {
+        let mut app = create_test_app();
+        app.update();
+
+        *app.world_mut().resource_mut::<RunOutcome>() = RunOutcome::Victory;
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+        assert_eq!(*app.world().resource::<RunOutcome>(), RunOutcome::Victory);
+    }
 }
@@ -2,10 +2,12 @@
 
 use bevy::prelude::*;
 
-use super::components::{PauseState, RunStats};
+use super::components::{PauseState, RunOutcome, RunStats};
 use crate::GameState;
 
 /// Handle pause input (Escape key)
+///
+/// Escape also leaves upgrade selection, skipping the offered upgrade.
 pub fn handle_pause_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     current_state: Res<State<GameState>>,
@@ -18,12 +20,11 @@ pub fn handle_pause_input(
                 pause_state.was_paused_by_upgrade = false;
                 next_state.set(GameState::Paused);
             }
-            GameState::Paused => {
-                // Only unpause if not paused for upgrade
-                if !pause_state.was_paused_by_upgrade {
-                    next_state.set(GameState::Playing);
-                }
+            // Only unpause if not paused for upgrade
+            GameState::Paused if !pause_state.was_paused_by_upgrade => {
+                next_state.set(GameState::Playing);
             }
+            GameState::Upgrading => next_state.set(GameState::Playing),
             _ => {}
         }
     }
@@ -34,6 +35,18 @@ pub fn reset_run_stats(mut run_stats: ResMut<RunStats>) {
     run_stats.reset();
 }
 
+/// Clear the outcome when a new run starts
+pub fn reset_run_outcome(mut outcome: ResMut<RunOutcome>) {
+    *outcome = RunOutcome::Undecided;
+}
+
+/// Record a defeat when the run ends without a victory
+pub fn record_defeat(mut outcome: ResMut<RunOutcome>) {
+    if *outcome == RunOutcome::Undecided {
+        *outcome = RunOutcome::Defeat;
+    }
+}
+
 /// Update elapsed time during gameplay
 pub fn update_run_time(time: Res<Time>, mut run_stats: ResMut<RunStats>) {
     run_stats.elapsed_time += time.delta_secs();
@@ -0,0 +1,485 @@
+//! Danger sense indicators
+//!
+//! Screen-edge arrows pointing toward hostiles just outside the camera view:
+//! - Each hostile within sensing range of the view is projected to the edge
+//! - Hostiles arriving from the same direction merge into one indicator
+//! - Indicators grow with the threat they carry and as hostiles close in
+//! - Bosses get their own indicator and icon
+//! - Hostiles hidden by fog of war are not sensed
+//!
+//! The projection and clustering are pure functions; the systems only gather
+//! sightings and draw the result.
+
+use std::f32::consts::{PI, TAU};
+
+use bevy::prelude::*;
+
+use crate::game::boss::Boss;
+use crate::game::camera::MainCamera;
+use crate::game::enemies::Enemy;
+use crate::game::map::{FogGrid, MinimapMark};
+use crate::game::network::Health;
+
+/// Tuning for danger sense
+#[derive(Resource, Debug, Clone)]
+pub struct DangerSenseConfig {
+    /// How far beyond the view edge hostiles are sensed
+    pub sense_range: f32,
+    /// Inset of indicators from the view edge in world units
+    pub edge_margin: f32,
+    /// Hostiles whose bearings differ by less than this (radians) share an
+    /// indicator
+    pub cluster_angle: f32,
+    /// Threat at which an indicator reaches full size
+    pub full_threat: f32,
+    /// Indicator scale for the weakest, farthest threat
+    pub min_scale: f32,
+    /// Indicator scale for the strongest, closest threat
+    pub max_scale: f32,
+    /// Arrow length at scale 1.0 in world units
+    pub arrow_size: f32,
+    /// View half extents used when the camera has no projection
+    pub fallback_half_extents: Vec2,
+}
+
+impl Default for DangerSenseConfig {
+    fn default() -> Self {
+        Self {
+            sense_range: 600.0,
+            edge_margin: 24.0,
+            cluster_angle: 0.35,
+            full_threat: 300.0,
+            min_scale: 0.6,
+            max_scale: 1.6,
+            arrow_size: 18.0,
+            fallback_half_extents: Vec2::new(640.0, 360.0),
+        }
+    }
+}
+
+/// Where an off-screen point meets the view edge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeProjection {
+    /// Point on the inset view edge along the bearing to the target
+    pub anchor: Vec2,
+    /// Unit bearing from the view center to the target
+    pub direction: Vec2,
+    /// Distance from the anchor to the target
+    pub distance: f32,
+}
+
+/// Project a world position onto the edge of the view
+///
+/// The anchor lies on the view rectangle shrunk by `margin`, along the ray
+/// from the view center toward `point`. Returns `None` when the point is on
+/// screen.
+#[must_use]
+pub fn project_to_view_edge(view: Rect, point: Vec2, margin: f32) -> Option<EdgeProjection> {
+    if view.contains(point) {
+        return None;
+    }
+    let center = view.center();
+    let offset = point - center;
+    let direction = offset.try_normalize()?;
+    let half = (view.half_size() - Vec2::splat(margin)).max(Vec2::ZERO);
+
+    // Scale the bearing until it touches the nearer pair of edges
+    let tx = if direction.x.abs() > f32::EPSILON {
+        half.x / direction.x.abs()
+    } else {
+        f32::INFINITY
+    };
+    let ty = if direction.y.abs() > f32::EPSILON {
+        half.y / direction.y.abs()
+    } else {
+        f32::INFINITY
+    };
+    let anchor = center + direction * tx.min(ty);
+    Some(EdgeProjection {
+        anchor,
+        direction,
+        distance: anchor.distance(point),
+    })
+}
+
+/// A hostile seen by danger sense this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sighting {
+    pub projection: EdgeProjection,
+    /// Threat it carries, its current health
+    pub threat: f32,
+    pub boss: bool,
+}
+
+/// One arrow on the screen edge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DangerIndicator {
+    /// Position on the inset view edge
+    pub anchor: Vec2,
+    /// Unit bearing toward the threat
+    pub direction: Vec2,
+    /// Distance to the closest hostile in the cluster
+    pub distance: f32,
+    /// Combined threat of the cluster
+    pub threat: f32,
+    /// Hostiles merged into this indicator
+    pub count: usize,
+    pub boss: bool,
+}
+
+fn bearing(direction: Vec2) -> f32 {
+    direction.y.atan2(direction.x).rem_euclid(TAU)
+}
+
+/// Merge sightings that arrive from similar bearings
+///
+/// Bosses are never merged. Each cluster points along the bearing of its
+/// closest member.
+#[must_use]
+pub fn cluster_sightings(sightings: &[Sighting], cluster_angle: f32) -> Vec<DangerIndicator> {
+    let mut indicators: Vec<DangerIndicator> = sightings
+        .iter()
+        .filter(|s| s.boss)
+        .map(|s| DangerIndicator {
+            anchor: s.projection.anchor,
+            direction: s.projection.direction,
+            distance: s.projection.distance,
+            threat: s.threat,
+            count: 1,
+            boss: true,
+        })
+        .collect();
+
+    let mut regular: Vec<&Sighting> = sightings.iter().filter(|s| !s.boss).collect();
+    regular.sort_by(|a, b| {
+        bearing(a.projection.direction).total_cmp(&bearing(b.projection.direction))
+    });
+
+    // Walk the bearings in order, starting a cluster at each gap wider than
+    // the cluster angle so the wrap at 0/TAU is handled by the largest gap
+    let n = regular.len();
+    if n == 0 {
+        return indicators;
+    }
+    let start = (0..n)
+        .max_by(|&a, &b| {
+            let gap = |i: usize| {
+                let prev = bearing(regular[(i + n - 1) % n].projection.direction);
+                (bearing(regular[i].projection.direction) - prev).rem_euclid(TAU)
+            };
+            gap(a).total_cmp(&gap(b))
+        })
+        .unwrap_or(0);
+
+    let mut cluster: Option<(DangerIndicator, f32)> = None;
+    for offset in 0..n {
+        let sighting = regular[(start + offset) % n];
+        let angle = bearing(sighting.projection.direction);
+        if let Some((indicator, last)) = cluster.as_mut() {
+            let gap = (angle - *last).rem_euclid(TAU);
+            if gap < cluster_angle.min(PI) {
+                indicator.threat += sighting.threat;
+                indicator.count += 1;
+                if sighting.projection.distance < indicator.distance {
+                    indicator.anchor = sighting.projection.anchor;
+                    indicator.direction = sighting.projection.direction;
+                    indicator.distance = sighting.projection.distance;
+                }
+                *last = angle;
+                continue;
+            }
+            indicators.push(*indicator);
+        }
+        cluster = Some((
+            DangerIndicator {
+                anchor: sighting.projection.anchor,
+                direction: sighting.projection.direction,
+                distance: sighting.projection.distance,
+                threat: sighting.threat,
+                count: 1,
+                boss: false,
+            },
+            angle,
+        ));
+    }
+    if let Some((indicator, _)) = cluster {
+        indicators.push(indicator);
+    }
+    indicators
+}
+
+/// Indicator size for its threat and distance
+///
+/// Grows with threat up to `full_threat` and as the closest hostile nears
+/// the view edge.
+#[must_use]
+pub fn indicator_scale(indicator: &DangerIndicator, config: &DangerSenseConfig) -> f32 {
+    let threat = if config.full_threat > 0.0 {
+        (indicator.threat / config.full_threat).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let proximity = if config.sense_range > 0.0 {
+        1.0 - (indicator.distance / config.sense_range).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let weight = 0.5 * threat + 0.5 * proximity;
+    config.min_scale + (config.max_scale - config.min_scale) * weight
+}
+
+/// Indicators shown this frame
+#[derive(Resource, Debug, Default, Clone)]
+pub struct DangerIndicators(pub Vec<DangerIndicator>);
+
+/// Gather sightings around the camera view and cluster them
+pub fn update_danger_indicators(
+    config: Res<DangerSenseConfig>,
+    fog: Res<FogGrid>,
+    cameras: Query<(&Transform, Option<&OrthographicProjection>), With<MainCamera>>,
+    hostiles: Query<
+        (&Transform, Option<&Health>, Has<Boss>, Has<MinimapMark>),
+        Or<(With<Enemy>, With<Boss>)>,
+    >,
+    mut indicators: ResMut<DangerIndicators>,
+) {
+    indicators.0.clear();
+    let Ok((camera, projection)) = cameras.get_single() else {
+        return;
+    };
+    let half = projection.map_or(config.fallback_half_extents, |p| p.area.half_size());
+    let view = Rect::from_center_half_size(camera.translation.truncate(), half);
+
+    let sightings: Vec<Sighting> = hostiles
+        .iter()
+        .filter_map(|(transform, health, boss, marked)| {
+            let position = transform.translation.truncate();
+            if !marked && !fog.is_visible(position) {
+                return None;
+            }
+            let projection = project_to_view_edge(view, position, config.edge_margin)?;
+            (projection.distance <= config.sense_range + config.edge_margin).then(|| Sighting {
+                projection,
+                threat: health.map_or(0.0, |h| h.current.max(0.0)),
+                boss,
+            })
+        })
+        .collect();
+    indicators.0 = cluster_sightings(&sightings, config.cluster_angle);
+}
+
+/// Draw indicators as arrows on the view edge
+pub fn render_danger_indicators(
+    mut gizmos: Gizmos,
+    config: Res<DangerSenseConfig>,
+    indicators: Res<DangerIndicators>,
+) {
+    for indicator in indicators.0.iter() {
+        let size = config.arrow_size * indicator_scale(indicator, &config);
+        let tip = indicator.anchor;
+        let back = tip - indicator.direction * size;
+        let side = indicator.direction.perp() * size * 0.5;
+        let color = if indicator.boss {
+            Color::srgb(0.95, 0.2, 0.2)
+        } else {
+            Color::srgb(1.0, 0.75, 0.2)
+        };
+        gizmos.line_2d(back + side, tip, color);
+        gizmos.line_2d(back - side, tip, color);
+        if indicator.boss {
+            // Skull-like ring behind the arrow marks a boss
+            gizmos.circle_2d(back - indicator.direction * size * 0.5, size * 0.4, color);
+        } else if indicator.count > 1 {
+            gizmos.line_2d(back + side, back - side, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> Rect {
+        Rect::from_center_half_size(Vec2::ZERO, Vec2::new(100.0, 50.0))
+    }
+
+    fn sighting(point: Vec2, threat: f32, boss: bool) -> Sighting {
+        Sighting {
+            projection: project_to_view_edge(view(), point, 0.0).unwrap(),
+            threat,
+            boss,
+        }
+    }
+
+    #[test]
+    fn test_on_screen_points_are_not_projected() {
+        assert!(project_to_view_edge(view(), Vec2::new(90.0, 40.0), 0.0).is_none());
+    }
+
+    #[test]
+    fn test_projection_lands_on_nearest_edge() {
+        let right = project_to_view_edge(view(), Vec2::new(300.0, 0.0), 10.0).unwrap();
+        assert_eq!(right.anchor, Vec2::new(90.0, 0.0));
+        assert_eq!(right.direction, Vec2::X);
+        assert_eq!(right.distance, 210.0);
+
+        let top = project_to_view_edge(view(), Vec2::new(0.0, -200.0), 10.0).unwrap();
+        assert_eq!(top.anchor, Vec2::new(0.0, -40.0));
+    }
+
+    #[test]
+    fn test_diagonal_projection_clamps_to_shorter_axis() {
+        let corner = project_to_view_edge(view(), Vec2::new(200.0, 200.0), 0.0).unwrap();
+        assert!((corner.anchor - Vec2::new(50.0, 50.0)).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_projection_follows_offset_view() {
+        let view = Rect::from_center_half_size(Vec2::new(1000.0, 0.0), Vec2::splat(50.0));
+        let left = project_to_view_edge(view, Vec2::ZERO, 0.0).unwrap();
+        assert_eq!(left.anchor, Vec2::new(950.0, 0.0));
+        assert_eq!(left.direction, Vec2::NEG_X);
+    }
+
+    #[test]
+    fn test_sightings_from_one_direction_cluster() {
+        let indicators = cluster_sightings(
+            &[
+                sighting(Vec2::new(300.0, 10.0), 10.0, false),
+                sighting(Vec2::new(200.0, -10.0), 20.0, false),
+                sighting(Vec2::new(-300.0, 0.0), 5.0, false),
+            ],
+            0.35,
+        );
+        assert_eq!(indicators.len(), 2);
+        let right = indicators.iter().find(|i| i.direction.x > 0.0).unwrap();
+        assert_eq!(right.count, 2);
+        assert_eq!(right.threat, 30.0);
+        // Points at the closer of the two
+        assert_eq!(
+            right.distance,
+            sighting(Vec2::new(200.0, -10.0), 0.0, false)
+                .projection
+                .distance
+        );
+    }
+
+    #[test]
+    fn test_clusters_span_the_zero_bearing() {
+        let indicators = cluster_sightings(
+            &[
+                sighting(Vec2::new(300.0, 20.0), 1.0, false),
+                sighting(Vec2::new(300.0, -20.0), 1.0, false),
+            ],
+            0.35,
+        );
+        assert_eq!(indicators.len(), 1);
+        assert_eq!(indicators[0].count, 2);
+    }
+
+    #[test]
+    fn test_bosses_get_their_own_indicator() {
+        let indicators = cluster_sightings(
+            &[
+                sighting(Vec2::new(300.0, 0.0), 10.0, false),
+                sighting(Vec2::new(310.0, 0.0), 500.0, true),
+            ],
+            0.35,
+        );
+        assert_eq!(indicators.len(), 2);
+        assert_eq!(indicators.iter().filter(|i| i.boss).count(), 1);
+    }
+
+    #[test]
+    fn test_scale_grows_with_threat_and_proximity() {
+        let config = DangerSenseConfig::default();
+        let base = DangerIndicator {
+            anchor: Vec2::ZERO,
+            direction: Vec2::X,
+            distance: config.sense_range,
+            threat: 0.0,
+            count: 1,
+            boss: false,
+        };
+        let closer = DangerIndicator {
+            distance: 0.0,
+            ..base
+        };
+        let stronger = DangerIndicator {
+            threat: config.full_threat,
+            ..base
+        };
+        assert_eq!(indicator_scale(&base, &config), config.min_scale);
+        assert!(indicator_scale(&closer, &config) > indicator_scale(&base, &config));
+        assert!(indicator_scale(&stronger, &config) > indicator_scale(&base, &config));
+        assert_eq!(
+            indicator_scale(
+                &DangerIndicator {
+                    threat: config.full_threat,
+                    ..closer
+                },
+                &config
+            ),
+            config.max_scale
+        );
+    }
+
+    #[test]
+    fn test_indicators_track_enemies_around_camera() {
+        use crate::game::enemies::{BehaviourKind, EnemyKind};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<DangerSenseConfig>()
+            .init_resource::<DangerIndicators>()
+            .insert_resource(FogGrid::new(Vec2::splat(-6000.0), 100.0, 120, 120))
+            .add_systems(Update, update_danger_indicators);
+        app.world_mut().spawn((MainCamera, Transform::default()));
+        let enemy = Enemy {
+            kind: EnemyKind::Insect,
+            behaviour: BehaviourKind::Swarm,
+            speed: 0.0,
+            radius: 8.0,
+            nutrient_drop: 0.0,
+        };
+        // Just off the right edge, on screen, far beyond sensing range, and
+        // just off the left edge in fog
+        let mut fog = app.world_mut().resource_mut::<FogGrid>();
+        for x in [800.0, 100.0, 5000.0] {
+            fog.reveal(Vec2::new(x, 0.0), 10.0);
+        }
+        let mut fogged = Entity::PLACEHOLDER;
+        for x in [800.0, 100.0, 5000.0, -800.0] {
+            fogged = app
+                .world_mut()
+                .spawn((
+                    enemy.clone(),
+                    Health::new(10.0),
+                    Transform::from_xyz(x, 0.0, 0.0),
+                ))
+                .id();
+        }
+        app.update();
+
+        let indicators = &app.world().resource::<DangerIndicators>().0;
+        assert_eq!(indicators.len(), 1);
+        assert_eq!(indicators[0].direction, Vec2::X);
+        assert_eq!(indicators[0].threat, 10.0);
+
+        // A scout mark shows the enemy in fog too
+        app.world_mut()
+            .entity_mut(fogged)
+            .insert(MinimapMark { remaining: 5.0 });
+        app.update();
+        let indicators = &app.world().resource::<DangerIndicators>().0;
+        assert_eq!(indicators.len(), 2);
+        assert!(indicators.iter().any(|i| i.direction == Vec2::NEG_X));
+    }
+
+    #[test]
+    fn test_danger_sense_types_are_registered() {
+        fn assert_resource<T: Resource>() {}
+        assert_resource::<DangerSenseConfig>();
+        assert_resource::<DangerIndicators>();
+    }
+}
@@ -0,0 +1,150 @@
+//! Game over screen
+//!
+//! Shown while in `GameOver`:
+//! - Whether the run was won or lost
+//! - The seed the map was generated from, so it can be replayed
+
+use bevy::prelude::*;
+
+use crate::game::state::{RunOutcome, RunSeed};
+
+/// Marker for the game over screen root
+#[derive(Component, Debug)]
+pub struct GameOverScreen;
+
+/// Headline for a finished run
+#[must_use]
+pub fn outcome_title(outcome: RunOutcome) -> &'static str {
+    match outcome {
+        RunOutcome::Victory => "Victory",
+        // The core died before the outcome was recorded
+        RunOutcome::Defeat | RunOutcome::Undecided => "Defeat",
+    }
+}
+
+/// Spawn the game over screen
+pub fn spawn_game_over_screen(
+    mut commands: Commands,
+    outcome: Res<RunOutcome>,
+    run_seed: Res<RunSeed>,
+) {
+    commands
+        .spawn((
+            GameOverScreen,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(outcome_title(*outcome)),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                Text::new(format!("Seed: {}", run_seed.seed)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// Remove the game over screen
+pub fn despawn_game_over_screen(
+    mut commands: Commands,
+    screens: Query<Entity, With<GameOverScreen>>,
+) {
+    for entity in screens.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    use crate::game::map::FogGrid;
+    use crate::game::ui::UiPlugin;
+    use crate::GameState;
+
+    fn create_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunOutcome>()
+            .insert_resource(RunSeed {
+                seed: 1234,
+                requested: None,
+            })
+            .init_resource::<FogGrid>()
+            .add_plugins(UiPlugin);
+        app.update();
+        app
+    }
+
+    fn enter_state(app: &mut App, state: GameState) {
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(state);
+        app.update();
+    }
+
+    fn screen_texts(app: &mut App) -> Vec<String> {
+        let mut query = app.world_mut().query::<(&Text, &Parent)>();
+        query
+            .iter(app.world())
+            .map(|(text, _)| text.0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_outcome_title() {
+        assert_eq!(outcome_title(RunOutcome::Victory), "Victory");
+        assert_eq!(outcome_title(RunOutcome::Defeat), "Defeat");
+        assert_eq!(outcome_title(RunOutcome::Undecided), "Defeat");
+    }
+
+    #[test]
+    fn test_screen_shows_outcome_and_seed() {
+        let mut app = create_test_app();
+        *app.world_mut().resource_mut::<RunOutcome>() = RunOutcome::Victory;
+        enter_state(&mut app, GameState::GameOver);
+
+        let texts = screen_texts(&mut app);
+        assert!(texts.contains(&"Victory".to_string()));
+        assert!(texts.contains(&"Seed: 1234".to_string()));
+    }
+
+    #[test]
+    fn test_screen_removed_on_exit() {
+        let mut app = create_test_app();
+        enter_state(&mut app, GameState::GameOver);
+        enter_state(&mut app, GameState::Menu);
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<Entity, With<GameOverScreen>>();
+        assert_eq!(query.iter(app.world()).count(), 0);
+        assert!(screen_texts(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_game_over_types_are_registered() {
+        fn assert_component<T: Component>() {}
+        assert_component::<GameOverScreen>();
+    }
+}
@@ -5,14 +5,69 @@
 //! - Upgrade selection screen
 //! - Menus
 //! - Danger indicators
+//! - Game over screen with the run seed
 
 use bevy::prelude::*;
 
+use crate::GameState;
+
+pub mod danger_sense;
+pub mod game_over;
+
+pub use danger_sense::{
+    cluster_sightings, indicator_scale, project_to_view_edge, DangerIndicator, DangerIndicators,
+    DangerSenseConfig, EdgeProjection, Sighting,
+};
+pub use game_over::{outcome_title, GameOverScreen};
+
 /// Plugin for the UI system
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: Register UI systems
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DangerSenseConfig>()
+            .init_resource::<DangerIndicators>()
+            .add_systems(
+                Update,
+                danger_sense::update_danger_indicators.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                danger_sense::render_danger_indicators.run_if(
+                    resource_exists::<bevy::gizmos::config::GizmoConfigStore>
+                        .and(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                game_over::spawn_game_over_screen,
+            )
+            .add_systems(
+                OnExit(GameState::GameOver),
+                game_over::despawn_game_over_screen,
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    use crate::game::map::FogGrid;
+    use crate::game::state::{RunOutcome, RunSeed};
+
+    #[test]
+    fn test_ui_plugin_builds() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .init_resource::<RunOutcome>()
+            .init_resource::<RunSeed>()
+            .init_resource::<FogGrid>()
+            .add_plugins(UiPlugin);
+        app.update();
+        assert!(app.world().get_resource::<DangerIndicators>().is_some());
     }
 }
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
+use mycelia::game::combat::CombatLog;
 use mycelia::GamePlugin;
 
 fn main() {
+    let mut combat_log = CombatLog::default();
+    combat_log.dump_path = CombatLog::dump_path_from_args(std::env::args());
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -11,6 +15,7 @@ fn main() {
             }),
             ..default()
         }))
+        .insert_resource(combat_log)
         .add_plugins(GamePlugin)
         .run();
 }